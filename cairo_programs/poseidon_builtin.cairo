@@ -0,0 +1,9 @@
+%builtins poseidon
+from starkware.cairo.common.cairo_builtins import PoseidonBuiltin
+from starkware.cairo.common.builtin_poseidon.poseidon import poseidon_hash
+
+func main{poseidon_ptr: PoseidonBuiltin*}() {
+    let (x) = poseidon_hash(1, 2);
+    assert x = 2636648219362971850283425434366427370362725365790740855428580782178634926362;
+    return ();
+}
@@ -0,0 +1,24 @@
+%builtins poseidon
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.cairo_builtins import PoseidonBuiltin
+from starkware.cairo.common.builtin_poseidon.poseidon import poseidon_hash_many
+
+func fill_array(array: felt*, n: felt) {
+    if (n == 0) {
+        return ();
+    }
+    assert array[n - 1] = n;
+    return fill_array(array, n - 1);
+}
+
+func main{poseidon_ptr: PoseidonBuiltin*}() {
+    alloc_locals;
+    let (elements: felt*) = alloc();
+    fill_array(elements, 13);
+    let (x) = poseidon_hash_many(13, elements);
+    assert x = 1251882193596311692818427530103203928040554098680756687123122581460311004644;
+
+    let (y) = poseidon_hash_many(2, elements);
+    assert y = 1557996165160500454210437319447297236715335099509187222888255133199463084263;
+    return ();
+}
@@ -10,7 +10,7 @@ use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
 use num_bigint::BigInt;
 use std::collections::HashMap;
 use std::path::Path;
-use std::rc::Rc;
+use std::sync::Arc;
 
 // Create the function that implements the custom hint
 fn print_a_hint(
@@ -33,7 +33,7 @@ fn main() {
     let mut hint_processor = BuiltinHintProcessor::new_empty();
 
     //Add the custom hint, together with the Python code
-    hint_processor.add_hint(String::from("print(ids.a)"), Rc::new(hint));
+    hint_processor.add_hint(String::from("print(ids.a)"), Arc::new(hint));
 
     //Run the cairo program
     cairo_run(
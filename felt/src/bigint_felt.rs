@@ -1,9 +1,6 @@
-use lazy_static::lazy_static;
-use num_bigint::{BigInt, BigUint, ToBigInt, U64Digits};
-use num_integer::Integer;
-use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
-use serde::Deserialize;
-use std::{
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::{
     convert::Into,
     fmt,
     iter::Sum,
@@ -11,9 +8,15 @@ use std::{
         Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Rem, Shl, Shr, ShrAssign,
         Sub, SubAssign,
     },
+    str::FromStr,
 };
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, BigUint, ToBigInt, U64Digits};
+use num_integer::Integer;
+use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{FeltOps, NewFelt, ParseFeltError, FIELD};
+use crate::{felt_serde, FeltOps, NewFelt, ParseFeltError, FIELD};
 
 lazy_static! {
     pub static ref CAIRO_PRIME: BigUint =
@@ -24,7 +27,7 @@ lazy_static! {
         .expect("Conversion BigUint -> BigInt can't fail");
 }
 
-#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Deserialize, Default)]
+#[derive(Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Default)]
 pub struct FeltBigInt(BigUint);
 
 macro_rules! from_integer {
@@ -125,6 +128,8 @@ impl NewFelt for FeltBigInt {
 }
 
 impl FeltOps for FeltBigInt {
+    type U64Digits<'a> = U64Digits<'a>;
+
     fn modpow(&self, exponent: &FeltBigInt, modulus: &FeltBigInt) -> Self {
         FeltBigInt(self.0.modpow(&exponent.0, &modulus.0))
     }
@@ -712,6 +717,26 @@ impl FromPrimitive for FeltBigInt {
     }
 }
 
+impl Serialize for FeltBigInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        felt_serde::serialize_radix(self, 16, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeltBigInt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        felt_serde::deserialize(deserializer)
+    }
+}
+
+impl FromStr for FeltBigInt {
+    type Err = ParseFeltError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        felt_serde::parse_felt_str(string)
+    }
+}
+
 impl fmt::Display for FeltBigInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -836,4 +861,72 @@ mod tests {
         let d = c.neg();
         assert_eq!(d, FeltBigInt::new(10_i32));
     }
+
+    #[test]
+    fn from_str_hex_and_decimal() {
+        assert_eq!(FeltBigInt::from_str("0x1a"), Ok(FeltBigInt::new(26)));
+        assert_eq!(FeltBigInt::from_str("26"), Ok(FeltBigInt::new(26)));
+        assert_eq!(FeltBigInt::from_str("-0x1"), Ok(FeltBigInt::new(-1)));
+        assert_eq!(FeltBigInt::from_str("-1"), Ok(FeltBigInt::new(-1)));
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(FeltBigInt::from_str(""), Err(ParseFeltError));
+        assert_eq!(FeltBigInt::from_str("0x"), Err(ParseFeltError));
+        assert_eq!(FeltBigInt::from_str("1a"), Err(ParseFeltError));
+        assert_eq!(FeltBigInt::from_str("0xzz"), Err(ParseFeltError));
+    }
+
+    #[test]
+    fn serialize_as_hex_string() {
+        let felt = FeltBigInt::new(-1);
+        assert_eq!(
+            serde_json::to_string(&felt).unwrap(),
+            "\"0x800000000000011000000000000000000000000000000000000000000000000\""
+        );
+    }
+
+    #[test]
+    fn deserialize_hex_and_decimal_strings() {
+        let expected = FeltBigInt::new(255);
+        assert_eq!(
+            serde_json::from_str::<FeltBigInt>("\"0xff\"").unwrap(),
+            expected
+        );
+        assert_eq!(
+            serde_json::from_str::<FeltBigInt>("\"255\"").unwrap(),
+            expected
+        );
+        assert!(serde_json::from_str::<FeltBigInt>("\"0xfg\"").is_err());
+    }
+
+    #[test]
+    fn deserialize_integers() {
+        use serde::de::{value::Error, IntoDeserializer};
+
+        let deserializer: serde::de::value::U64Deserializer<Error> = 7u64.into_deserializer();
+        assert_eq!(
+            FeltBigInt::deserialize(deserializer),
+            Ok(FeltBigInt::new(7))
+        );
+        let deserializer: serde::de::value::I64Deserializer<Error> = (-7i64).into_deserializer();
+        assert_eq!(
+            FeltBigInt::deserialize(deserializer),
+            Ok(FeltBigInt::new(-7))
+        );
+    }
+
+    #[test]
+    fn serde_json_round_trip() {
+        let felt = FeltBigInt::from_str(
+            "3618502788666131213697322783095070105623107215331596699973092056135872020470",
+        )
+        .unwrap();
+        let serialized = serde_json::to_string(&felt).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FeltBigInt>(&serialized).unwrap(),
+            felt
+        );
+    }
 }
@@ -0,0 +1,111 @@
+use crate::{FeltOps, ParseFeltError};
+use core::{fmt, marker::PhantomData, ops::Neg};
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Size in bytes of the big-endian encoding used by non human-readable formats.
+const FELT_BYTES: usize = 32;
+
+/// Parses a felt written either as a `0x`-prefixed hexadecimal string or as a decimal string.
+/// Both forms may be preceded by a `-` sign, in which case the value is negated modulo the prime.
+pub(crate) fn parse_felt_str<T>(string: &str) -> Result<T, ParseFeltError>
+where
+    T: FeltOps + Neg<Output = T>,
+{
+    let (negative, digits) = match string.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, string),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => T::parse_bytes(hex.as_bytes(), 16),
+        None => T::parse_bytes(digits.as_bytes(), 10),
+    }
+    .ok_or(ParseFeltError)?;
+    Ok(if negative { -value } else { value })
+}
+
+pub(crate) fn serialize_radix<T, S>(felt: &T, radix: u32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: FeltOps,
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        let bytes = felt.to_bytes_be();
+        let mut buffer = [0; FELT_BYTES];
+        buffer[FELT_BYTES - bytes.len()..].copy_from_slice(&bytes);
+        return serializer.serialize_bytes(&buffer);
+    }
+    match radix {
+        16 => serializer.serialize_str(&format!("0x{}", felt.to_str_radix(16))),
+        _ => serializer.serialize_str(&felt.to_str_radix(radix)),
+    }
+}
+
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FeltOps + Neg<Output = T> + From<u64> + From<i64> + From<u128> + From<i128>,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FeltVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(FeltVisitor(PhantomData))
+    }
+}
+
+struct FeltVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FeltVisitor<T>
+where
+    T: FeltOps + Neg<Output = T> + From<u64> + From<i64> + From<u128> + From<i128>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a felt as a hexadecimal or decimal string, an integer or 32 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        parse_felt_str(value).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        Ok(T::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        Ok(T::from(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<T, E> {
+        Ok(T::from(value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<T, E> {
+        Ok(T::from(value))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<T, E> {
+        if value.len() > FELT_BYTES {
+            return Err(E::invalid_length(value.len(), &self));
+        }
+        Ok(T::from_bytes_be(value))
+    }
+}
+
+/// Serializes a felt as a decimal string instead of the default `0x`-prefixed hexadecimal one.
+/// Meant to be used as `#[serde(with = "felt::serde_decimal")]`. Deserialization accepts both forms.
+pub mod serde_decimal {
+    use crate::Felt;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(felt: &Felt, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_radix(felt, 10, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Felt, D::Error> {
+        Felt::deserialize(deserializer)
+    }
+}
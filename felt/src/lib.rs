@@ -1,10 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 mod bigint_felt;
+mod felt_serde;
+#[cfg(feature = "montgomery")]
+mod montgomery_felt;
 
-use bigint_felt::FeltBigInt;
-use num_bigint::{BigInt, BigUint, U64Digits};
-use num_integer::Integer;
-use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
-use std::{
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+pub use bigint_felt::FeltBigInt;
+use core::{
     convert::Into,
     fmt::{Debug, Display},
     iter::Sum,
@@ -12,9 +20,20 @@ use std::{
         Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Rem, Shl, Shr, ShrAssign,
         Sub, SubAssign,
     },
+    str::FromStr,
 };
+pub use felt_serde::serde_decimal;
+#[cfg(feature = "montgomery")]
+pub use montgomery_felt::FeltMontgomery;
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
 
+#[cfg(not(feature = "montgomery"))]
 pub type Felt = FeltBigInt;
+#[cfg(feature = "montgomery")]
+pub type Felt = FeltMontgomery;
 
 pub const PRIME_STR: &str = "0x800000000000011000000000000000000000000000000000000000000000001";
 pub const FIELD: (u128, u128) = ((1 << 123) + (17 << 64), 1);
@@ -22,16 +41,22 @@ pub const FIELD: (u128, u128) = ((1 << 123) + (17 << 64), 1);
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ParseFeltError;
 
-pub trait NewFelt {
-    fn new<T: Into<Felt>>(value: T) -> Self;
+pub trait NewFelt: Sized {
+    fn new<T: Into<Self>>(value: T) -> Self;
 }
 
-pub trait FeltOps {
-    fn modpow(&self, exponent: &Felt, modulus: &Felt) -> Self;
-    fn iter_u64_digits(&self) -> U64Digits;
+pub trait FeltOps: Sized {
+    /// Iterator over the 64-bit digits of a felt, least significant first, as returned by
+    /// `iter_u64_digits`.
+    type U64Digits<'a>: ExactSizeIterator<Item = u64>
+    where
+        Self: 'a;
+
+    fn modpow(&self, exponent: &Self, modulus: &Self) -> Self;
+    fn iter_u64_digits(&self) -> Self::U64Digits<'_>;
     fn to_signed_bytes_le(&self) -> Vec<u8>;
     fn to_bytes_be(&self) -> Vec<u8>;
-    fn parse_bytes(buf: &[u8], radix: u32) -> Option<Felt>;
+    fn parse_bytes(buf: &[u8], radix: u32) -> Option<Self>;
     fn from_bytes_be(bytes: &[u8]) -> Self;
     fn to_str_radix(&self, radix: u32) -> String;
     fn to_bigint(&self) -> BigInt;
@@ -87,6 +112,9 @@ macro_rules! assert_felt_impl {
             fn assert_to_primitive<T: ToPrimitive>() {}
             fn assert_display<T: Display>() {}
             fn assert_debug<T: Debug>() {}
+            fn assert_from_str<T: FromStr>() {}
+            fn assert_serialize<T: Serialize>() {}
+            fn assert_deserialize<'de, T: Deserialize<'de>>() {}
 
             // RFC 2056
             #[allow(dead_code)]
@@ -145,12 +173,17 @@ macro_rules! assert_felt_impl {
                 assert_to_primitive::<$type>();
                 assert_display::<$type>();
                 assert_debug::<$type>();
+                assert_from_str::<$type>();
+                assert_serialize::<$type>();
+                assert_deserialize::<$type>();
             }
         };
     };
 }
 
-assert_felt_impl!(Felt);
+assert_felt_impl!(FeltBigInt);
+#[cfg(feature = "montgomery")]
+assert_felt_impl!(FeltMontgomery);
 
 #[cfg(test)]
 mod test {
@@ -219,11 +252,10 @@ mod test {
         // "With assignment" means that the result of the operation is autommatically assigned to the variable value, replacing its previous content.
         fn shift_right_assign_in_range(ref value in "(0|[1-9][0-9]*)", ref shift_amount in "[0-9]{1,3}"){
             let mut value = Felt::parse_bytes(value.as_bytes(), 10).unwrap();
-            let p = FeltBigInt::parse_bytes(PRIME_STR[2..].as_bytes(), 16).unwrap();
+            let p = &BigUint::parse_bytes(PRIME_STR[2..].as_bytes(), 16).unwrap();
             let shift_amount:usize = shift_amount.parse::<usize>().unwrap();
             value >>= shift_amount;
-            value.to_biguint();
-            prop_assert!(value < p);
+            prop_assert!(&value.to_biguint() < p);
         }
 
         #[test]
@@ -237,5 +269,21 @@ mod test {
             let as_uint = &result.to_biguint();
             prop_assert!(as_uint < p, "{}", as_uint);
         }
+
+        #[test]
+        // Property-based test that ensures, for 100 values {x} that are randomly generated each time tests are run, that serializing a felt both as hex and as decimal and deserializing it back returns the same felt.
+        fn serde_round_trip(ref x in "(0|[1-9][0-9]*)") {
+            #[derive(Serialize, Deserialize)]
+            struct Decimal(#[serde(with = "serde_decimal")] Felt);
+
+            let felt = Felt::parse_bytes(x.as_bytes(), 10).unwrap();
+            let hex = serde_json::to_string(&felt).unwrap();
+            prop_assert!(hex.starts_with("\"0x"), "{}", hex);
+            prop_assert_eq!(serde_json::from_str::<Felt>(&hex).unwrap(), felt.clone());
+
+            let decimal = serde_json::to_string(&Decimal(felt.clone())).unwrap();
+            prop_assert_eq!(&decimal, &format!("\"{}\"", felt));
+            prop_assert_eq!(serde_json::from_str::<Decimal>(&decimal).unwrap().0, felt);
+        }
     }
 }
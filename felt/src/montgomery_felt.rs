@@ -0,0 +1,940 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::{
+    array,
+    cmp::Ordering,
+    convert::Into,
+    fmt,
+    iter::{Sum, Take},
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Rem, Shl, Shr, ShrAssign,
+        Sub, SubAssign,
+    },
+    str::FromStr,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{felt_serde, FeltOps, NewFelt, ParseFeltError};
+
+// All the limb arrays are little-endian.
+const MODULUS: [u64; 4] = [1, 0, 0, 0x0800000000000011];
+const MODULUS_MINUS_ONE: [u64; 4] = [0, 0, 0, 0x0800000000000011];
+const MODULUS_MINUS_TWO: [u64; 4] = [
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x0800000000000010,
+];
+// Felts below (p - 1) / 2 are positive, as in `FeltBigInt`.
+const SIGNED_FELT_MAX: [u64; 4] = [0, 0, 0x8000000000000000, 0x0400000000000008];
+// R = 2^256 mod p, the Montgomery form of one.
+const R: [u64; 4] = [
+    0xffffffffffffffe1,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x07fffffffffffdf0,
+];
+// R^2 mod p, multiplying by it converts a value into its Montgomery form.
+const R2: [u64; 4] = [
+    0xfffffd737e000401,
+    0x00000001330fffff,
+    0xffffffffff6f8000,
+    0x07ffd4ab5e008810,
+];
+// -p^-1 mod 2^64. The lowest limb of p is 1, so this is -1.
+const INV: u64 = u64::MAX;
+
+/// A field element kept in Montgomery form, `x * 2^256 mod p`, as four 64-bit limbs.
+/// Unlike `FeltBigInt` it never allocates, and the field operations reduce without divisions.
+/// Operations that aren't field operations, such as integer division or bitwise operations,
+/// work on the canonical value through a `BigUint`.
+#[derive(Clone, Copy, Eq, Hash, PartialEq, Default)]
+pub struct FeltMontgomery([u64; 4]);
+
+// Returns a + b + carry and the new carry.
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+// Returns a - b - borrow and the new borrow.
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let (d, b1) = a.overflowing_sub(b);
+    let (d, b2) = d.overflowing_sub(borrow);
+    (d, (b1 | b2) as u64)
+}
+
+// Returns a + b * c + carry and the new carry.
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut sum = [0; 4];
+    let mut carry = 0;
+    for ((s, a), b) in sum.iter_mut().zip(a).zip(b) {
+        (*s, carry) = adc(*a, *b, carry);
+    }
+    (sum, carry)
+}
+
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut diff = [0; 4];
+    let mut borrow = 0;
+    for ((d, a), b) in diff.iter_mut().zip(a).zip(b) {
+        (*d, borrow) = sbb(*a, *b, borrow);
+    }
+    (diff, borrow)
+}
+
+fn cmp_limbs(a: &[u64; 4], b: &[u64; 4]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+// Both operands are below p < 2^252, so the sum can't overflow the four limbs.
+fn add_mod(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let (sum, _) = add_limbs(a, b);
+    if cmp_limbs(&sum, &MODULUS) != Ordering::Less {
+        sub_limbs(&sum, &MODULUS).0
+    } else {
+        sum
+    }
+}
+
+fn sub_mod(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let (diff, borrow) = sub_limbs(a, b);
+    if borrow != 0 {
+        add_limbs(&diff, &MODULUS).0
+    } else {
+        diff
+    }
+}
+
+// Montgomery multiplication (CIOS): returns a * b * 2^-256 mod p.
+fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    for b_i in b {
+        let mut carry = 0;
+        for (t_j, a_j) in t.iter_mut().zip(a) {
+            (*t_j, carry) = mac(*t_j, *a_j, *b_i, carry);
+        }
+        let (t4, overflow) = t[4].overflowing_add(carry);
+        t[4] = t4;
+        t[5] = overflow as u64;
+
+        let m = t[0].wrapping_mul(INV);
+        let (_, mut carry) = mac(t[0], m, MODULUS[0], 0);
+        for j in 1..4 {
+            (t[j - 1], carry) = mac(t[j], m, MODULUS[j], carry);
+        }
+        let (t3, overflow) = t[4].overflowing_add(carry);
+        t[3] = t3;
+        t[4] = t[5] + overflow as u64;
+    }
+    let result = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || cmp_limbs(&result, &MODULUS) != Ordering::Less {
+        sub_limbs(&result, &MODULUS).0
+    } else {
+        result
+    }
+}
+
+impl FeltMontgomery {
+    // The limbs must hold a value below p.
+    fn from_canonical(limbs: [u64; 4]) -> Self {
+        Self(mont_mul(&limbs, &R2))
+    }
+
+    fn to_canonical(self) -> [u64; 4] {
+        mont_mul(&self.0, &[1, 0, 0, 0])
+    }
+
+    fn pow_limbs(self, exponent: &[u64; 4]) -> Self {
+        let mut result = Self::one();
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result * result;
+                if (limb >> bit) & 1 == 1 {
+                    result = result * self;
+                }
+            }
+        }
+        result
+    }
+
+    // By Fermat's little theorem. Like in `FeltBigInt`, zero has no inverse and yields zero.
+    fn inverse(self) -> Self {
+        self.pow_limbs(&MODULUS_MINUS_TWO)
+    }
+}
+
+macro_rules! from_integer {
+    ($type:ty) => {
+        impl From<$type> for FeltMontgomery {
+            fn from(value: $type) -> Self {
+                let abs = Self::from(value.unsigned_abs() as u128);
+                if value < 0 {
+                    -abs
+                } else {
+                    abs
+                }
+            }
+        }
+    };
+}
+
+macro_rules! from_unsigned {
+    ($type:ty) => {
+        impl From<$type> for FeltMontgomery {
+            fn from(value: $type) -> Self {
+                Self::from_canonical([value as u64, 0, 0, 0])
+            }
+        }
+    };
+}
+
+from_integer!(i8);
+from_integer!(i16);
+from_integer!(i32);
+from_integer!(i64);
+from_integer!(i128);
+from_integer!(isize);
+
+from_unsigned!(u8);
+from_unsigned!(u16);
+from_unsigned!(u32);
+from_unsigned!(u64);
+from_unsigned!(usize);
+
+impl From<u128> for FeltMontgomery {
+    fn from(value: u128) -> Self {
+        Self::from_canonical([value as u64, (value >> 64) as u64, 0, 0])
+    }
+}
+
+impl From<BigUint> for FeltMontgomery {
+    fn from(value: BigUint) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&BigUint> for FeltMontgomery {
+    fn from(value: &BigUint) -> Self {
+        let modulus = BigUint::from_slice(&limbs_to_u32_digits(&MODULUS));
+        let mut limbs = [0; 4];
+        for (limb, digit) in limbs
+            .iter_mut()
+            .zip(value.mod_floor(&modulus).iter_u64_digits())
+        {
+            *limb = digit;
+        }
+        Self::from_canonical(limbs)
+    }
+}
+
+impl From<BigInt> for FeltMontgomery {
+    fn from(value: BigInt) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&BigInt> for FeltMontgomery {
+    fn from(value: &BigInt) -> Self {
+        let abs = Self::from(value.magnitude());
+        if value.sign() == Sign::Minus {
+            -abs
+        } else {
+            abs
+        }
+    }
+}
+
+fn limbs_to_u32_digits(limbs: &[u64; 4]) -> Vec<u32> {
+    limbs
+        .iter()
+        .flat_map(|limb| [*limb as u32, (limb >> 32) as u32])
+        .collect()
+}
+
+impl NewFelt for FeltMontgomery {
+    fn new<T: Into<Self>>(value: T) -> Self {
+        value.into()
+    }
+}
+
+impl FeltOps for FeltMontgomery {
+    type U64Digits<'a> = Take<array::IntoIter<u64, 4>>;
+
+    fn modpow(&self, exponent: &FeltMontgomery, modulus: &FeltMontgomery) -> Self {
+        Self::from(
+            self.to_biguint()
+                .modpow(&exponent.to_biguint(), &modulus.to_biguint()),
+        )
+    }
+
+    fn iter_u64_digits(&self) -> Take<array::IntoIter<u64, 4>> {
+        let digits = self.to_canonical();
+        let len = digits
+            .iter()
+            .rposition(|digit| *digit != 0)
+            .map_or(0, |last| last + 1);
+        digits.into_iter().take(len)
+    }
+
+    fn to_signed_bytes_le(&self) -> Vec<u8> {
+        self.to_biguint().to_bytes_le()
+    }
+
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.to_biguint().to_bytes_be()
+    }
+
+    fn parse_bytes(buf: &[u8], radix: u32) -> Option<Self> {
+        match BigUint::parse_bytes(buf, radix) {
+            Some(parsed) => Some(FeltMontgomery::new(parsed)),
+            None => BigInt::parse_bytes(buf, radix).map(FeltMontgomery::new),
+        }
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Self {
+        Self::new(BigUint::from_bytes_be(bytes))
+    }
+
+    fn to_str_radix(&self, radix: u32) -> String {
+        self.to_biguint().to_str_radix(radix)
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        if self.is_negative() {
+            BigInt::from_biguint(Sign::Minus, self.neg().to_biguint())
+        } else {
+            self.to_biguint().into()
+        }
+    }
+
+    fn to_biguint(&self) -> BigUint {
+        BigUint::from_slice(&limbs_to_u32_digits(&self.to_canonical()))
+    }
+
+    fn sqrt(&self) -> Self {
+        Self::from(self.to_biguint().sqrt())
+    }
+
+    fn bits(&self) -> u64 {
+        let limbs = self.to_canonical();
+        match limbs.iter().rposition(|limb| *limb != 0) {
+            Some(i) => 64 * i as u64 + 64 - limbs[i].leading_zeros() as u64,
+            None => 0,
+        }
+    }
+}
+
+impl Add for FeltMontgomery {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(add_mod(&self.0, &rhs.0))
+    }
+}
+
+impl<'a> Add for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn add(self, rhs: Self) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'a> Add<&'a FeltMontgomery> for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn add(self, rhs: &'a FeltMontgomery) -> Self::Output {
+        self + *rhs
+    }
+}
+
+impl Add<u32> for FeltMontgomery {
+    type Output = Self;
+    fn add(self, rhs: u32) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl Add<usize> for FeltMontgomery {
+    type Output = Self;
+    fn add(self, rhs: usize) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl<'a> Add<usize> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn add(self, rhs: usize) -> Self::Output {
+        *self + FeltMontgomery::from(rhs)
+    }
+}
+
+impl AddAssign for FeltMontgomery {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<'a> AddAssign<&'a FeltMontgomery> for FeltMontgomery {
+    fn add_assign(&mut self, rhs: &'a FeltMontgomery) {
+        *self = *self + *rhs;
+    }
+}
+
+impl Sum for FeltMontgomery {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(FeltMontgomery::zero(), |acc, x| acc + x)
+    }
+}
+
+impl Neg for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn neg(self) -> Self::Output {
+        Self(sub_mod(&[0; 4], &self.0))
+    }
+}
+
+impl<'a> Neg for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl Sub for FeltMontgomery {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(sub_mod(&self.0, &rhs.0))
+    }
+}
+
+impl<'a> Sub<&'a FeltMontgomery> for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: &'a FeltMontgomery) -> Self::Output {
+        self - *rhs
+    }
+}
+
+impl<'a> Sub for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl Sub<u32> for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: u32) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl<'a> Sub<u32> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: u32) -> Self::Output {
+        *self - FeltMontgomery::from(rhs)
+    }
+}
+
+impl Sub<usize> for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: usize) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl SubAssign for FeltMontgomery {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<'a> SubAssign<&'a FeltMontgomery> for FeltMontgomery {
+    fn sub_assign(&mut self, rhs: &'a FeltMontgomery) {
+        *self = *self - *rhs;
+    }
+}
+
+impl Sub<FeltMontgomery> for usize {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: FeltMontgomery) -> Self::Output {
+        FeltMontgomery::from(self) - rhs
+    }
+}
+
+impl Sub<&FeltMontgomery> for usize {
+    type Output = FeltMontgomery;
+    fn sub(self, rhs: &FeltMontgomery) -> Self::Output {
+        FeltMontgomery::from(self) - *rhs
+    }
+}
+
+impl Mul for FeltMontgomery {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(mont_mul(&self.0, &rhs.0))
+    }
+}
+
+impl<'a> Mul for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn mul(self, rhs: Self) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'a> Mul<&'a FeltMontgomery> for FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn mul(self, rhs: &'a FeltMontgomery) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl<'a> MulAssign<&'a FeltMontgomery> for FeltMontgomery {
+    fn mul_assign(&mut self, rhs: &'a FeltMontgomery) {
+        *self = *self * *rhs;
+    }
+}
+
+impl Pow<u32> for FeltMontgomery {
+    type Output = Self;
+    fn pow(self, rhs: u32) -> Self {
+        self.pow_limbs(&[rhs as u64, 0, 0, 0])
+    }
+}
+
+impl<'a> Pow<u32> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn pow(self, rhs: u32) -> Self::Output {
+        (*self).pow(rhs)
+    }
+}
+
+impl Div for FeltMontgomery {
+    type Output = Self;
+    // In Felts `x / y` needs to be expressed as `x * y^-1`
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<'a> Div for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn div(self, rhs: Self) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<'a> Div<FeltMontgomery> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn div(self, rhs: FeltMontgomery) -> Self::Output {
+        *self / rhs
+    }
+}
+
+impl Rem for FeltMontgomery {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        Self::from(self.to_biguint() % rhs.to_biguint())
+    }
+}
+
+impl<'a> Rem<&'a FeltMontgomery> for FeltMontgomery {
+    type Output = Self;
+    fn rem(self, rhs: &'a FeltMontgomery) -> Self::Output {
+        self % *rhs
+    }
+}
+
+impl Zero for FeltMontgomery {
+    fn zero() -> Self {
+        Self([0; 4])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+}
+
+impl One for FeltMontgomery {
+    fn one() -> Self {
+        Self(R)
+    }
+
+    fn is_one(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self.0 == R
+    }
+}
+
+impl Bounded for FeltMontgomery {
+    fn min_value() -> Self {
+        Self::zero()
+    }
+    fn max_value() -> Self {
+        Self::from_canonical(MODULUS_MINUS_ONE)
+    }
+}
+
+impl Num for FeltMontgomery {
+    type FromStrRadixErr = ParseFeltError;
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match BigUint::from_str_radix(string, radix) {
+            Ok(num) => Ok(FeltMontgomery::new(num)),
+            Err(_) => Err(ParseFeltError),
+        }
+    }
+}
+
+impl Integer for FeltMontgomery {
+    fn div_floor(&self, other: &Self) -> Self {
+        Self::from(self.to_biguint().div_floor(&other.to_biguint()))
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let (d, m) = self.to_biguint().div_mod_floor(&other.to_biguint());
+        (Self::from(d), Self::from(m))
+    }
+
+    fn divides(&self, other: &Self) -> bool {
+        self.is_multiple_of(other)
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        Self::from(self.to_biguint().gcd(&other.to_biguint()))
+    }
+
+    fn is_even(&self) -> bool {
+        self.to_canonical()[0] & 1 == 0
+    }
+
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        self.to_biguint().is_multiple_of(&other.to_biguint())
+    }
+
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    fn lcm(&self, other: &Self) -> Self {
+        Self::from(self.to_biguint().lcm(&other.to_biguint()))
+    }
+
+    fn mod_floor(&self, other: &Self) -> Self {
+        Self::from(self.to_biguint().mod_floor(&other.to_biguint()))
+    }
+}
+
+impl Signed for FeltMontgomery {
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            self.neg()
+        } else {
+            *self
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if self.is_zero() {
+            FeltMontgomery::zero()
+        } else if self.is_positive() {
+            FeltMontgomery::one()
+        } else {
+            FeltMontgomery::max_value()
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.is_zero() && cmp_limbs(&self.to_canonical(), &SIGNED_FELT_MAX) == Ordering::Less
+    }
+
+    fn is_negative(&self) -> bool {
+        !(self.is_positive() || self.is_zero())
+    }
+}
+
+// The Montgomery form doesn't preserve the order, so felts are compared by their values.
+impl Ord for FeltMontgomery {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.to_canonical(), &other.to_canonical())
+    }
+}
+
+impl PartialOrd for FeltMontgomery {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Shl<u32> for FeltMontgomery {
+    type Output = Self;
+    fn shl(self, other: u32) -> Self::Output {
+        Self::from(self.to_biguint().shl(other))
+    }
+}
+
+impl<'a> Shl<u32> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn shl(self, other: u32) -> Self::Output {
+        (*self).shl(other)
+    }
+}
+
+impl Shl<usize> for FeltMontgomery {
+    type Output = Self;
+    fn shl(self, other: usize) -> Self::Output {
+        Self::from(self.to_biguint().shl(other))
+    }
+}
+
+impl<'a> Shl<usize> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn shl(self, other: usize) -> Self::Output {
+        (*self).shl(other)
+    }
+}
+
+impl Shr<u32> for FeltMontgomery {
+    type Output = Self;
+    fn shr(self, other: u32) -> Self::Output {
+        Self::from(self.to_biguint().shr(other))
+    }
+}
+
+impl<'a> Shr<u32> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn shr(self, other: u32) -> Self::Output {
+        (*self).shr(other)
+    }
+}
+
+impl ShrAssign<usize> for FeltMontgomery {
+    fn shr_assign(&mut self, other: usize) {
+        *self = Self::from(self.to_biguint().shr(other));
+    }
+}
+
+impl<'a> BitAnd for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        FeltMontgomery::from(self.to_biguint() & rhs.to_biguint())
+    }
+}
+
+impl<'a> BitAnd<&'a FeltMontgomery> for FeltMontgomery {
+    type Output = Self;
+    fn bitand(self, rhs: &'a FeltMontgomery) -> Self::Output {
+        &self & rhs
+    }
+}
+
+impl<'a> BitAnd<FeltMontgomery> for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn bitand(self, rhs: Self::Output) -> Self::Output {
+        self & &rhs
+    }
+}
+
+impl<'a> BitOr for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        FeltMontgomery::from(self.to_biguint() | rhs.to_biguint())
+    }
+}
+
+impl<'a> BitXor for &'a FeltMontgomery {
+    type Output = FeltMontgomery;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        FeltMontgomery::from(self.to_biguint() ^ rhs.to_biguint())
+    }
+}
+
+impl ToPrimitive for FeltMontgomery {
+    fn to_u64(&self) -> Option<u64> {
+        match self.to_canonical() {
+            [value, 0, 0, 0] => Some(value),
+            _ => None,
+        }
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|value| value.try_into().ok())
+    }
+}
+
+impl FromPrimitive for FeltMontgomery {
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().map(Self::from)
+    }
+}
+
+impl Serialize for FeltMontgomery {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        felt_serde::serialize_radix(self, 16, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeltMontgomery {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        felt_serde::deserialize(deserializer)
+    }
+}
+
+impl FromStr for FeltMontgomery {
+    type Err = ParseFeltError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        felt_serde::parse_felt_str(string)
+    }
+}
+
+impl fmt::Display for FeltMontgomery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_biguint())
+    }
+}
+
+impl fmt::Debug for FeltMontgomery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_biguint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint_felt::FeltBigInt;
+
+    #[test]
+    fn constants_match_the_prime() {
+        let prime = BigUint::parse_bytes(crate::PRIME_STR[2..].as_bytes(), 16).unwrap();
+        let to_biguint = |limbs: &[u64; 4]| BigUint::from_slice(&limbs_to_u32_digits(limbs));
+        assert_eq!(to_biguint(&MODULUS), prime);
+        assert_eq!(to_biguint(&R), (BigUint::one() << 256_u32) % &prime);
+        assert_eq!(to_biguint(&R2), (BigUint::one() << 512_u32) % &prime);
+        assert_eq!(to_biguint(&SIGNED_FELT_MAX), &prime >> 1_u32);
+    }
+
+    #[test]
+    fn add_felts_within_field() {
+        let a = FeltMontgomery::new(1);
+        let b = FeltMontgomery::new(2);
+        let c = FeltMontgomery::new(3);
+
+        assert_eq!(a + b, c);
+    }
+
+    #[test]
+    fn mul_felts_within_field() {
+        let a = FeltMontgomery::new(2);
+        let b = FeltMontgomery::new(3);
+        let c = FeltMontgomery::new(6);
+
+        assert_eq!(a * b, c);
+    }
+
+    #[test]
+    fn sub_felts_wraps_around() {
+        let a = FeltMontgomery::new(2);
+        let b = FeltMontgomery::new(3);
+
+        assert_eq!(a - b, FeltMontgomery::new(-1));
+        assert_eq!(FeltMontgomery::new(-1), FeltMontgomery::max_value());
+    }
+
+    #[test]
+    fn negate_num() {
+        let a = FeltMontgomery::new(10_i32);
+        assert_eq!(
+            a.neg().to_str_radix(10),
+            "3618502788666131213697322783095070105623107215331596699973092056135872020471"
+        );
+        assert_eq!(a.neg().neg(), a);
+        assert_eq!(FeltMontgomery::zero().neg(), FeltMontgomery::zero());
+    }
+
+    #[test]
+    fn div_is_mul_inv() {
+        let a = FeltMontgomery::new(7);
+        let b = FeltMontgomery::new(3);
+        assert_eq!((a / b) * b, a);
+        assert_eq!(a / FeltMontgomery::zero(), FeltMontgomery::zero());
+    }
+
+    #[test]
+    fn to_primitive() {
+        assert_eq!(FeltMontgomery::new(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!(FeltMontgomery::new(u128::MAX).to_u64(), None);
+        assert_eq!(FeltMontgomery::new(-1).to_i64(), None);
+    }
+
+    #[test]
+    fn iter_u64_digits() {
+        assert_eq!(
+            FeltMontgomery::new((5_u128 << 64) + 3)
+                .iter_u64_digits()
+                .collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+        assert_eq!(FeltMontgomery::zero().iter_u64_digits().len(), 0);
+    }
+
+    #[test]
+    fn ordering_follows_values() {
+        let big = FeltMontgomery::max_value();
+        let small = FeltMontgomery::new(1);
+        assert!(small < big);
+        assert!(FeltMontgomery::new(2) > small);
+        assert!(small.is_positive());
+        assert!(big.is_negative());
+    }
+
+    #[test]
+    fn matches_bigint_backend() {
+        let x = "2726253412562834785394589028452365346234634576345634527853453276576348635";
+        let y = "1233455654678456823456783456345632458645634564356453456345634563456745642";
+        let (a, b) = (
+            FeltMontgomery::parse_bytes(x.as_bytes(), 10).unwrap(),
+            FeltMontgomery::parse_bytes(y.as_bytes(), 10).unwrap(),
+        );
+        let (c, d) = (
+            FeltBigInt::parse_bytes(x.as_bytes(), 10).unwrap(),
+            FeltBigInt::parse_bytes(y.as_bytes(), 10).unwrap(),
+        );
+        assert_eq!((a + b).to_biguint(), (&c + &d).to_biguint());
+        assert_eq!((a - b).to_biguint(), (&c - &d).to_biguint());
+        assert_eq!((b - a).to_biguint(), (&d - &c).to_biguint());
+        assert_eq!((a * b).to_biguint(), (&c * &d).to_biguint());
+        assert_eq!((a / b).to_biguint(), (&c / &d).to_biguint());
+        assert_eq!(a.pow(7).to_biguint(), (&c).pow(7).to_biguint());
+        assert_eq!((a << 100_u32).to_biguint(), (&c << 100_u32).to_biguint());
+        assert_eq!((a >> 100_u32).to_biguint(), (&c >> 100_u32).to_biguint());
+        assert_eq!(a.to_bigint(), c.to_bigint());
+        assert_eq!((-a).to_bigint(), (-&c).to_bigint());
+        assert_eq!(a.bits(), c.bits());
+    }
+}
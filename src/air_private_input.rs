@@ -0,0 +1,177 @@
+use crate::air_public_input::serialize_felt_hex;
+use crate::stdlib::{collections::HashMap, prelude::*};
+use felt::Felt;
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Inputs of the builtin instances used in a run, by builtin name. Together with the trace and
+/// memory files, they make up the private input of the AIR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AirPrivateInput(pub HashMap<String, Vec<PrivateInput>>);
+
+/// The private input file read by the Stone prover, which points to the trace and memory files
+/// of the run.
+#[cfg(feature = "std")]
+#[derive(Debug, Serialize)]
+pub struct AirPrivateInputFile<'a> {
+    pub trace_path: &'a Path,
+    pub memory_path: &'a Path,
+    #[serde(flatten)]
+    pub builtins: &'a HashMap<String, Vec<PrivateInput>>,
+}
+
+#[cfg(feature = "std")]
+impl AirPrivateInput {
+    pub fn to_file<'a>(
+        &'a self,
+        trace_path: &'a Path,
+        memory_path: &'a Path,
+    ) -> AirPrivateInputFile<'a> {
+        AirPrivateInputFile {
+            trace_path,
+            memory_path,
+            builtins: &self.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum PrivateInput {
+    Value(PrivateInputValue),
+    Pair(PrivateInputPair),
+    EcOp(PrivateInputEcOp),
+    KeccakState(PrivateInputKeccakState),
+    PoseidonState(PrivateInputPoseidonState),
+    Signature(PrivateInputSignature),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputValue {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub value: Felt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputPair {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub y: Felt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputEcOp {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub p_x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub p_y: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub m: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub q_x: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub q_y: Felt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputKeccakState {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s0: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s1: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s2: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s3: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s4: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s5: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s6: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s7: Felt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputPoseidonState {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s0: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s1: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub input_s2: Felt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrivateInputSignature {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub pubkey: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub msg: Felt,
+    pub signature_input: SignatureInput,
+}
+
+/// An ecdsa signature as taken by the prover, with `w` being the inverse of `s` modulo the
+/// order of the curve.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SignatureInput {
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub r: Felt,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub w: Felt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    #[test]
+    fn serialize_private_input_file() {
+        let private_input = AirPrivateInput(HashMap::from([
+            (
+                String::from("range_check"),
+                vec![PrivateInput::Value(PrivateInputValue {
+                    index: 0,
+                    value: Felt::new(10),
+                })],
+            ),
+            (
+                String::from("ecdsa"),
+                vec![PrivateInput::Signature(PrivateInputSignature {
+                    index: 1,
+                    pubkey: Felt::new(2),
+                    msg: Felt::new(3),
+                    signature_input: SignatureInput {
+                        r: Felt::new(4),
+                        w: Felt::new(255),
+                    },
+                })],
+            ),
+        ]));
+        let file = private_input.to_file(Path::new("/tmp/trace.bin"), Path::new("/tmp/memory.bin"));
+        assert_eq!(
+            serde_json::to_value(&file).unwrap(),
+            serde_json::json!({
+                "trace_path": "/tmp/trace.bin",
+                "memory_path": "/tmp/memory.bin",
+                "range_check": [{ "index": 0, "value": "0xa" }],
+                "ecdsa": [{
+                    "index": 1,
+                    "pubkey": "0x2",
+                    "msg": "0x3",
+                    "signature_input": { "r": "0x4", "w": "0xff" }
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::stdlib::collections::{BTreeMap, HashMap};
+use crate::stdlib::prelude::*;
+use crate::vm::errors::air_input_errors::AirInputError;
+use felt::{Felt, FeltOps};
+use serde::{Serialize, Serializer};
+
+/// Public input of the AIR of a proof mode run, in the format expected by the Stone prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PublicInput {
+    pub layout: String,
+    pub rc_min: isize,
+    pub rc_max: isize,
+    pub n_steps: usize,
+    pub memory_segments: HashMap<String, MemorySegmentAddresses>,
+    pub public_memory: Vec<PublicMemoryEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamic_params: Option<DynamicParams>,
+}
+
+/// Parameters fitted to a run with the dynamic layout, which the prover needs to rebuild the
+/// layout. The diluted pool is left out when no builtin uses it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DynamicParams {
+    pub rc_units: u32,
+    pub memory_units_per_step: u32,
+    pub public_memory_fraction: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diluted_units_per_step: Option<u32>,
+    pub builtin_ratios: BTreeMap<String, u32>,
+}
+
+/// Relocated start and end of a memory segment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MemorySegmentAddresses {
+    pub begin_addr: usize,
+    pub stop_ptr: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PublicMemoryEntry {
+    pub address: usize,
+    #[serde(serialize_with = "serialize_felt_hex")]
+    pub value: Felt,
+    pub page: usize,
+}
+
+impl PublicInput {
+    /// Builds the public input from the relocated memory and the relocated address and page of
+    /// each public memory cell.
+    pub fn new(
+        relocated_memory: &[Option<Felt>],
+        layout: &str,
+        public_memory_addresses: &[(usize, usize)],
+        memory_segments: HashMap<String, MemorySegmentAddresses>,
+        n_steps: usize,
+        (rc_min, rc_max): (isize, isize),
+    ) -> Result<PublicInput, AirInputError> {
+        let public_memory = public_memory_addresses
+            .iter()
+            .map(|&(address, page)| {
+                let value = relocated_memory
+                    .get(address)
+                    .and_then(Option::as_ref)
+                    .ok_or(AirInputError::MissingPublicMemoryValue(address))?;
+                Ok(PublicMemoryEntry {
+                    address,
+                    value: value.clone(),
+                    page,
+                })
+            })
+            .collect::<Result<_, AirInputError>>()?;
+
+        Ok(PublicInput {
+            layout: layout.to_string(),
+            rc_min,
+            rc_max,
+            n_steps,
+            memory_segments,
+            public_memory,
+            dynamic_params: None,
+        })
+    }
+}
+
+pub(crate) fn serialize_felt_hex<S: Serializer>(
+    value: &Felt,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("{:#x}", value.to_biguint()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    #[test]
+    fn new_public_input() {
+        let relocated_memory = vec![None, Some(Felt::new(0x10)), None, Some(Felt::new(3))];
+        let memory_segments = HashMap::from([(
+            String::from("program"),
+            MemorySegmentAddresses {
+                begin_addr: 1,
+                stop_ptr: 2,
+            },
+        )]);
+        let public_input = PublicInput::new(
+            &relocated_memory,
+            "small",
+            &[(1, 0), (3, 1)],
+            memory_segments,
+            16,
+            (-2, 5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            public_input.public_memory,
+            vec![
+                PublicMemoryEntry {
+                    address: 1,
+                    value: Felt::new(0x10),
+                    page: 0
+                },
+                PublicMemoryEntry {
+                    address: 3,
+                    value: Felt::new(3),
+                    page: 1
+                }
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&public_input).unwrap(),
+            serde_json::json!({
+                "layout": "small",
+                "rc_min": -2,
+                "rc_max": 5,
+                "n_steps": 16,
+                "memory_segments": { "program": { "begin_addr": 1, "stop_ptr": 2 } },
+                "public_memory": [
+                    { "address": 1, "value": "0x10", "page": 0 },
+                    { "address": 3, "value": "0x3", "page": 1 }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn new_public_input_missing_value() {
+        let relocated_memory = vec![None, Some(Felt::new(1))];
+        assert_eq!(
+            PublicInput::new(
+                &relocated_memory,
+                "small",
+                &[(1, 0), (2, 0)],
+                HashMap::new(),
+                16,
+                (0, 0)
+            ),
+            Err(AirInputError::MissingPublicMemoryValue(2))
+        );
+    }
+
+    #[test]
+    fn serialize_public_input_with_dynamic_params() {
+        let mut public_input =
+            PublicInput::new(&[], "dynamic", &[], HashMap::new(), 16, (0, 4)).unwrap();
+        public_input.dynamic_params = Some(DynamicParams {
+            rc_units: 4,
+            memory_units_per_step: 8,
+            public_memory_fraction: 8,
+            diluted_units_per_step: None,
+            builtin_ratios: BTreeMap::from([(String::from("range_check"), 16)]),
+        });
+        assert_eq!(
+            serde_json::to_value(&public_input).unwrap()["dynamic_params"],
+            serde_json::json!({
+                "rc_units": 4,
+                "memory_units_per_step": 8,
+                "public_memory_fraction": 8,
+                "builtin_ratios": { "range_check": 16 }
+            })
+        );
+    }
+}
@@ -0,0 +1,105 @@
+#![deny(warnings)]
+use cairo_vm::cairo_run;
+use cairo_vm::types::instruction::Instruction;
+use cairo_vm::vm::decoding::decoder::decode_instruction;
+use clap::{Parser, ValueHint};
+use felt::Felt;
+use num_traits::ToPrimitive;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+
+/// Compares the binary trace and memory files of two runs, such as the ones written by
+/// cairo-rs-run and by the Python VM, and reports the first difference.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(
+        long = "--trace_files",
+        value_parser,
+        number_of_values = 2,
+        value_hint = ValueHint::FilePath,
+        required_unless_present = "memory_files"
+    )]
+    trace_files: Vec<PathBuf>,
+    #[clap(
+        long = "--memory_files",
+        value_parser,
+        number_of_values = 2,
+        value_hint = ValueHint::FilePath
+    )]
+    memory_files: Vec<PathBuf>,
+}
+
+// Decodes the instruction at `pc`, along with its immediate if it has one.
+fn decode_at(memory: &BTreeMap<usize, Felt>, pc: usize) -> Option<Instruction> {
+    let encoded_instruction = memory.get(&pc)?.to_i64()?;
+    decode_instruction(encoded_instruction, memory.get(&(pc + 1))).ok()
+}
+
+fn main() -> io::Result<()> {
+    let args = Args::parse();
+    let memories = args
+        .memory_files
+        .iter()
+        .map(|path| cairo_run::read_binary_memory(path))
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut equal = true;
+
+    if !args.trace_files.is_empty() {
+        let traces = args
+            .trace_files
+            .iter()
+            .map(|path| cairo_run::read_binary_trace(path))
+            .collect::<io::Result<Vec<_>>>()?;
+        if let Some(step) = cairo_run::find_trace_mismatch(&traces[0], &traces[1]) {
+            equal = false;
+            println!("Traces differ at step {step}:");
+            for (i, (path, trace)) in args.trace_files.iter().zip(&traces).enumerate() {
+                let entry = match trace.get(step) {
+                    Some(entry) => entry,
+                    None => {
+                        println!("  {}: ended after {step} steps", path.display());
+                        continue;
+                    }
+                };
+                println!(
+                    "  {}: pc={} ap={} fp={}",
+                    path.display(),
+                    entry.pc,
+                    entry.ap,
+                    entry.fp
+                );
+                if let Some(instruction) = memories
+                    .get(i)
+                    .and_then(|memory| decode_at(memory, entry.pc))
+                {
+                    println!("    {instruction}");
+                }
+            }
+            if memories.is_empty() {
+                println!("  Pass --memory_files to decode the instructions at that step");
+            }
+        }
+    }
+
+    if !memories.is_empty() {
+        if let Some(addr) = cairo_run::find_memory_mismatch(&memories[0], &memories[1]) {
+            equal = false;
+            println!("Memories differ at address {addr}:");
+            for (path, memory) in args.memory_files.iter().zip(&memories) {
+                match memory.get(&addr) {
+                    Some(value) => println!("  {}: {value}", path.display()),
+                    None => println!("  {}: unset", path.display()),
+                }
+            }
+        }
+    }
+
+    if !equal {
+        process::exit(1);
+    }
+    println!("No differences found");
+    Ok(())
+}
@@ -0,0 +1,27 @@
+#![deny(warnings)]
+use cairo_vm::types::program::Program;
+use cairo_vm::vm::decoding::disassembler;
+use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
+use cairo_vm::vm::errors::runner_errors::RunnerError;
+use clap::{Parser, ValueHint};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+/// Prints the bytecode of a compiled Cairo program as assembly, annotated with its labels,
+/// hints and source locations.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(value_parser, value_hint=ValueHint::FilePath)]
+    filename: PathBuf,
+}
+
+fn main() -> Result<(), CairoRunError> {
+    let args = Args::parse();
+    let program = Program::from_file(&args.filename, None)?;
+    let mut writer = BufWriter::new(io::stdout().lock());
+    disassembler::write_disassembly(&program, &mut writer)
+        .and_then(|_| writer.flush())
+        .map_err(|_| RunnerError::WriteFail)?;
+    Ok(())
+}
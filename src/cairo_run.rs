@@ -1,22 +1,86 @@
+use crate::stdlib::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    prelude::*,
+    time::Duration,
+};
+#[cfg(feature = "std")]
+use crate::{air_private_input::AirPrivateInput, air_public_input::PublicInput};
 use crate::{
     hint_processor::hint_processor_definition::HintProcessor,
-    types::program::Program,
+    types::{
+        contract_class::ContractClass, errors::program_errors::ProgramError, layout::CairoLayout,
+        program::Program, relocatable::MaybeRelocatable,
+    },
     vm::{
         errors::{
-            cairo_run_errors::CairoRunError, runner_errors::RunnerError, vm_exception::VmException,
+            cairo_run_errors::CairoRunError, runner_errors::RunnerError,
+            vm_errors::VirtualMachineError, vm_exception::VmException,
+        },
+        runners::{
+            cairo_pie::CairoPie,
+            cairo_runner::{CairoArg, CairoRunner, ExecutionResources, SegmentInfo},
         },
-        runners::cairo_runner::CairoRunner,
         trace::trace_entry::RelocatedTraceEntry,
-        vm_core::VirtualMachine,
+        vm_core::{RunLimits, VirtualMachine},
     },
 };
-use felt::{Felt, FeltOps};
+use felt::{Felt, FeltOps, NewFelt, PRIME_STR};
+use num_traits::ToPrimitive;
+use serde::Serialize;
+#[cfg(feature = "std")]
 use std::{
-    fs::File,
+    fs::{self, File},
     io::{self, BufWriter, Error, ErrorKind, Write},
     path::Path,
 };
 
+pub struct CairoRunConfig<'a> {
+    pub entrypoint: &'a str,
+    pub trace_enabled: bool,
+    /// Writes the program output to stdout at the end of the run. Ignored without `std`.
+    pub print_output: bool,
+    pub layout: &'a str,
+    /// Layout used instead of the predefined one named by `layout`, if set.
+    pub custom_layout: Option<&'a CairoLayout>,
+    pub proof_mode: bool,
+    /// Arguments passed to the entrypoint after the builtin pointers.
+    pub args: &'a [CairoArg],
+    pub run_limits: RunLimits,
+    /// Signatures added to the ecdsa builtin before the run, by the offset of the public key
+    /// they sign in the builtin's segment.
+    pub signatures: &'a [(usize, (Felt, Felt))],
+}
+
+impl<'a> Default for CairoRunConfig<'a> {
+    fn default() -> Self {
+        CairoRunConfig {
+            entrypoint: "main",
+            trace_enabled: false,
+            print_output: false,
+            layout: "plain",
+            custom_layout: None,
+            proof_mode: false,
+            args: &[],
+            run_limits: RunLimits::default(),
+            signatures: &[],
+        }
+    }
+}
+
+impl<'a> CairoRunConfig<'a> {
+    /// Returns the layout of the run, which is `custom_layout` if set, or else the predefined
+    /// layout named by `layout`.
+    pub fn cairo_layout(&self) -> Result<CairoLayout, RunnerError> {
+        match self.custom_layout {
+            Some(layout) => Ok(layout.clone()),
+            None => CairoLayout::from_name(self.layout)
+                .ok_or_else(|| RunnerError::InvalidLayoutName(self.layout.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 pub fn cairo_run(
     path: &Path,
     entrypoint: &str,
@@ -25,35 +89,189 @@ pub fn cairo_run(
     layout: &str,
     proof_mode: bool,
     hint_executor: &mut dyn HintProcessor,
-) -> Result<CairoRunner, CairoRunError> {
-    let program = match Program::from_file(path, Some(entrypoint)) {
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let cairo_run_config = CairoRunConfig {
+        entrypoint,
+        trace_enabled,
+        print_output,
+        layout,
+        proof_mode,
+        ..Default::default()
+    };
+    cairo_run_with_config(path, &cairo_run_config, hint_executor)
+}
+
+/// Like `cairo_run`, with the options that it doesn't take, such as the arguments of the
+/// entrypoint or the run limits, given through `cairo_run_config`.
+#[cfg(feature = "std")]
+pub fn cairo_run_with_config(
+    path: &Path,
+    cairo_run_config: &CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let program = match Program::from_file(path, Some(cairo_run_config.entrypoint)) {
         Ok(program) => program,
         Err(error) => return Err(CairoRunError::Program(error)),
     };
 
-    let mut cairo_runner = CairoRunner::new(&program, layout, proof_mode)?;
-    let mut vm = VirtualMachine::new(trace_enabled);
-    let end = cairo_runner.initialize(&mut vm)?;
+    let mut cairo_runner = CairoRunner::new_with_layout(
+        &program,
+        cairo_run_config.cairo_layout()?,
+        cairo_run_config.proof_mode,
+    );
+    let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
+    vm.set_run_limits(cairo_run_config.run_limits);
+    let end = cairo_runner.initialize_with_args(&mut vm, cairo_run_config.args)?;
+    add_signatures(&mut vm, cairo_run_config.signatures)?;
 
     cairo_runner
         .run_until_pc(end, &mut vm, hint_executor)
         .map_err(|err| VmException::from_vm_error(&cairo_runner, &vm, err))?;
-    cairo_runner.end_run(false, false, &mut vm, hint_executor)?;
+    finalize_run(
+        &mut cairo_runner,
+        &mut vm,
+        cairo_run_config.print_output,
+        hint_executor,
+    )?;
+
+    Ok((cairo_runner, vm))
+}
+
+/// Adds precomputed signatures to the ecdsa builtin of an initialized run, so the program can
+/// write the public keys and messages they sign without calling the hint that adds them. Each
+/// signature is given with the offset of its public key in the builtin's segment.
+pub fn add_signatures(
+    vm: &mut VirtualMachine,
+    signatures: &[(usize, (Felt, Felt))],
+) -> Result<(), CairoRunError> {
+    if signatures.is_empty() {
+        return Ok(());
+    }
+    let signature_builtin = vm.get_signature_builtin()?;
+    let base = signature_builtin.base();
+    for (offset, signature) in signatures {
+        signature_builtin.add_signature((base, *offset).into(), signature)?;
+    }
+    Ok(())
+}
+
+/// Runs the program of a Cairo PIE and checks that the run reproduces the PIE. Only the layout,
+/// trace, output and run limits options of `cairo_run_config` are used.
+pub fn cairo_run_pie(
+    cairo_pie: &CairoPie,
+    cairo_run_config: &CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let prime = &cairo_pie.metadata.program.prime;
+    if prime != PRIME_STR {
+        return Err(ProgramError::PrimeDiffers(prime.clone()).into());
+    }
+    let program = Program::from(&cairo_pie.metadata.program);
+
+    let mut cairo_runner =
+        CairoRunner::new_with_layout(&program, cairo_run_config.cairo_layout()?, false);
+    let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
+    vm.set_run_limits(cairo_run_config.run_limits);
+    let end = cairo_runner.initialize_from_cairo_pie(&mut vm, cairo_pie)?;
+
+    cairo_runner
+        .run_until_pc(end, &mut vm, hint_executor)
+        .map_err(|err| VmException::from_vm_error(&cairo_runner, &vm, err))?;
+    finalize_run(
+        &mut cairo_runner,
+        &mut vm,
+        cairo_run_config.print_output,
+        hint_executor,
+    )?;
+    cairo_pie.check_matches(&cairo_runner.get_cairo_pie(&vm)?)?;
+
+    Ok((cairo_runner, vm))
+}
+
+/// Runs the external entrypoint of a contract class with the given selector and calldata, and
+/// returns its retdata. The entrypoint receives a new segment as its syscall pointer, so
+/// syscalls need a hint processor that handles them, such as `SyscallHintProcessor`. Only the
+/// layout, trace and run limits options of `cairo_run_config` are used.
+pub fn cairo_run_contract_entrypoint(
+    contract_class: &ContractClass,
+    selector: &Felt,
+    calldata: &[Felt],
+    cairo_run_config: &CairoRunConfig,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<Vec<Felt>, CairoRunError> {
+    let entrypoint = contract_class.get_external_entrypoint(selector)?;
+
+    let mut cairo_runner = CairoRunner::new_with_layout(
+        &contract_class.program,
+        cairo_run_config.cairo_layout()?,
+        false,
+    );
+    let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
+    vm.set_run_limits(cairo_run_config.run_limits);
+    cairo_runner.initialize_builtins(&mut vm)?;
+    cairo_runner.initialize_segments(&mut vm, None);
+
+    // The wrapper of an external function takes the selector, a pointer to the syscall
+    // pointer followed by the builtin pointers, and the calldata.
+    let mut os_context = vec![MaybeRelocatable::from(vm.add_memory_segment())];
+    for (_, builtin_runner) in vm.get_builtin_runners() {
+        os_context.extend(builtin_runner.initial_stack());
+    }
+    let selector = MaybeRelocatable::from(selector);
+    let calldata_size = MaybeRelocatable::from(Felt::new(calldata.len()));
+    let calldata: Vec<MaybeRelocatable> = calldata.iter().map(MaybeRelocatable::from).collect();
+    let args: Vec<&dyn Any> = vec![&selector, &os_context, &calldata_size, &calldata];
+    cairo_runner
+        .run_from_entrypoint(
+            entrypoint.offset,
+            args,
+            false,
+            true,
+            true,
+            &mut vm,
+            hint_executor,
+        )
+        .map_err(|err| VmException::from_vm_error(&cairo_runner, &vm, err))?;
+
+    // It returns the size of the retdata and a pointer to it.
+    let return_values = vm.get_return_values(2)?;
+    let retdata_size = return_values[0]
+        .get_int_ref()?
+        .to_usize()
+        .ok_or(VirtualMachineError::BigintToUsizeFail)?;
+    let retdata_ptr = return_values[1].get_relocatable()?;
+    Ok(vm
+        .get_integer_range(&retdata_ptr, retdata_size)?
+        .into_iter()
+        .map(|value| value.into_owned())
+        .collect())
+}
+
+/// Ends a run that already reached its final pc and relocates its memory and trace.
+/// Used by `cairo_run` and by runs driven step by step, such as the debugger's.
+pub fn finalize_run(
+    cairo_runner: &mut CairoRunner,
+    vm: &mut VirtualMachine,
+    print_output: bool,
+    hint_executor: &mut dyn HintProcessor,
+) -> Result<(), CairoRunError> {
+    cairo_runner.end_run(false, false, vm, hint_executor)?;
 
     vm.verify_auto_deductions()?;
-    if proof_mode {
-        cairo_runner.read_return_values(&vm)?;
-        cairo_runner.finalize_segments(&mut vm)?;
+    cairo_runner.read_return_values(vm)?;
+    if cairo_runner.proof_mode {
+        cairo_runner.finalize_segments(vm)?;
     }
-    cairo_runner.relocate(&mut vm)?;
+    cairo_runner.relocate(vm)?;
 
     if print_output {
-        write_output(&mut cairo_runner, &mut vm)?;
+        #[cfg(feature = "std")]
+        write_output(cairo_runner, vm)?;
     }
-
-    Ok(cairo_runner)
+    Ok(())
 }
 
+#[cfg(feature = "std")]
 pub fn write_output(
     cairo_runner: &mut CairoRunner,
     vm: &mut VirtualMachine,
@@ -67,8 +285,79 @@ pub fn write_output(
         .map_err(|_| CairoRunError::Runner(RunnerError::WriteFail))
 }
 
+/// Resource usage and results of a finished run, as written by `cairo-rs-run --report`.
+#[derive(Debug, Serialize)]
+pub struct ExecutionReport {
+    pub execution_resources: ExecutionResources,
+    pub builtin_segments: HashMap<&'static str, SegmentInfo>,
+    pub segment_sizes: Vec<usize>,
+    pub output: Vec<String>,
+    pub run_time_secs: f64,
+}
+
+impl ExecutionReport {
+    /// Builds the report of a run that already went through `finalize_run`.
+    pub fn new(
+        cairo_runner: &CairoRunner,
+        vm: &mut VirtualMachine,
+        run_time: Duration,
+    ) -> Result<ExecutionReport, CairoRunError> {
+        let output = cairo_runner
+            .get_output_values(vm)?
+            .iter()
+            .map(|value| value.to_bigint().to_string())
+            .collect();
+
+        Ok(ExecutionReport {
+            execution_resources: cairo_runner.get_execution_resources(vm)?,
+            builtin_segments: cairo_runner.get_builtin_segments_info(vm)?,
+            segment_sizes: vm.segments.compute_effective_sizes(&vm.memory).clone(),
+            output,
+            run_time_secs: run_time.as_secs_f64(),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn write_execution_report(report: &ExecutionReport, report_file: &Path) -> io::Result<()> {
+    write_json(report, report_file)
+}
+
+#[cfg(feature = "std")]
+pub fn write_air_public_input(
+    public_input: &PublicInput,
+    public_input_file: &Path,
+) -> io::Result<()> {
+    write_json(public_input, public_input_file)
+}
+
+/// Writes the AIR private input of a run, which refers to the trace and memory files by their
+/// paths. The prover resolves these paths from its own working directory, so they should be
+/// absolute.
+#[cfg(feature = "std")]
+pub fn write_air_private_input(
+    private_input: &AirPrivateInput,
+    trace_file: &Path,
+    memory_file: &Path,
+    private_input_file: &Path,
+) -> io::Result<()> {
+    write_json(
+        &private_input.to_file(trace_file, memory_file),
+        private_input_file,
+    )
+}
+
+#[cfg(feature = "std")]
+fn write_json<T: Serialize>(value: &T, path: &Path) -> io::Result<()> {
+    let file = File::create(path)?;
+    let mut buffer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut buffer, value)?;
+    buffer.flush()
+}
+
 /// Writes a trace as a binary file. Bincode encodes to little endian by default and each trace
 /// entry is composed of 3 usize values that are padded to always reach 64 bit size.
+#[cfg(feature = "std")]
 pub fn write_binary_trace(
     relocated_trace: &[RelocatedTraceEntry],
     trace_file: &Path,
@@ -88,6 +377,13 @@ pub fn write_binary_trace(
     buffer.flush()
 }
 
+// Size in bytes of a trace entry, made of the ap, fp and pc registers.
+#[cfg(feature = "std")]
+const TRACE_ENTRY_SIZE: usize = 24;
+// Size in bytes of a memory cell, made of an 8-byte address and a 32-byte value.
+#[cfg(feature = "std")]
+const MEMORY_CELL_SIZE: usize = 40;
+
 /*
    Writes a binary memory file with the relocated memory as input.
    The memory pairs (address, value) are encoded and concatenated in the file
@@ -96,6 +392,7 @@ pub fn write_binary_trace(
    * address -> 8-byte encoded
    * value -> 32-byte encoded
 */
+#[cfg(feature = "std")]
 pub fn write_binary_memory(
     relocated_memory: &[Option<Felt>],
     memory_file: &Path,
@@ -120,6 +417,7 @@ pub fn write_binary_memory(
 }
 
 // encodes a given memory cell.
+#[cfg(feature = "std")]
 fn encode_relocated_memory(memory_bytes: &mut Vec<u8>, addr: usize, memory_cell: &Felt) {
     // append memory address to bytes vector using a 8 bytes representation
     let mut addr_bytes = (addr as u64).to_le_bytes().to_vec();
@@ -131,6 +429,102 @@ fn encode_relocated_memory(memory_bytes: &mut Vec<u8>, addr: usize, memory_cell:
     memory_bytes.append(&mut value_bytes);
 }
 
+/// Reads a trace written by `write_binary_trace`.
+#[cfg(feature = "std")]
+pub fn read_binary_trace(trace_file: &Path) -> io::Result<Vec<RelocatedTraceEntry>> {
+    let bytes = fs::read(trace_file)?;
+    if bytes.len() % TRACE_ENTRY_SIZE != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Trace file size {} is not a multiple of the entry size",
+                bytes.len()
+            ),
+        ));
+    }
+
+    let read_register = |bytes: &[u8]| -> io::Result<usize> {
+        let mut register = [0; 8];
+        register.copy_from_slice(bytes);
+        usize::try_from(u64::from_le_bytes(register))
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    };
+    bytes
+        .chunks(TRACE_ENTRY_SIZE)
+        .map(|entry| {
+            Ok(RelocatedTraceEntry {
+                ap: read_register(&entry[0..8])?,
+                fp: read_register(&entry[8..16])?,
+                pc: read_register(&entry[16..24])?,
+            })
+        })
+        .collect()
+}
+
+/// Reads a memory file written by `write_binary_memory`, returning the relocated memory by
+/// address. The addresses come from the file, so they're kept in a map rather than used to size
+/// a vector.
+#[cfg(feature = "std")]
+pub fn read_binary_memory(memory_file: &Path) -> io::Result<BTreeMap<usize, Felt>> {
+    let bytes = fs::read(memory_file)?;
+    if bytes.len() % MEMORY_CELL_SIZE != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Memory file size {} is not a multiple of the memory cell size",
+                bytes.len()
+            ),
+        ));
+    }
+
+    let mut relocated_memory = BTreeMap::new();
+    for cell in bytes.chunks(MEMORY_CELL_SIZE) {
+        let mut addr_bytes = [0; 8];
+        addr_bytes.copy_from_slice(&cell[..8]);
+        let addr = usize::try_from(u64::from_le_bytes(addr_bytes))
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let value_bytes: Vec<u8> = cell[8..].iter().rev().copied().collect();
+
+        if relocated_memory
+            .insert(addr, Felt::from_bytes_be(&value_bytes))
+            .is_some()
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Address {addr} has two values"),
+            ));
+        }
+    }
+    Ok(relocated_memory)
+}
+
+/// Returns the first step at which two traces differ, including the step at which the
+/// shortest one ends.
+pub fn find_trace_mismatch(
+    trace: &[RelocatedTraceEntry],
+    other_trace: &[RelocatedTraceEntry],
+) -> Option<usize> {
+    match trace.iter().zip(other_trace).position(|(a, b)| a != b) {
+        Some(step) => Some(step),
+        None if trace.len() != other_trace.len() => Some(trace.len().min(other_trace.len())),
+        None => None,
+    }
+}
+
+/// Returns the first address at which two relocated memories differ, treating addresses missing
+/// from a memory as unset.
+pub fn find_memory_mismatch(
+    memory: &BTreeMap<usize, Felt>,
+    other_memory: &BTreeMap<usize, Felt>,
+) -> Option<usize> {
+    memory
+        .keys()
+        .chain(other_memory.keys())
+        .filter(|&&addr| memory.get(&addr) != other_memory.get(&addr))
+        .min()
+        .copied()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,10 +533,12 @@ mod tests {
             builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
             hint_processor_definition::HintProcessor,
         },
+        types::relocatable::Relocatable,
         utils::test_utils::*,
+        vm::{errors::cairo_pie_errors::CairoPieError, runners::cairo_pie::BuiltinAdditionalData},
     };
     use felt::NewFelt;
-    use std::io::Read;
+    use std::io::{Cursor, Read};
 
     fn run_test_program(
         program_path: &Path,
@@ -309,6 +705,191 @@ mod tests {
         assert!(compare_files(cairo_rs_memory_path, expected_memory_path).is_ok());
     }
 
+    #[test]
+    fn execution_report() {
+        let program_path = Path::new("cairo_programs/bitwise_output.json");
+        let report_path = Path::new("cairo_programs/trace_memory/bitwise_output_report.json");
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (cairo_runner, mut vm) = cairo_run(
+            program_path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            &mut hint_processor,
+        )
+        .unwrap();
+
+        let report =
+            ExecutionReport::new(&cairo_runner, &mut vm, Duration::from_millis(1500)).unwrap();
+        assert_eq!(report.execution_resources.n_steps, vm.current_step);
+        assert_eq!(
+            report.execution_resources.builtin_instance_counter["output"],
+            report.output.len()
+        );
+        assert_eq!(
+            report.builtin_segments["output"].size,
+            report.segment_sizes[report.builtin_segments["output"].index as usize]
+        );
+        assert!(report.builtin_segments.contains_key("bitwise"));
+        assert_eq!(report.run_time_secs, 1.5);
+
+        assert!(write_execution_report(&report, report_path).is_ok());
+        let written: serde_json::Value =
+            serde_json::from_reader(File::open(report_path).unwrap()).unwrap();
+        assert_eq!(
+            written["execution_resources"]["n_steps"],
+            serde_json::json!(vm.current_step)
+        );
+        assert_eq!(
+            written["output"].as_array().unwrap().len(),
+            report.output.len()
+        );
+    }
+
+    #[test]
+    fn cairo_pie_round_trip() {
+        let program_path = Path::new("cairo_programs/pedersen_test.json");
+        let cairo_run_config = CairoRunConfig {
+            layout: "all",
+            ..Default::default()
+        };
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (cairo_runner, vm) =
+            cairo_run_with_config(program_path, &cairo_run_config, &mut hint_processor).unwrap();
+
+        let cairo_pie = cairo_runner.get_cairo_pie(&vm).unwrap();
+        let metadata = &cairo_pie.metadata;
+        assert_eq!(
+            metadata.program_segment,
+            SegmentInfo {
+                index: 0,
+                size: metadata.program.data.len()
+            }
+        );
+        assert_eq!(metadata.builtin_segments["output"].size, 1);
+        assert_eq!(
+            metadata.ret_pc_segment.index,
+            metadata.ret_fp_segment.index + 1
+        );
+        assert!(metadata.extra_segments.is_empty());
+        assert_eq!(
+            cairo_pie.additional_data["pedersen_builtin"],
+            BuiltinAdditionalData::Hash(vec![Relocatable::from((
+                metadata.builtin_segments["pedersen"].index,
+                2
+            ))])
+        );
+
+        let mut buffer = Cursor::new(Vec::new());
+        cairo_pie.write_zip(&mut buffer).unwrap();
+        buffer.set_position(0);
+        let read_cairo_pie = CairoPie::read_zip(buffer).unwrap();
+        assert_eq!(read_cairo_pie, cairo_pie);
+
+        assert!(cairo_run_pie(&read_cairo_pie, &cairo_run_config, &mut hint_processor).is_ok());
+    }
+
+    #[test]
+    fn cairo_run_pie_mismatch() {
+        let program_path = Path::new("cairo_programs/pedersen_test.json");
+        let cairo_run_config = CairoRunConfig {
+            layout: "all",
+            ..Default::default()
+        };
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (cairo_runner, vm) =
+            cairo_run_with_config(program_path, &cairo_run_config, &mut hint_processor).unwrap();
+
+        let mut cairo_pie = cairo_runner.get_cairo_pie(&vm).unwrap();
+        cairo_pie.execution_resources.n_steps += 1;
+        assert!(matches!(
+            cairo_run_pie(&cairo_pie, &cairo_run_config, &mut hint_processor),
+            Err(CairoRunError::CairoPie(CairoPieError::Mismatch(
+                "execution resources"
+            )))
+        ));
+    }
+
+    #[test]
+    fn read_binary_trace_and_memory_files() {
+        let program_path = Path::new("cairo_programs/struct.json");
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let (mut cairo_runner, mut vm) =
+            run_test_program(program_path, &mut hint_processor).unwrap();
+        assert!(cairo_runner.relocate(&mut vm).is_ok());
+
+        let trace =
+            read_binary_trace(Path::new("cairo_programs/trace_memory/cairo_trace_struct")).unwrap();
+        assert_eq!(Some(trace), cairo_runner.relocated_trace);
+        let memory =
+            read_binary_memory(Path::new("cairo_programs/trace_memory/cairo_memory_struct"))
+                .unwrap();
+        let relocated_memory = cairo_runner
+            .relocated_memory
+            .iter()
+            .enumerate()
+            .filter_map(|(addr, value)| Some((addr, value.clone()?)))
+            .collect();
+        assert_eq!(find_memory_mismatch(&memory, &relocated_memory), None);
+    }
+
+    #[test]
+    fn read_binary_memory_large_address() {
+        let memory_path = Path::new("cairo_programs/trace_memory/large_address.memory");
+        let mut cell = [0_u8; MEMORY_CELL_SIZE];
+        cell[..8].copy_from_slice(&(1_u64 << 40).to_le_bytes());
+        cell[8] = 7;
+        fs::write(memory_path, cell).unwrap();
+        let memory = read_binary_memory(memory_path).unwrap();
+        assert_eq!(
+            memory.into_iter().collect::<Vec<_>>(),
+            vec![(1 << 40, Felt::new(7))]
+        );
+    }
+
+    #[test]
+    fn read_binary_memory_invalid_size() {
+        let memory_path = Path::new("cairo_programs/trace_memory/invalid_size.memory");
+        fs::write(memory_path, [0_u8; MEMORY_CELL_SIZE + 1]).unwrap();
+        let error = read_binary_memory(memory_path).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn find_trace_mismatch_steps() {
+        let entry = |pc| RelocatedTraceEntry { ap: 10, fp: 10, pc };
+        let trace = [entry(1), entry(2), entry(4)];
+        assert_eq!(find_trace_mismatch(&trace, &trace), None);
+        assert_eq!(
+            find_trace_mismatch(&trace, &[entry(1), entry(3), entry(4)]),
+            Some(1)
+        );
+        assert_eq!(find_trace_mismatch(&trace, &trace[..2]), Some(2));
+    }
+
+    #[test]
+    fn find_memory_mismatch_addresses() {
+        let memory = BTreeMap::from([(1, Felt::new(1)), (2, Felt::new(2))]);
+        assert_eq!(find_memory_mismatch(&memory, &memory), None);
+        assert_eq!(
+            find_memory_mismatch(
+                &memory,
+                &BTreeMap::from([(1, Felt::new(1)), (2, Felt::new(3))])
+            ),
+            Some(2)
+        );
+        assert_eq!(
+            find_memory_mismatch(&memory, &BTreeMap::from([(1, Felt::new(1))])),
+            Some(2)
+        );
+        assert_eq!(
+            find_memory_mismatch(&BTreeMap::from([(1, Felt::new(1))]), &memory),
+            Some(2)
+        );
+    }
+
     #[test]
     fn run_with_no_trace() {
         let program_path = Path::new("cairo_programs/struct.json");
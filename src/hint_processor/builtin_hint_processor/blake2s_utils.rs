@@ -1,3 +1,4 @@
+use crate::stdlib::{borrow::Cow, collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -16,7 +17,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::ToPrimitive;
-use std::{borrow::Cow, collections::HashMap};
 
 fn get_fixed_size_u32_array<const T: usize>(
     h_range: &Vec<Cow<Felt>>,
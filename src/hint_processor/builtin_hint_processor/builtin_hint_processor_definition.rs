@@ -1,3 +1,4 @@
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*, sync::Arc};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -20,6 +21,7 @@ use crate::{
                 add_segment, enter_scope, exit_scope, memcpy_continue_copying, memcpy_enter_scope,
             },
             memset_utils::{memset_continue_loop, memset_enter_scope},
+            poseidon_utils::elements_over_x,
             pow_utils::pow,
             secp::{
                 bigint_utils::{bigint_to_uint256, nondet_bigint3},
@@ -37,6 +39,7 @@ use crate::{
             segments::{relocate_segment, temporary_array},
             set::set_add,
             sha256_utils::{sha256_finalize, sha256_input, sha256_main},
+            signature::verify_ecdsa_signature,
             squash_dict_utils::{
                 squash_dict, squash_dict_inner_assert_len_keys,
                 squash_dict_inner_check_access_index, squash_dict_inner_continue_loop,
@@ -56,10 +59,10 @@ use crate::{
     },
     serde::deserialize_program::ApTracking,
     types::exec_scope::ExecutionScopes,
+    utils::assert_send,
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
 };
 use felt::Felt;
-use std::{any::Any, collections::HashMap, rc::Rc};
 
 pub struct HintProcessorData {
     pub code: String,
@@ -87,12 +90,15 @@ pub struct HintFunc(
                 &ApTracking,
                 &HashMap<String, Felt>,
             ) -> Result<(), HintError>
+            + Send
             + Sync,
     >,
 );
 pub struct BuiltinHintProcessor {
-    pub extra_hints: HashMap<String, Rc<HintFunc>>,
+    pub extra_hints: HashMap<String, Arc<HintFunc>>,
 }
+
+const _: () = assert_send::<BuiltinHintProcessor>();
 impl BuiltinHintProcessor {
     pub fn new_empty() -> Self {
         BuiltinHintProcessor {
@@ -100,11 +106,11 @@ impl BuiltinHintProcessor {
         }
     }
 
-    pub fn new(extra_hints: HashMap<String, Rc<HintFunc>>) -> Self {
+    pub fn new(extra_hints: HashMap<String, Arc<HintFunc>>) -> Self {
         BuiltinHintProcessor { extra_hints }
     }
 
-    pub fn add_hint(&mut self, hint_code: String, hint_func: Rc<HintFunc>) {
+    pub fn add_hint(&mut self, hint_code: String, hint_func: Arc<HintFunc>) {
         self.extra_hints.insert(hint_code, hint_func);
     }
 }
@@ -114,7 +120,7 @@ impl HintProcessor for BuiltinHintProcessor {
         &mut self,
         vm: &mut VirtualMachine,
         exec_scopes: &mut ExecutionScopes,
-        hint_data: &Box<dyn Any>,
+        hint_data: &Box<dyn Any + Send>,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), HintError> {
         let hint_data = hint_data
@@ -430,6 +436,15 @@ impl HintProcessor for BuiltinHintProcessor {
             hint_code::TEMPORARY_ARRAY => {
                 temporary_array(vm, &hint_data.ids_data, &hint_data.ap_tracking)
             }
+            hint_code::NONDET_ELEMENTS_OVER_TEN => {
+                elements_over_x(vm, &hint_data.ids_data, &hint_data.ap_tracking, 10)
+            }
+            hint_code::NONDET_ELEMENTS_OVER_TWO => {
+                elements_over_x(vm, &hint_data.ids_data, &hint_data.ap_tracking, 2)
+            }
+            hint_code::VERIFY_ECDSA_SIGNATURE => {
+                verify_ecdsa_signature(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+            }
             code => Err(HintError::UnknownHint(code.to_string())),
         }
     }
@@ -619,7 +634,7 @@ mod tests {
         let mut vm = vm!();
         // Create new vm scope with dummy variable
         let mut exec_scopes = ExecutionScopes::new();
-        let a_value: Box<dyn Any> = Box::new(Felt::one());
+        let a_value: Box<dyn Any + Send> = Box::new(Felt::one());
         exec_scopes.enter_scope(HashMap::from([(String::from("a"), a_value)]));
         // Initialize memory segments
         add_segments!(vm, 1);
@@ -826,8 +841,8 @@ mod tests {
     #[test]
     fn add_hint_add_same_hint_twice() {
         let mut hint_processor = BuiltinHintProcessor::new_empty();
-        let hint_func = Rc::new(HintFunc(Box::new(enter_scope)));
-        hint_processor.add_hint(String::from("enter_scope_custom_a"), Rc::clone(&hint_func));
+        let hint_func = Arc::new(HintFunc(Box::new(enter_scope)));
+        hint_processor.add_hint(String::from("enter_scope_custom_a"), Arc::clone(&hint_func));
         hint_processor.add_hint(String::from("enter_scope_custom_b"), hint_func);
         let mut vm = vm!();
         let exec_scopes = exec_scopes_ref!();
@@ -1,3 +1,4 @@
+use crate::stdlib::{borrow::Cow, collections::HashMap, ops::Add, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -14,7 +15,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::{ToPrimitive, Zero};
-use std::{borrow::Cow, collections::HashMap, ops::Add};
 
 // Constants in package "starkware.cairo.common.cairo_keccak.keccak".
 const BYTES_IN_WORD: &str = "starkware.cairo.common.cairo_keccak.keccak.BYTES_IN_WORD";
@@ -1,8 +1,13 @@
+use crate::stdlib::{
+    any::Any,
+    collections::HashMap,
+    prelude::*,
+    sync::{self, Arc, Mutex},
+};
 use crate::{
     types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
 };
-use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::{
     any_box,
@@ -51,11 +56,11 @@ pub fn dict_new(
     let initial_dict = copy_initial_dict(exec_scopes).ok_or(HintError::NoInitialDict)?;
     //Check if there is a dict manager in scope, create it if there isnt one
     let base = if let Ok(dict_manager) = exec_scopes.get_dict_manager() {
-        dict_manager.borrow_mut().new_dict(vm, initial_dict)?
+        sync::lock(&dict_manager).new_dict(vm, initial_dict)?
     } else {
         let mut dict_manager = DictManager::new();
         let base = dict_manager.new_dict(vm, initial_dict)?;
-        exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
+        exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)));
         base
     };
     insert_value_into_ap(vm, base)
@@ -84,13 +89,11 @@ pub fn default_dict_new(
     let initial_dict = copy_initial_dict(exec_scopes);
     //Check if there is a dict manager in scope, create it if there isnt one
     let base = if let Ok(dict_manager) = exec_scopes.get_dict_manager() {
-        dict_manager
-            .borrow_mut()
-            .new_default_dict(vm, &default_value, initial_dict)?
+        sync::lock(&dict_manager).new_default_dict(vm, &default_value, initial_dict)?
     } else {
         let mut dict_manager = DictManager::new();
         let base = dict_manager.new_default_dict(vm, &default_value, initial_dict)?;
-        exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
+        exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)));
         base
     };
     insert_value_into_ap(vm, base)
@@ -110,7 +113,7 @@ pub fn dict_read(
     let key = get_maybe_relocatable_from_var_name("key", vm, ids_data, ap_tracking)?;
     let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
-    let mut dict = dict_manager_ref.borrow_mut();
+    let mut dict = sync::lock(&dict_manager_ref);
     let tracker = dict.get_tracker_mut(&dict_ptr)?;
     tracker.current_ptr.offset += DICT_ACCESS_SIZE;
     let value = tracker.get_value(&key)?;
@@ -134,7 +137,7 @@ pub fn dict_write(
     let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
     //Get tracker for dictionary
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
-    let mut dict = dict_manager_ref.borrow_mut();
+    let mut dict = sync::lock(&dict_manager_ref);
     let tracker = dict.get_tracker_mut(&dict_ptr)?;
     //dict_ptr is a pointer to a struct, with the ordered fields (key, prev_value, new_value),
     //dict_ptr.prev_value will be equal to dict_ptr + 1
@@ -175,7 +178,7 @@ pub fn dict_update(
 
     //Get tracker for dictionary
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
-    let mut dict = dict_manager_ref.borrow_mut();
+    let mut dict = sync::lock(&dict_manager_ref);
     let tracker = dict.get_tracker_mut(&dict_ptr)?;
     //Check that prev_value is equal to the current value at the given key
     let current_value = tracker.get_value(&key)?;
@@ -210,8 +213,8 @@ pub fn dict_squash_copy_dict(
 ) -> Result<(), HintError> {
     let dict_accesses_end = get_ptr_from_var_name("dict_accesses_end", vm, ids_data, ap_tracking)?;
     let dict_manager_ref = exec_scopes.get_dict_manager()?;
-    let dict_manager = dict_manager_ref.borrow();
-    let dict_copy: Box<dyn Any> = Box::new(
+    let dict_manager = sync::lock(&dict_manager_ref);
+    let dict_copy: Box<dyn Any + Send> = Box::new(
         dict_manager
             .get_tracker(&dict_accesses_end)?
             .get_dictionary_copy(),
@@ -240,9 +243,7 @@ pub fn dict_squash_update_ptr(
     let squashed_dict_start =
         get_ptr_from_var_name("squashed_dict_start", vm, ids_data, ap_tracking)?;
     let squashed_dict_end = get_ptr_from_var_name("squashed_dict_end", vm, ids_data, ap_tracking)?;
-    exec_scopes
-        .get_dict_manager()?
-        .borrow_mut()
+    sync::lock(&exec_scopes.get_dict_manager()?)
         .get_tracker_mut(&squashed_dict_start)?
         .current_ptr = squashed_dict_end;
     Ok(())
@@ -290,10 +291,7 @@ mod tests {
         //Check the dict manager has a tracker for segment 0,
         //and that tracker contains the ptr (1,0) and an empty dict
         assert_eq!(
-            exec_scopes
-                .get_dict_manager()
-                .unwrap()
-                .borrow()
+            sync::lock(&exec_scopes.get_dict_manager().unwrap())
                 .trackers
                 .get(&1),
             Some(&DictTracker::new_empty(&relocatable!(1, 0)))
@@ -384,7 +382,7 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 3;
         //Create manager
-        let mut exec_scopes = scope![("dict_manager", Rc::new(RefCell::new(DictManager::new())))];
+        let mut exec_scopes = scope![("dict_manager", Arc::new(Mutex::new(DictManager::new())))];
 
         //Insert ids into memory
         vm.memory = memory![((1, 0), 6), ((1, 2), (2, 0))];
@@ -414,10 +412,7 @@ mod tests {
         //Check the dict manager has a tracker for segment 0,
         //and that tracker contains the ptr (0,0) and an empty dict
         assert_eq!(
-            exec_scopes
-                .get_dict_manager()
-                .unwrap()
-                .borrow()
+            sync::lock(&exec_scopes.get_dict_manager().unwrap())
                 .trackers
                 .get(&0),
             Some(&DictTracker::new_default_dict(
@@ -838,7 +833,7 @@ mod tests {
         vm.run_context.fp = 1;
         //Create manager
         let dict_manager = DictManager::new();
-        let mut exec_scopes = scope![("dict_manager", Rc::new(RefCell::new(dict_manager)))];
+        let mut exec_scopes = scope![("dict_manager", Arc::new(Mutex::new(dict_manager)))];
 
         vm.memory = memory![((1, 0), (2, 0))];
         add_segments!(vm, 1);
@@ -858,7 +853,7 @@ mod tests {
         vm.run_context.fp = 2;
         //Create manager
         let dict_manager = DictManager::new();
-        let mut exec_scopes = scope![("dict_manager", Rc::new(RefCell::new(dict_manager)))];
+        let mut exec_scopes = scope![("dict_manager", Arc::new(Mutex::new(dict_manager)))];
         vm.memory = memory![((1, 0), (2, 0)), ((1, 1), (2, 3))];
         add_segments!(vm, 1);
         //Create ids
@@ -939,10 +934,7 @@ mod tests {
             current_ptr: Relocatable::from((2, 3)),
         };
         assert_eq!(
-            exec_scopes
-                .get_dict_manager()
-                .unwrap()
-                .borrow()
+            sync::lock(&exec_scopes.get_dict_manager().unwrap())
                 .trackers
                 .get(&2),
             Some(&expeced_dict_tracker)
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use crate::stdlib::collections::HashMap;
 
 use crate::{
     types::relocatable::{MaybeRelocatable, Relocatable},
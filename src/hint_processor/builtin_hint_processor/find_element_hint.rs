@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -16,7 +17,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::{Signed, ToPrimitive};
-use std::collections::HashMap;
 
 pub fn find_element(
     vm: &mut VirtualMachine,
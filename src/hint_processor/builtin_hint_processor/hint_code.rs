@@ -539,3 +539,12 @@ pub(crate) const RELOCATE_SEGMENT: &str =
     r#"memory.add_relocation_rule(src_ptr=ids.src_ptr, dest_ptr=ids.dest_ptr)"#;
 
 pub(crate) const TEMPORARY_ARRAY: &str = r#"ids.temporary_array = segments.add_temp_segment()"#;
+
+pub(crate) const NONDET_ELEMENTS_OVER_TEN: &str =
+    r#"memory[ap] = to_felt_or_relocatable(ids.elements_end - ids.elements >= 10)"#;
+
+pub(crate) const NONDET_ELEMENTS_OVER_TWO: &str =
+    r#"memory[ap] = to_felt_or_relocatable(ids.elements_end - ids.elements >= 2)"#;
+
+pub(crate) const VERIFY_ECDSA_SIGNATURE: &str =
+    r#"ecdsa_builtin.add_signature(ids.ecdsa_ptr.address_, (ids.signature_r, ids.signature_s))"#;
@@ -6,12 +6,12 @@ use crate::hint_processor::hint_processor_utils::{
     get_integer_from_reference, get_maybe_relocatable_from_reference,
 };
 use crate::serde::deserialize_program::ApTracking;
+use crate::stdlib::collections::HashMap;
+use crate::stdlib::{borrow::Cow, prelude::*};
 use crate::types::relocatable::MaybeRelocatable;
 use crate::types::relocatable::Relocatable;
 use crate::vm::errors::hint_errors::HintError;
 use crate::vm::vm_core::VirtualMachine;
-use std::borrow::Cow;
-use std::collections::HashMap;
 
 //Inserts value into the address of the given ids variable
 pub fn insert_value_from_var_name(
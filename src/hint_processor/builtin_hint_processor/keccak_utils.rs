@@ -1,3 +1,4 @@
+use crate::stdlib::{cmp, collections::HashMap, ops::Shl, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -18,7 +19,6 @@ use crate::{
 use felt::{Felt, FeltOps};
 use num_traits::{One, Signed, ToPrimitive};
 use sha3::{Digest, Keccak256};
-use std::{cmp, collections::HashMap, ops::Shl};
 
 /* Implements hint:
    %{
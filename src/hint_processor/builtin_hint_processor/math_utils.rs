@@ -1,3 +1,9 @@
+use crate::stdlib::{
+    any::Any,
+    collections::HashMap,
+    ops::{Shl, Shr},
+    prelude::*,
+};
 use crate::{
     any_box,
     hint_processor::{
@@ -20,11 +26,6 @@ use num_bigint::BigUint;
 use num_integer::Integer;
 use num_traits::One;
 use num_traits::{Num, Signed, Zero};
-use std::{
-    any::Any,
-    collections::HashMap,
-    ops::{Shl, Shr},
-};
 
 //Implements hint: memory[ap] = 0 if 0 <= (ids.a % PRIME) < range_check_builtin.bound else 1
 pub fn is_nn(
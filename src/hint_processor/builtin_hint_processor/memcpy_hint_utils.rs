@@ -1,3 +1,4 @@
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -11,7 +12,6 @@ use crate::{
 };
 use felt::Felt;
 use num_traits::{One, Zero};
-use std::{any::Any, collections::HashMap};
 
 //Implements hint: memory[ap] = segments.add()
 pub fn add_segment(vm: &mut VirtualMachine) -> Result<(), HintError> {
@@ -39,7 +39,7 @@ pub fn memcpy_enter_scope(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
-    let len: Box<dyn Any> =
+    let len: Box<dyn Any + Send> =
         Box::new(get_integer_from_var_name("len", vm, ids_data, ap_tracking)?.into_owned());
     exec_scopes.enter_scope(HashMap::from([(String::from("n"), len)]));
     Ok(())
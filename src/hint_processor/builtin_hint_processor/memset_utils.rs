@@ -1,3 +1,4 @@
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -11,7 +12,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::Signed;
-use std::{any::Any, collections::HashMap};
 
 //  Implements hint:
 //  %{ vm_enter_scope({'n': ids.n}) %}
@@ -21,7 +21,7 @@ pub fn memset_enter_scope(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), HintError> {
-    let n: Box<dyn Any> =
+    let n: Box<dyn Any + Send> =
         Box::new(get_integer_from_var_name("n", vm, ids_data, ap_tracking)?.into_owned());
     exec_scopes.enter_scope(HashMap::from([(String::from("n"), n)]));
     Ok(())
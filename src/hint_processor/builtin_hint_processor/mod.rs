@@ -11,11 +11,13 @@ pub mod keccak_utils;
 pub mod math_utils;
 pub mod memcpy_hint_utils;
 pub mod memset_utils;
+pub mod poseidon_utils;
 pub mod pow_utils;
 pub mod secp;
 pub mod segments;
 pub mod set;
 pub mod sha256_utils;
+pub mod signature;
 pub mod squash_dict_utils;
 pub mod uint256_utils;
 pub mod usort;
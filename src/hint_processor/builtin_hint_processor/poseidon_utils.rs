@@ -0,0 +1,128 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_into_ap},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::{Felt, NewFelt};
+
+/*
+Implements hints:
+    Cairo code:
+    if (nondet %{ ids.elements_end - ids.elements >= 10 %} != 0) {
+    if (nondet %{ ids.elements_end - ids.elements >= 2 %} != 0) {
+
+    Compiled code:
+    memory[ap] = to_felt_or_relocatable(ids.elements_end - ids.elements >= 10)
+    memory[ap] = to_felt_or_relocatable(ids.elements_end - ids.elements >= 2)
+*/
+pub fn elements_over_x(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    x: usize,
+) -> Result<(), HintError> {
+    let elements_end = get_ptr_from_var_name("elements_end", vm, ids_data, ap_tracking)?;
+    let elements = get_ptr_from_var_name("elements", vm, ids_data, ap_tracking)?;
+    let n_elements = elements_end.sub(&elements)?;
+    insert_value_into_ap(vm, Felt::new((n_elements >= x) as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{
+            errors::vm_errors::VirtualMachineError, vm_core::VirtualMachine,
+            vm_memory::memory::Memory,
+        },
+    };
+    use std::any::Any;
+
+    #[test]
+    fn run_elements_over_ten_true() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 12)), ((1, 1), (2, 2))];
+        //Initialize ap and fp
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["elements_end", "elements"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::NONDET_ELEMENTS_OVER_TEN),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 2), 1)];
+    }
+
+    #[test]
+    fn run_elements_over_ten_false() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 11)), ((1, 1), (2, 2))];
+        //Initialize ap and fp
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["elements_end", "elements"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::NONDET_ELEMENTS_OVER_TEN),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 2), 0)];
+    }
+
+    #[test]
+    fn run_elements_over_two_true() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 4)), ((1, 1), (2, 2))];
+        //Initialize ap and fp
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["elements_end", "elements"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::NONDET_ELEMENTS_OVER_TWO),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 2), 1)];
+    }
+
+    #[test]
+    fn run_elements_over_two_false() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 3)), ((1, 1), (2, 2))];
+        //Initialize ap and fp
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["elements_end", "elements"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::NONDET_ELEMENTS_OVER_TWO),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 2), 0)];
+    }
+
+    #[test]
+    fn run_elements_over_ten_different_segments() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (3, 12)), ((1, 1), (2, 2))];
+        //Initialize ap and fp
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let ids_data = ids_data!["elements_end", "elements"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::NONDET_ELEMENTS_OVER_TEN),
+            Err(HintError::Internal(VirtualMachineError::DiffIndexSub))
+        );
+    }
+}
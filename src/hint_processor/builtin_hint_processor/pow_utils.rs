@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -10,7 +11,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_integer::Integer;
-use std::collections::HashMap;
 
 /*
 Implements hint:
@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -14,7 +15,6 @@ use crate::{
     },
 };
 use felt::{Felt, NewFelt};
-use std::collections::HashMap;
 /*
 Implements hint:
 %{
@@ -1,3 +1,8 @@
+use crate::stdlib::{
+    collections::HashMap,
+    ops::{BitAnd, Shl},
+    prelude::*,
+};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -17,10 +22,6 @@ use felt::{Felt, FeltOps};
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{One, Zero};
-use std::{
-    collections::HashMap,
-    ops::{BitAnd, Shl},
-};
 
 /*
 Implements hint:
@@ -1,4 +1,5 @@
 use super::secp_utils::pack_from_var_name;
+use crate::stdlib::{collections::HashMap, ops::Shl, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -16,7 +17,6 @@ use felt::{Felt, FeltOps, NewFelt};
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{One, Zero};
-use std::{collections::HashMap, ops::Shl};
 
 /*
 Implements hint:
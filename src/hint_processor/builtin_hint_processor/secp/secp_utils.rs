@@ -1,3 +1,5 @@
+use crate::stdlib::ops::Shl;
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::get_relocatable_from_var_name,
@@ -10,8 +12,6 @@ use crate::{
 use felt::{Felt, FeltOps};
 use num_bigint::BigInt;
 use num_traits::Zero;
-use std::collections::HashMap;
-use std::ops::Shl;
 
 // Constants in package "starkware.cairo.common.cairo_secp.constants".
 pub const BASE_86: &str = "starkware.cairo.common.cairo_secp.constants.BASE";
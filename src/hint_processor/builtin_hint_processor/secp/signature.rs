@@ -1,3 +1,8 @@
+use crate::stdlib::{
+    collections::HashMap,
+    ops::{Shl, Shr},
+    prelude::*,
+};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -16,10 +21,6 @@ use felt::{Felt, FeltOps};
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::One;
-use std::{
-    collections::HashMap,
-    ops::{Shl, Shr},
-};
 
 /* Implements hint:
 from starkware.cairo.common.cairo_secp.secp_utils import N, pack
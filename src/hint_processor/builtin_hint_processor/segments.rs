@@ -4,10 +4,10 @@ use crate::hint_processor::{
     hint_processor_definition::HintReference,
 };
 use crate::serde::deserialize_program::ApTracking;
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::vm::errors::hint_errors::HintError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
-use std::collections::HashMap;
 
 /*
 Implements hint:
@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -14,7 +15,6 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::{One, ToPrimitive, Zero};
-use std::collections::HashMap;
 
 pub fn set_add(
     vm: &mut VirtualMachine,
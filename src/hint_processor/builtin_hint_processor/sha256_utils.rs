@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -14,7 +15,6 @@ use felt::{Felt, NewFelt};
 use generic_array::GenericArray;
 use num_traits::{One, Zero};
 use sha2::compress256;
-use std::collections::HashMap;
 
 use crate::hint_processor::hint_processor_definition::HintReference;
 
@@ -0,0 +1,132 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_integer_from_var_name, get_ptr_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+
+/*
+Implements hint:
+%{ ecdsa_builtin.add_signature(ids.ecdsa_ptr.address_, (ids.signature_r, ids.signature_s)) %}
+*/
+pub fn verify_ecdsa_signature(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let signature_r = get_integer_from_var_name("signature_r", vm, ids_data, ap_tracking)?;
+    let signature_s = get_integer_from_var_name("signature_s", vm, ids_data, ap_tracking)?;
+    let signature = (signature_r.into_owned(), signature_s.into_owned());
+    let ecdsa_ptr = get_ptr_from_var_name("ecdsa_ptr", vm, ids_data, ap_tracking)?;
+    vm.get_signature_builtin()?
+        .add_signature(ecdsa_ptr, &signature)
+        .map_err(VirtualMachineError::MemoryError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            builtin_hint_processor::{
+                builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+                hint_code,
+            },
+            hint_processor_definition::HintProcessor,
+        },
+        types::{
+            exec_scope::ExecutionScopes,
+            instance_definitions::ecdsa_instance_def::EcdsaInstanceDef,
+            relocatable::MaybeRelocatable,
+        },
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError,
+            runners::{
+                builtin_runner::{BuiltinRunner, SignatureBuiltinRunner},
+                cairo_pie::BuiltinAdditionalData,
+            },
+            vm_memory::memory::Memory,
+        },
+    };
+    use felt::{Felt, FeltOps};
+    use std::any::Any;
+
+    #[test]
+    fn verify_ecdsa_signature_valid() {
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(
+            "ecdsa".to_string(),
+            SignatureBuiltinRunner::new(&EcdsaInstanceDef::new(512), true).into(),
+        )];
+        let signature_r = Felt::parse_bytes(
+            b"3086480810278599376317923499561306189851900463386393948998357832163236918254",
+            10,
+        )
+        .unwrap();
+        let signature_s = Felt::parse_bytes(
+            b"598673427589502599949712887611119751108407514580626464031881322743364689811",
+            10,
+        )
+        .unwrap();
+        vm.memory = memory![((1, 0), (2, 0))];
+        vm.memory
+            .insert_value(&(1, 1).into(), signature_r.clone())
+            .unwrap();
+        vm.memory
+            .insert_value(&(1, 2).into(), signature_s.clone())
+            .unwrap();
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["ecdsa_ptr", "signature_r", "signature_s"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::VERIFY_ECDSA_SIGNATURE),
+            Ok(())
+        );
+        let signature_builtin = match &vm.builtin_runners[0].1 {
+            BuiltinRunner::Signature(signature_builtin) => signature_builtin,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            signature_builtin.get_additional_data(),
+            BuiltinAdditionalData::Signature(vec![((2, 0).into(), (signature_r, signature_s))])
+        );
+    }
+
+    #[test]
+    fn verify_ecdsa_signature_no_ecdsa_builtin() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), 1), ((1, 2), 2)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["ecdsa_ptr", "signature_r", "signature_s"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::VERIFY_ECDSA_SIGNATURE),
+            Err(HintError::Internal(VirtualMachineError::NoSignatureBuiltin))
+        );
+    }
+
+    #[test]
+    fn verify_ecdsa_signature_ptr_is_not_relocatable() {
+        let mut vm = vm!();
+        vm.builtin_runners = vec![(
+            "ecdsa".to_string(),
+            SignatureBuiltinRunner::new(&EcdsaInstanceDef::new(512), true).into(),
+        )];
+        vm.memory = memory![((1, 0), 3), ((1, 1), 1), ((1, 2), 2)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["ecdsa_ptr", "signature_r", "signature_s"];
+        assert!(matches!(
+            run_hint!(vm, ids_data, hint_code::VERIFY_ECDSA_SIGNATURE),
+            Err(HintError::Internal(
+                VirtualMachineError::ExpectedRelocatable(_)
+            ))
+        ));
+    }
+}
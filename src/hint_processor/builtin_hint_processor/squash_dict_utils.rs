@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::{
@@ -19,7 +20,6 @@ use crate::{
 use felt::{Felt, NewFelt};
 use num_integer::Integer;
 use num_traits::{One, ToPrimitive, Zero};
-use std::collections::HashMap;
 
 fn get_access_indices(
     exec_scopes: &mut ExecutionScopes,
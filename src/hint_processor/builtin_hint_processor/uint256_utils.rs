@@ -1,3 +1,8 @@
+use crate::stdlib::{
+    collections::HashMap,
+    ops::{Shl, Shr},
+    prelude::*,
+};
 use crate::{
     hint_processor::builtin_hint_processor::hint_utils::{
         get_integer_from_var_name, get_relocatable_from_var_name, insert_value_from_var_name,
@@ -11,10 +16,6 @@ use crate::{
 use felt::{Felt, FeltOps, NewFelt};
 use num_integer::div_rem;
 use num_traits::{One, Signed, Zero};
-use std::{
-    collections::HashMap,
-    ops::{Shl, Shr},
-};
 /*
 Implements hint:
 %{
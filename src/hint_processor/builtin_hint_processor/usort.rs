@@ -1,3 +1,4 @@
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*};
 use crate::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
@@ -11,11 +12,10 @@ use crate::{
 };
 use felt::{Felt, NewFelt};
 use num_traits::{ToPrimitive, Zero};
-use std::{any::Any, collections::HashMap};
 
 pub fn usort_enter_scope(exec_scopes: &mut ExecutionScopes) -> Result<(), HintError> {
     if let Ok(usort_max_size) = exec_scopes.get::<Felt>("usort_max_size") {
-        let boxed_max_size: Box<dyn Any> = Box::new(usort_max_size);
+        let boxed_max_size: Box<dyn Any + Send> = Box::new(usort_max_size);
         exec_scopes.enter_scope(HashMap::from([(
             "usort_max_size".to_string(),
             boxed_max_size,
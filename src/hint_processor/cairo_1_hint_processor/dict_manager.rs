@@ -0,0 +1,127 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+
+use crate::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use felt::{Felt, NewFelt};
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+///Manages the dictionaries of a Cairo 1 program, kept in the execution scopes.
+///Dictionaries are numbered in the order they were allocated, which is their index in the
+///segment arena.
+pub struct DictManagerExecScope {
+    pub trackers: Vec<DictTrackerExecScope>,
+    //Index of the tracker of the dictionary on each segment.
+    pub segment_to_tracker: HashMap<isize, usize>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+///Tracks the values of a Cairo 1 dictionary. Keys not written yet read as zero.
+pub struct DictTrackerExecScope {
+    pub data: HashMap<Felt, MaybeRelocatable>,
+    pub start: Relocatable,
+}
+
+impl DictManagerExecScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    //Allocates a segment for a new dictionary and returns its base.
+    pub fn new_default_dict(&mut self, vm: &mut VirtualMachine) -> Relocatable {
+        let start = vm.add_memory_segment();
+        self.segment_to_tracker
+            .insert(start.segment_index, self.trackers.len());
+        self.trackers.push(DictTrackerExecScope {
+            data: HashMap::new(),
+            start,
+        });
+        start
+    }
+
+    pub fn get_dict_infos_index(&self, dict_ptr: &Relocatable) -> Result<usize, HintError> {
+        self.segment_to_tracker
+            .get(&dict_ptr.segment_index)
+            .copied()
+            .ok_or(HintError::NoDictTracker(dict_ptr.segment_index))
+    }
+
+    fn get_tracker_mut(
+        &mut self,
+        dict_ptr: &Relocatable,
+    ) -> Result<&mut DictTrackerExecScope, HintError> {
+        let index = self.get_dict_infos_index(dict_ptr)?;
+        Ok(&mut self.trackers[index])
+    }
+
+    pub fn get_from_tracker(
+        &self,
+        dict_ptr: &Relocatable,
+        key: &Felt,
+    ) -> Result<MaybeRelocatable, HintError> {
+        let index = self.get_dict_infos_index(dict_ptr)?;
+        Ok(self.trackers[index]
+            .data
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| MaybeRelocatable::from(Felt::new(0))))
+    }
+
+    pub fn insert_to_tracker(
+        &mut self,
+        dict_ptr: &Relocatable,
+        key: Felt,
+        value: MaybeRelocatable,
+    ) -> Result<(), HintError> {
+        self.get_tracker_mut(dict_ptr)?.data.insert(key, value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    #[test]
+    fn new_default_dict_numbers_dictionaries() {
+        let mut vm = vm!();
+        let mut dict_manager = DictManagerExecScope::new();
+        let first = dict_manager.new_default_dict(&mut vm);
+        let second = dict_manager.new_default_dict(&mut vm);
+        assert_eq!(
+            dict_manager.get_dict_infos_index(&(second + 3_usize)),
+            Ok(1)
+        );
+        assert_eq!(dict_manager.get_dict_infos_index(&first), Ok(0));
+    }
+
+    #[test]
+    fn dictionary_values_default_to_zero() {
+        let mut vm = vm!();
+        let mut dict_manager = DictManagerExecScope::new();
+        let dict = dict_manager.new_default_dict(&mut vm);
+        assert_eq!(
+            dict_manager.insert_to_tracker(&dict, Felt::new(1), MaybeRelocatable::from((2, 0))),
+            Ok(())
+        );
+        assert_eq!(
+            dict_manager.get_from_tracker(&(dict + 3_usize), &Felt::new(1)),
+            Ok(MaybeRelocatable::from((2, 0)))
+        );
+        assert_eq!(
+            dict_manager.get_from_tracker(&dict, &Felt::new(2)),
+            Ok(MaybeRelocatable::from(Felt::new(0)))
+        );
+    }
+
+    #[test]
+    fn get_dict_infos_index_no_dictionary() {
+        let dict_manager = DictManagerExecScope::new();
+        assert_eq!(
+            dict_manager.get_dict_infos_index(&Relocatable::from((1, 0))),
+            Err(HintError::NoDictTracker(1))
+        );
+    }
+}
@@ -0,0 +1,872 @@
+use super::{
+    dict_manager::DictManagerExecScope,
+    hints::{BinOpOperand, CellRef, DerefOrImmediate, Hint, Operation, ResOperand},
+};
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*};
+use crate::{
+    any_box,
+    hint_processor::hint_processor_definition::{HintProcessor, HintReference},
+    serde::deserialize_program::ApTracking,
+    types::{
+        exec_scope::ExecutionScopes,
+        instruction::Register,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
+};
+use felt::{Felt, FeltOps, NewFelt};
+use num_bigint::{BigInt, BigUint};
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+// Name of the execution scope variable holding the `DictManagerExecScope`.
+const DICT_MANAGER: &str = "dict_manager_exec_scope";
+
+/// A Cairo 1 hint, compiled into a closure that runs it against the VM.
+#[allow(clippy::type_complexity)]
+pub struct Cairo1HintFunc(
+    pub Box<dyn Fn(&mut VirtualMachine, &mut ExecutionScopes) -> Result<(), HintError> + Send>,
+);
+
+impl Cairo1HintFunc {
+    fn new(
+        func: impl Fn(&mut VirtualMachine, &mut ExecutionScopes) -> Result<(), HintError>
+            + Send
+            + 'static,
+    ) -> Self {
+        Cairo1HintFunc(Box::new(func))
+    }
+}
+
+/// Executes the structured hints of Cairo 1 programs, such as the ones of a
+/// `CasmContractClass`. Each hint code is the JSON serialization of a `Hint`.
+#[derive(Debug, Default)]
+pub struct Cairo1HintProcessor;
+
+impl Cairo1HintProcessor {
+    pub fn new() -> Self {
+        Cairo1HintProcessor
+    }
+}
+
+impl HintProcessor for Cairo1HintProcessor {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any + Send>,
+        _constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let hint_func = hint_data
+            .downcast_ref::<Cairo1HintFunc>()
+            .ok_or(HintError::WrongHintData)?;
+        hint_func.0(vm, exec_scopes)
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        _ap_tracking_data: &ApTracking,
+        _reference_ids: &HashMap<String, usize>,
+        _references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any + Send>, VirtualMachineError> {
+        let hint: Hint = serde_json::from_str(hint_code)
+            .map_err(|_| VirtualMachineError::CompileHintFail(hint_code.to_string()))?;
+        Ok(any_box!(compile_hint_func(hint)))
+    }
+}
+
+fn compile_hint_func(hint: Hint) -> Cairo1HintFunc {
+    match hint {
+        Hint::AllocSegment { dst } => Cairo1HintFunc::new(move |vm, _| alloc_segment(vm, &dst)),
+        Hint::TestLessThan { lhs, rhs, dst } => {
+            Cairo1HintFunc::new(move |vm, _| test_less_than(vm, &lhs, &rhs, &dst, false))
+        }
+        Hint::TestLessThanOrEqual { lhs, rhs, dst } => {
+            Cairo1HintFunc::new(move |vm, _| test_less_than(vm, &lhs, &rhs, &dst, true))
+        }
+        Hint::DivMod {
+            lhs,
+            rhs,
+            quotient,
+            remainder,
+        } => Cairo1HintFunc::new(move |vm, _| div_mod(vm, &lhs, &rhs, &quotient, &remainder)),
+        Hint::WideMul128 {
+            lhs,
+            rhs,
+            high,
+            low,
+        } => Cairo1HintFunc::new(move |vm, _| wide_mul_128(vm, &lhs, &rhs, &high, &low)),
+        Hint::U256InvModN {
+            b0,
+            b1,
+            n0,
+            n1,
+            g0_or_no_inv,
+            g1_option,
+            s_or_r0,
+            s_or_r1,
+            t_or_k0,
+            t_or_k1,
+        } => Cairo1HintFunc::new(move |vm, _| {
+            u256_inv_mod_n(
+                vm,
+                [&b0, &b1, &n0, &n1],
+                [
+                    &g0_or_no_inv,
+                    &g1_option,
+                    &s_or_r0,
+                    &s_or_r1,
+                    &t_or_k0,
+                    &t_or_k1,
+                ],
+            )
+        }),
+        Hint::SquareRoot { value, dst } => {
+            Cairo1HintFunc::new(move |vm, _| square_root(vm, &value, &dst))
+        }
+        Hint::AllocDictFeltTo { dict_manager_ptr } => {
+            Cairo1HintFunc::new(move |vm, exec_scopes| {
+                alloc_dict_felt_to(vm, exec_scopes, &dict_manager_ptr)
+            })
+        }
+        Hint::Felt252DictRead {
+            dict_ptr,
+            key,
+            value_dst,
+        } => Cairo1HintFunc::new(move |vm, exec_scopes| {
+            felt252_dict_read(vm, exec_scopes, &dict_ptr, &key, &value_dst)
+        }),
+        Hint::Felt252DictWrite {
+            dict_ptr,
+            key,
+            value,
+        } => Cairo1HintFunc::new(move |vm, exec_scopes| {
+            felt252_dict_write(vm, exec_scopes, &dict_ptr, &key, &value)
+        }),
+        Hint::GetSegmentArenaIndex {
+            dict_end_ptr,
+            dict_index,
+        } => Cairo1HintFunc::new(move |vm, exec_scopes| {
+            get_segment_arena_index(vm, exec_scopes, &dict_end_ptr, &dict_index)
+        }),
+        Hint::DebugPrint { start, end } => {
+            Cairo1HintFunc::new(move |vm, _| debug_print(vm, &start, &end))
+        }
+    }
+}
+
+fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
+    let base = match cell_ref.register {
+        Register::AP => vm.get_ap(),
+        Register::FP => vm.get_fp(),
+    };
+    base + cell_ref.offset as i32
+}
+
+fn get_cell_val(vm: &VirtualMachine, cell_ref: &CellRef) -> Result<Felt, VirtualMachineError> {
+    Ok(vm
+        .get_integer(&cell_ref_to_relocatable(cell_ref, vm))?
+        .into_owned())
+}
+
+fn get_deref_or_immediate_val(
+    vm: &VirtualMachine,
+    operand: &DerefOrImmediate,
+) -> Result<Felt, VirtualMachineError> {
+    match operand {
+        DerefOrImmediate::Deref(cell_ref) => get_cell_val(vm, cell_ref),
+        DerefOrImmediate::Immediate(value) => Ok(value.clone()),
+    }
+}
+
+fn get_val(vm: &VirtualMachine, res_operand: &ResOperand) -> Result<Felt, VirtualMachineError> {
+    match res_operand {
+        ResOperand::Deref(cell_ref) => get_cell_val(vm, cell_ref),
+        ResOperand::DoubleDeref(cell_ref, offset) => {
+            let ptr = vm.get_relocatable(&cell_ref_to_relocatable(cell_ref, vm))?;
+            Ok(vm.get_integer(&(ptr + *offset as i32))?.into_owned())
+        }
+        ResOperand::Immediate(value) => Ok(value.clone()),
+        ResOperand::BinOp(bin_op) => {
+            let a = get_cell_val(vm, &bin_op.a)?;
+            let b = get_deref_or_immediate_val(vm, &bin_op.b)?;
+            Ok(match bin_op.op {
+                Operation::Add => a + b,
+                Operation::Mul => a * b,
+            })
+        }
+    }
+}
+
+// Like `get_val`, but the cells read may also hold pointers.
+fn get_maybe(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<MaybeRelocatable, VirtualMachineError> {
+    let addr = match res_operand {
+        ResOperand::Deref(cell_ref) => cell_ref_to_relocatable(cell_ref, vm),
+        ResOperand::DoubleDeref(cell_ref, offset) => {
+            vm.get_relocatable(&cell_ref_to_relocatable(cell_ref, vm))? + *offset as i32
+        }
+        ResOperand::Immediate(value) => return Ok(MaybeRelocatable::from(value.clone())),
+        ResOperand::BinOp(_) => return get_val(vm, res_operand).map(MaybeRelocatable::from),
+    };
+    vm.get_maybe(&addr)?
+        .ok_or_else(|| VirtualMachineError::MemoryGet(MaybeRelocatable::from(addr)))
+}
+
+// Pointer operands are a cell holding the pointer, possibly plus an offset.
+fn get_ptr(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<Relocatable, VirtualMachineError> {
+    match res_operand {
+        ResOperand::Deref(cell_ref) => vm.get_relocatable(&cell_ref_to_relocatable(cell_ref, vm)),
+        ResOperand::DoubleDeref(cell_ref, offset) => {
+            let ptr = vm.get_relocatable(&cell_ref_to_relocatable(cell_ref, vm))?;
+            vm.get_relocatable(&(ptr + *offset as i32))
+        }
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Add,
+            a,
+            b,
+        }) => {
+            let ptr = vm.get_relocatable(&cell_ref_to_relocatable(a, vm))?;
+            ptr.add_int(&get_deref_or_immediate_val(vm, b)?)
+        }
+        _ => Err(VirtualMachineError::ExpectedRelocatable(
+            MaybeRelocatable::from(get_val(vm, res_operand)?),
+        )),
+    }
+}
+
+fn insert_value_into_cell(
+    vm: &mut VirtualMachine,
+    cell_ref: &CellRef,
+    value: impl Into<MaybeRelocatable>,
+) -> Result<(), HintError> {
+    let address = cell_ref_to_relocatable(cell_ref, vm);
+    vm.insert_value(&address, value)?;
+    Ok(())
+}
+
+// Splits a value into its low and high 128 bits.
+fn split_128<T: Integer + Clone + From<u128> + One>(value: &T) -> (T, T) {
+    let pow_2_128 = T::from(u128::MAX) + T::one();
+    let (high, low) = value.div_rem(&pow_2_128);
+    (low, high)
+}
+
+/*
+Implements hint:
+    memory[dst] = segments.add()
+*/
+fn alloc_segment(vm: &mut VirtualMachine, dst: &CellRef) -> Result<(), HintError> {
+    let segment = vm.add_memory_segment();
+    insert_value_into_cell(vm, dst, segment)
+}
+
+/*
+Implements hints:
+    memory[dst] = memory[lhs] < memory[rhs]
+    memory[dst] = memory[lhs] <= memory[rhs]
+*/
+fn test_less_than(
+    vm: &mut VirtualMachine,
+    lhs: &ResOperand,
+    rhs: &ResOperand,
+    dst: &CellRef,
+    or_equal: bool,
+) -> Result<(), HintError> {
+    let lhs = get_val(vm, lhs)?;
+    let rhs = get_val(vm, rhs)?;
+    let result = if or_equal { lhs <= rhs } else { lhs < rhs };
+    insert_value_into_cell(vm, dst, Felt::new(result as usize))
+}
+
+/*
+Implements hint:
+    (memory[quotient], memory[remainder]) = divmod(memory[lhs], memory[rhs])
+*/
+fn div_mod(
+    vm: &mut VirtualMachine,
+    lhs: &ResOperand,
+    rhs: &ResOperand,
+    quotient: &CellRef,
+    remainder: &CellRef,
+) -> Result<(), HintError> {
+    let lhs = get_val(vm, lhs)?.to_biguint();
+    let rhs = get_val(vm, rhs)?.to_biguint();
+    if rhs.is_zero() {
+        return Err(VirtualMachineError::DividedByZero.into());
+    }
+    let (q, r) = lhs.div_rem(&rhs);
+    insert_value_into_cell(vm, quotient, Felt::from(q))?;
+    insert_value_into_cell(vm, remainder, Felt::from(r))
+}
+
+/*
+Implements hint:
+    (memory[high], memory[low]) = divmod(memory[lhs] * memory[rhs], 2**128)
+*/
+fn wide_mul_128(
+    vm: &mut VirtualMachine,
+    lhs: &ResOperand,
+    rhs: &ResOperand,
+    high: &CellRef,
+    low: &CellRef,
+) -> Result<(), HintError> {
+    let product = get_val(vm, lhs)?.to_biguint() * get_val(vm, rhs)?.to_biguint();
+    let (product_low, product_high) = split_128::<BigUint>(&product);
+    insert_value_into_cell(vm, high, Felt::from(product_high))?;
+    insert_value_into_cell(vm, low, Felt::from(product_low))
+}
+
+/*
+Implements hint:
+    from starkware.python.math_utils import igcdex
+    b = memory[b0] + (memory[b1] << 128)
+    n = memory[n0] + (memory[n1] << 128)
+    (_, r, g) = igcdex(n, b)
+    if n == 1:
+        (g, s, t) = (1, b, 1)
+    elif g != 1:
+        if g % 2 == 0:
+            g = 2
+        (s, t) = (b // g, n // g)
+    else:
+        r %= n
+        (g, s, t) = (0, r, (r * b - 1) // n)
+    ...
+Each of g, s and t is written as its low and high 128 bits, except for the high bits of g
+when there's an inverse.
+*/
+fn u256_inv_mod_n(
+    vm: &mut VirtualMachine,
+    [b0, b1, n0, n1]: [&ResOperand; 4],
+    [g0_or_no_inv, g1_option, s_or_r0, s_or_r1, t_or_k0, t_or_k1]: [&CellRef; 6],
+) -> Result<(), HintError> {
+    let pow_2_128 = BigInt::from(u128::MAX) + 1_u32;
+    let get_bigint = |operand| get_val(vm, operand).map(|value| BigInt::from(value.to_biguint()));
+    let b = get_bigint(b0)? + get_bigint(b1)? * &pow_2_128;
+    let n = get_bigint(n0)? + get_bigint(n1)? * &pow_2_128;
+    let ExtendedGcd { gcd, y: r, .. } = n.extended_gcd(&b);
+
+    let (g, s, t) = if n.is_one() {
+        (Some(BigInt::one()), b, BigInt::one())
+    } else if !gcd.is_one() {
+        // Makes sure g0_or_no_inv is never zero when there's no inverse.
+        let g = if gcd.is_even() {
+            BigInt::from(2_u32)
+        } else {
+            gcd
+        };
+        let (s, t) = (&b / &g, &n / &g);
+        (Some(g), s, t)
+    } else {
+        let mut r = r % &n;
+        if r.is_negative() {
+            r += &n;
+        }
+        let k = (&r * &b - 1_u32) / &n;
+        (None, r, k)
+    };
+
+    match g {
+        Some(g) => {
+            let (g0, g1) = split_128(&g);
+            insert_value_into_cell(vm, g0_or_no_inv, Felt::from(g0))?;
+            insert_value_into_cell(vm, g1_option, Felt::from(g1))?;
+        }
+        None => insert_value_into_cell(vm, g0_or_no_inv, Felt::zero())?,
+    }
+    let (s0, s1) = split_128(&s);
+    insert_value_into_cell(vm, s_or_r0, Felt::from(s0))?;
+    insert_value_into_cell(vm, s_or_r1, Felt::from(s1))?;
+    let (t0, t1) = split_128(&t);
+    insert_value_into_cell(vm, t_or_k0, Felt::from(t0))?;
+    insert_value_into_cell(vm, t_or_k1, Felt::from(t1))
+}
+
+/*
+Implements hint:
+    import math
+    memory[dst] = math.isqrt(memory[value])
+*/
+fn square_root(
+    vm: &mut VirtualMachine,
+    value: &ResOperand,
+    dst: &CellRef,
+) -> Result<(), HintError> {
+    let value = get_val(vm, value)?.to_biguint();
+    insert_value_into_cell(vm, dst, Felt::from(value.sqrt()))
+}
+
+/*
+Implements hint:
+    if '__dict_manager' not in globals():
+        from starkware.cairo.common.dict import DictManager
+        __dict_manager = DictManager()
+    memory[ap] = __dict_manager.new_default_dict(segments, 0)
+    memory[memory[dict_manager_ptr - 3] + 3 * memory[dict_manager_ptr - 2]] = memory[ap]
+The dictionary manager pointer points right after the segment arena's info pointer, number
+of dictionaries and number of finalized dictionaries.
+*/
+fn alloc_dict_felt_to(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    dict_manager_ptr: &ResOperand,
+) -> Result<(), HintError> {
+    let dict_manager_address = get_ptr(vm, dict_manager_ptr)?;
+    let n_dicts = vm
+        .get_integer(&dict_manager_address.sub_usize(2)?)?
+        .to_usize()
+        .ok_or(HintError::BigintToUsizeFail)?;
+    let dict_infos_base = vm.get_relocatable(&dict_manager_address.sub_usize(3)?)?;
+
+    if exec_scopes
+        .get_ref::<DictManagerExecScope>(DICT_MANAGER)
+        .is_err()
+    {
+        exec_scopes.assign_or_update_variable(DICT_MANAGER, any_box!(DictManagerExecScope::new()));
+    }
+    let dict_manager = exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+    let new_dict = dict_manager.new_default_dict(vm);
+    vm.insert_value(&(dict_infos_base + 3 * n_dicts), new_dict)?;
+    Ok(())
+}
+
+/*
+Implements hint:
+    dict_tracker = __dict_manager.get_tracker(memory[dict_ptr])
+    dict_tracker.current_ptr += 3
+    memory[value_dst] = dict_tracker.data[memory[key]]
+*/
+fn felt252_dict_read(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    dict_ptr: &ResOperand,
+    key: &ResOperand,
+    value_dst: &CellRef,
+) -> Result<(), HintError> {
+    let dict_address = get_ptr(vm, dict_ptr)?;
+    let key = get_val(vm, key)?;
+    let value = exec_scopes
+        .get_ref::<DictManagerExecScope>(DICT_MANAGER)?
+        .get_from_tracker(&dict_address, &key)?;
+    insert_value_into_cell(vm, value_dst, value)
+}
+
+/*
+Implements hint:
+    dict_tracker = __dict_manager.get_tracker(memory[dict_ptr])
+    dict_tracker.current_ptr += 3
+    memory[memory[dict_ptr] + 1] = dict_tracker.data[memory[key]]
+    dict_tracker.data[memory[key]] = memory[value]
+*/
+fn felt252_dict_write(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    dict_ptr: &ResOperand,
+    key: &ResOperand,
+    value: &ResOperand,
+) -> Result<(), HintError> {
+    let dict_address = get_ptr(vm, dict_ptr)?;
+    let key = get_val(vm, key)?;
+    let value = get_maybe(vm, value)?;
+    let dict_manager = exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER)?;
+    let prev_value = dict_manager.get_from_tracker(&dict_address, &key)?;
+    vm.insert_value(&(dict_address + 1_usize), prev_value)?;
+    dict_manager.insert_to_tracker(&dict_address, key, value)
+}
+
+/*
+Implements hint:
+    memory[dict_index] = __dict_manager.get_dict_infos_index(memory[dict_end_ptr])
+*/
+fn get_segment_arena_index(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    dict_end_ptr: &ResOperand,
+    dict_index: &CellRef,
+) -> Result<(), HintError> {
+    let dict_address = get_ptr(vm, dict_end_ptr)?;
+    let index = exec_scopes
+        .get_ref::<DictManagerExecScope>(DICT_MANAGER)?
+        .get_dict_infos_index(&dict_address)?;
+    insert_value_into_cell(vm, dict_index, Felt::new(index))
+}
+
+/*
+Implements hint:
+    curr = memory[start]
+    end = memory[end]
+    while curr != end:
+        print(memory[curr])
+        curr += 1
+*/
+fn debug_print(
+    vm: &mut VirtualMachine,
+    start: &ResOperand,
+    end: &ResOperand,
+) -> Result<(), HintError> {
+    let start = get_ptr(vm, start)?;
+    let end = get_ptr(vm, end)?;
+    // Without std there's nowhere to print to, but the values are still read.
+    for value in vm.get_integer_range(&start, end.sub(&start)?)? {
+        #[cfg(feature = "std")]
+        println!("[DEBUG] {}", value);
+        #[cfg(not(feature = "std"))]
+        let _ = value;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        utils::test_utils::*,
+        vm::{errors::memory_errors::MemoryError, vm_memory::memory::Memory},
+    };
+
+    fn run_hint(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_code: &str,
+    ) -> Result<(), HintError> {
+        let mut hint_processor = Cairo1HintProcessor::new();
+        let hint_data = hint_processor
+            .compile_hint(
+                hint_code,
+                &ApTracking::new(),
+                &HashMap::new(),
+                &HashMap::new(),
+            )
+            .unwrap();
+        hint_processor.execute_hint(vm, exec_scopes, &hint_data, &HashMap::new())
+    }
+
+    #[test]
+    fn compile_unknown_hint() {
+        let hint_processor = Cairo1HintProcessor::new();
+        let hint_code = r#"{ "SystemCall": { "system": { "Immediate": "0x0" } } }"#;
+        assert!(matches!(
+            hint_processor.compile_hint(hint_code, &ApTracking::new(), &HashMap::new(), &HashMap::new()),
+            Err(VirtualMachineError::CompileHintFail(code)) if code == hint_code
+        ));
+    }
+
+    #[test]
+    fn run_alloc_segment() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let hint_code = r#"{ "AllocSegment": { "dst": { "register": "AP", "offset": 0 } } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), (2, 0))];
+        assert_eq!(vm.segments.num_segments, 3);
+    }
+
+    #[test]
+    fn run_test_less_than_or_equal() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let hint_code = r#"{ "TestLessThanOrEqual": {
+            "lhs": { "Immediate": "0x7" },
+            "rhs": { "Deref": { "register": "FP", "offset": -1 } },
+            "dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 1), 1)];
+    }
+
+    #[test]
+    fn run_test_less_than() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let hint_code = r#"{ "TestLessThan": {
+            "lhs": { "Immediate": "0x7" },
+            "rhs": { "Deref": { "register": "FP", "offset": -1 } },
+            "dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 1), 0)];
+    }
+
+    #[test]
+    fn run_div_mod() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 23), ((1, 1), 5)];
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        let hint_code = r#"{ "DivMod": {
+            "lhs": { "Deref": { "register": "FP", "offset": -2 } },
+            "rhs": { "Deref": { "register": "FP", "offset": -1 } },
+            "quotient": { "register": "AP", "offset": 0 },
+            "remainder": { "register": "AP", "offset": 1 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 2), 4), ((1, 3), 3)];
+    }
+
+    #[test]
+    fn run_div_mod_by_zero() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 23)];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let hint_code = r#"{ "DivMod": {
+            "lhs": { "Deref": { "register": "FP", "offset": -1 } },
+            "rhs": { "Immediate": "0x0" },
+            "quotient": { "register": "AP", "offset": 0 },
+            "remainder": { "register": "AP", "offset": 1 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Err(HintError::Internal(VirtualMachineError::DividedByZero))
+        );
+    }
+
+    #[test]
+    fn run_wide_mul_128() {
+        let mut vm = vm!();
+        // (2**127 + 3) * 6 = 2**128 * 3 + 18
+        vm.memory = memory![((1, 0), ("170141183460469231731687303715884105731", 10))];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let hint_code = r#"{ "WideMul128": {
+            "lhs": { "Deref": { "register": "FP", "offset": -1 } },
+            "rhs": { "Immediate": "0x6" },
+            "high": { "register": "AP", "offset": 0 },
+            "low": { "register": "AP", "offset": 1 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 1), 3), ((1, 2), 18)];
+    }
+
+    #[test]
+    fn run_square_root() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 99)];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let hint_code = r#"{ "SquareRoot": {
+            "value": { "BinOp": {
+                "op": "Add",
+                "a": { "register": "FP", "offset": -1 },
+                "b": { "Immediate": "0x1" }
+            } },
+            "dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 1), 10)];
+    }
+
+    const U256_INV_MOD_N: &str = r#"{ "U256InvModN": {
+        "b0": { "Deref": { "register": "FP", "offset": -2 } },
+        "b1": { "Immediate": "0x0" },
+        "n0": { "Deref": { "register": "FP", "offset": -1 } },
+        "n1": { "Immediate": "0x0" },
+        "g0_or_no_inv": { "register": "AP", "offset": 0 },
+        "g1_option": { "register": "AP", "offset": 1 },
+        "s_or_r0": { "register": "AP", "offset": 2 },
+        "s_or_r1": { "register": "AP", "offset": 3 },
+        "t_or_k0": { "register": "AP", "offset": 4 },
+        "t_or_k1": { "register": "AP", "offset": 5 }
+    } }"#;
+
+    #[test]
+    fn run_u256_inv_mod_n_inverse() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 3), ((1, 1), 7)];
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), U256_INV_MOD_N),
+            Ok(())
+        );
+        // 3 * 5 = 2 * 7 + 1
+        check_memory![
+            vm.memory,
+            ((1, 2), 0),
+            ((1, 4), 5),
+            ((1, 5), 0),
+            ((1, 6), 2),
+            ((1, 7), 0)
+        ];
+        assert_eq!(vm.get_maybe(&Relocatable::from((1, 3))), Ok(None));
+    }
+
+    #[test]
+    fn run_u256_inv_mod_n_no_inverse() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 6), ((1, 1), 9)];
+        vm.run_context.ap = 2;
+        vm.run_context.fp = 2;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), U256_INV_MOD_N),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((1, 2), 3),
+            ((1, 3), 0),
+            ((1, 4), 2),
+            ((1, 5), 0),
+            ((1, 6), 3),
+            ((1, 7), 0)
+        ];
+    }
+
+    #[test]
+    fn run_dict_hints() {
+        let mut vm = vm!();
+        add_segments!(vm, 4);
+        // The segment arena holds the dict infos pointer and the number of allocated and
+        // finalized dictionaries.
+        memory_from_memory!(
+            vm.memory,
+            (((1, 0), (2, 3)), ((2, 0), (3, 0)), ((2, 1), 0), ((2, 2), 0))
+        );
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        let alloc_dict = r#"{ "AllocDictFeltTo": {
+            "dict_manager_ptr": { "Deref": { "register": "FP", "offset": -1 } }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, alloc_dict), Ok(()));
+        check_memory![vm.memory, ((3, 0), (4, 0))];
+
+        // Writes 7 at key 5 through the dict access at (4, 0), then reads it back.
+        vm.insert_value(&Relocatable::from((1, 1)), Relocatable::from((4, 0)))
+            .unwrap();
+        vm.insert_value(&Relocatable::from((4, 0)), Felt::new(5))
+            .unwrap();
+        vm.run_context.ap = 2;
+        let dict_write = r#"{ "Felt252DictWrite": {
+            "dict_ptr": { "Deref": { "register": "AP", "offset": -1 } },
+            "key": { "Immediate": "0x5" },
+            "value": { "Immediate": "0x7" }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, dict_write), Ok(()));
+        check_memory![vm.memory, ((4, 1), 0)];
+
+        let dict_read = r#"{ "Felt252DictRead": {
+            "dict_ptr": { "Deref": { "register": "AP", "offset": -1 } },
+            "key": { "Immediate": "0x5" },
+            "value_dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, dict_read), Ok(()));
+        check_memory![vm.memory, ((1, 2), 7)];
+
+        vm.run_context.ap = 3;
+        let get_index = r#"{ "GetSegmentArenaIndex": {
+            "dict_end_ptr": { "BinOp": {
+                "op": "Add",
+                "a": { "register": "AP", "offset": -2 },
+                "b": { "Immediate": "0x3" }
+            } },
+            "dict_index": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, get_index), Ok(()));
+        check_memory![vm.memory, ((1, 3), 0)];
+    }
+
+    #[test]
+    fn run_dict_hints_with_relocatable_value() {
+        let mut vm = vm!();
+        add_segments!(vm, 4);
+        memory_from_memory!(
+            vm.memory,
+            (((1, 0), (2, 3)), ((2, 0), (3, 0)), ((2, 1), 0), ((2, 2), 0))
+        );
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        let alloc_dict = r#"{ "AllocDictFeltTo": {
+            "dict_manager_ptr": { "Deref": { "register": "FP", "offset": -1 } }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, alloc_dict), Ok(()));
+
+        // Writes the pointer (2, 1), held at (1, 2), at key 5, then reads it back.
+        vm.insert_value(&Relocatable::from((1, 1)), Relocatable::from((4, 0)))
+            .unwrap();
+        vm.insert_value(&Relocatable::from((1, 2)), Relocatable::from((2, 1)))
+            .unwrap();
+        vm.run_context.ap = 3;
+        let dict_write = r#"{ "Felt252DictWrite": {
+            "dict_ptr": { "Deref": { "register": "AP", "offset": -2 } },
+            "key": { "Immediate": "0x5" },
+            "value": { "Deref": { "register": "AP", "offset": -1 } }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, dict_write), Ok(()));
+        check_memory![vm.memory, ((4, 1), 0)];
+
+        let dict_read = r#"{ "Felt252DictRead": {
+            "dict_ptr": { "Deref": { "register": "AP", "offset": -2 } },
+            "key": { "Immediate": "0x5" },
+            "value_dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(run_hint(&mut vm, &mut exec_scopes, dict_read), Ok(()));
+        check_memory![vm.memory, ((1, 3), (2, 1))];
+    }
+
+    #[test]
+    fn run_dict_read_without_dict_manager() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0))];
+        vm.run_context.ap = 1;
+        let dict_read = r#"{ "Felt252DictRead": {
+            "dict_ptr": { "Deref": { "register": "AP", "offset": -1 } },
+            "key": { "Immediate": "0x5" },
+            "value_dst": { "register": "AP", "offset": 0 }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), dict_read),
+            Err(HintError::VariableNotInScopeError(DICT_MANAGER.to_string()))
+        );
+    }
+
+    #[test]
+    fn run_debug_print() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((1, 1), (2, 2)), ((2, 0), 1), ((2, 1), 2)];
+        vm.run_context.fp = 2;
+        let hint_code = r#"{ "DebugPrint": {
+            "start": { "Deref": { "register": "FP", "offset": -2 } },
+            "end": { "Deref": { "register": "FP", "offset": -1 } }
+        } }"#;
+        assert_eq!(
+            run_hint(&mut vm, &mut ExecutionScopes::new(), hint_code),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::types::instruction::Register;
+use felt::Felt;
+use serde::Deserialize;
+
+// The structured hints of Cairo 1 CASM files, as serialized by the Cairo 1 compiler.
+
+/// A memory cell given by its offset from `ap` or `fp`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CellRef {
+    pub register: Register,
+    pub offset: i16,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Mul,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DerefOrImmediate {
+    Deref(CellRef),
+    Immediate(Felt),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BinOpOperand {
+    pub op: Operation,
+    pub a: CellRef,
+    pub b: DerefOrImmediate,
+}
+
+/// An operand of a hint: `[cell]`, `[[cell] + offset]`, an immediate value or
+/// `[cell] (+|*) ([cell] | immediate)`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ResOperand {
+    Deref(CellRef),
+    DoubleDeref(CellRef, i16),
+    Immediate(Felt),
+    BinOp(BinOpOperand),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    AllocSegment {
+        dst: CellRef,
+    },
+    TestLessThan {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    TestLessThanOrEqual {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    DivMod {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        quotient: CellRef,
+        remainder: CellRef,
+    },
+    WideMul128 {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        high: CellRef,
+        low: CellRef,
+    },
+    U256InvModN {
+        b0: ResOperand,
+        b1: ResOperand,
+        n0: ResOperand,
+        n1: ResOperand,
+        g0_or_no_inv: CellRef,
+        g1_option: CellRef,
+        s_or_r0: CellRef,
+        s_or_r1: CellRef,
+        t_or_k0: CellRef,
+        t_or_k1: CellRef,
+    },
+    SquareRoot {
+        value: ResOperand,
+        dst: CellRef,
+    },
+    AllocDictFeltTo {
+        dict_manager_ptr: ResOperand,
+    },
+    Felt252DictRead {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+        value_dst: CellRef,
+    },
+    Felt252DictWrite {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+        value: ResOperand,
+    },
+    GetSegmentArenaIndex {
+        dict_end_ptr: ResOperand,
+        dict_index: CellRef,
+    },
+    DebugPrint {
+        start: ResOperand,
+        end: ResOperand,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    #[test]
+    fn deserialize_test_less_than_or_equal() {
+        let hint: Hint = serde_json::from_str(
+            r#"{ "TestLessThanOrEqual": {
+                "lhs": { "Immediate": "0x10" },
+                "rhs": { "Deref": { "register": "FP", "offset": -6 } },
+                "dst": { "register": "AP", "offset": 0 }
+            } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            hint,
+            Hint::TestLessThanOrEqual {
+                lhs: ResOperand::Immediate(Felt::new(16)),
+                rhs: ResOperand::Deref(CellRef {
+                    register: Register::FP,
+                    offset: -6
+                }),
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_double_deref_and_bin_op() {
+        let hint: Hint = serde_json::from_str(
+            r#"{ "Felt252DictWrite": {
+                "dict_ptr": { "BinOp": {
+                    "op": "Add",
+                    "a": { "register": "FP", "offset": -4 },
+                    "b": { "Immediate": "-0x3" }
+                } },
+                "key": { "DoubleDeref": [{ "register": "AP", "offset": -1 }, 2] },
+                "value": { "Immediate": "0x0" }
+            } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            hint,
+            Hint::Felt252DictWrite {
+                dict_ptr: ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Add,
+                    a: CellRef {
+                        register: Register::FP,
+                        offset: -4
+                    },
+                    b: DerefOrImmediate::Immediate(Felt::new(-3)),
+                }),
+                key: ResOperand::DoubleDeref(
+                    CellRef {
+                        register: Register::AP,
+                        offset: -1
+                    },
+                    2
+                ),
+                value: ResOperand::Immediate(Felt::new(0)),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_hint() {
+        let hint: Result<Hint, _> =
+            serde_json::from_str(r#"{ "SystemCall": { "system": { "Immediate": "0x0" } } }"#);
+        assert!(hint.is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dict_manager;
+pub mod hint_processor;
+pub mod hints;
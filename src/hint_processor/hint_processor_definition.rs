@@ -2,13 +2,13 @@ use crate::any_box;
 use crate::serde::deserialize_program::ApTracking;
 use crate::serde::deserialize_program::OffsetValue;
 use crate::serde::deserialize_program::Reference;
+use crate::stdlib::collections::HashMap;
+use crate::stdlib::{any::Any, prelude::*};
 use crate::types::exec_scope::ExecutionScopes;
 use crate::types::instruction::Register;
 use crate::vm::errors::hint_errors::HintError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
-use std::any::Any;
-use std::collections::HashMap;
 
 use super::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
 use felt::Felt;
@@ -24,7 +24,7 @@ pub trait HintProcessor {
         //access current scope variables
         exec_scopes: &mut ExecutionScopes,
         //Data structure that can be downcasted to the structure generated by compile_hint
-        hint_data: &Box<dyn Any>,
+        hint_data: &Box<dyn Any + Send>,
         //Constant values extracted from the program specification.
         constants: &HashMap<String, Felt>,
     ) -> Result<(), HintError>;
@@ -41,7 +41,7 @@ pub trait HintProcessor {
         reference_ids: &HashMap<String, usize>,
         //List of all references (key corresponds to element of the previous dictionary)
         references: &HashMap<usize, HintReference>,
-    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+    ) -> Result<Box<dyn Any + Send>, VirtualMachineError> {
         Ok(any_box!(HintProcessorData {
             code: hint_code.to_string(),
             ap_tracking: ap_tracking_data.clone(),
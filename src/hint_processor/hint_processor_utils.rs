@@ -1,3 +1,4 @@
+use crate::stdlib::borrow::Cow;
 use crate::{
     serde::deserialize_program::{ApTracking, OffsetValue},
     types::{
@@ -9,7 +10,6 @@ use crate::{
         vm_core::VirtualMachine,
     },
 };
-use std::borrow::Cow;
 
 use super::hint_processor_definition::HintReference;
 use felt::Felt;
@@ -0,0 +1,3 @@
+pub mod hint_code;
+pub mod state;
+pub mod syscall_handler;
@@ -0,0 +1,159 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+use crate::vm::errors::syscall_errors::SyscallError;
+use felt::Felt;
+use num_traits::Zero;
+
+/// The StarkNet state seen by the syscalls of a contract: the storage of every contract, and
+/// the results of the contracts and classes it calls.
+pub trait StarknetState {
+    fn get_storage_at(&self, contract_address: &Felt, key: &Felt) -> Felt;
+
+    fn set_storage_at(&mut self, contract_address: &Felt, key: Felt, value: Felt);
+
+    fn call_contract(
+        &mut self,
+        caller_address: &Felt,
+        contract_address: &Felt,
+        selector: &Felt,
+        calldata: &[Felt],
+    ) -> Result<Vec<Felt>, SyscallError>;
+
+    fn library_call(
+        &mut self,
+        contract_address: &Felt,
+        class_hash: &Felt,
+        selector: &Felt,
+        calldata: &[Felt],
+    ) -> Result<Vec<Felt>, SyscallError>;
+}
+
+/// A `StarknetState` kept in memory. Storage values not written yet read as zero, and calls
+/// to other contracts return the retdata registered for their address and selector.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InMemoryState {
+    pub storage: HashMap<(Felt, Felt), Felt>,
+    pub call_results: HashMap<(Felt, Felt), Vec<Felt>>,
+    pub library_call_results: HashMap<(Felt, Felt), Vec<Felt>>,
+}
+
+impl InMemoryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_call_result(&mut self, contract_address: Felt, selector: Felt, retdata: Vec<Felt>) {
+        self.call_results
+            .insert((contract_address, selector), retdata);
+    }
+
+    pub fn set_library_call_result(
+        &mut self,
+        class_hash: Felt,
+        selector: Felt,
+        retdata: Vec<Felt>,
+    ) {
+        self.library_call_results
+            .insert((class_hash, selector), retdata);
+    }
+}
+
+impl StarknetState for InMemoryState {
+    fn get_storage_at(&self, contract_address: &Felt, key: &Felt) -> Felt {
+        self.storage
+            .get(&(contract_address.clone(), key.clone()))
+            .cloned()
+            .unwrap_or_else(Felt::zero)
+    }
+
+    fn set_storage_at(&mut self, contract_address: &Felt, key: Felt, value: Felt) {
+        self.storage.insert((contract_address.clone(), key), value);
+    }
+
+    fn call_contract(
+        &mut self,
+        _caller_address: &Felt,
+        contract_address: &Felt,
+        selector: &Felt,
+        _calldata: &[Felt],
+    ) -> Result<Vec<Felt>, SyscallError> {
+        self.call_results
+            .get(&(contract_address.clone(), selector.clone()))
+            .cloned()
+            .ok_or_else(|| {
+                SyscallError::UnknownContractCall(contract_address.clone(), selector.clone())
+            })
+    }
+
+    fn library_call(
+        &mut self,
+        _contract_address: &Felt,
+        class_hash: &Felt,
+        selector: &Felt,
+        _calldata: &[Felt],
+    ) -> Result<Vec<Felt>, SyscallError> {
+        self.library_call_results
+            .get(&(class_hash.clone(), selector.clone()))
+            .cloned()
+            .ok_or_else(|| SyscallError::UnknownLibraryCall(class_hash.clone(), selector.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    #[test]
+    fn storage_defaults_to_zero() {
+        let state = InMemoryState::new();
+        assert_eq!(
+            state.get_storage_at(&Felt::new(1), &Felt::new(2)),
+            Felt::zero()
+        );
+    }
+
+    #[test]
+    fn storage_is_kept_per_contract() {
+        let mut state = InMemoryState::new();
+        state.set_storage_at(&Felt::new(1), Felt::new(2), Felt::new(3));
+        assert_eq!(
+            state.get_storage_at(&Felt::new(1), &Felt::new(2)),
+            Felt::new(3)
+        );
+        assert_eq!(
+            state.get_storage_at(&Felt::new(4), &Felt::new(2)),
+            Felt::zero()
+        );
+    }
+
+    #[test]
+    fn call_contract_registered_result() {
+        let mut state = InMemoryState::new();
+        state.set_call_result(Felt::new(1), Felt::new(2), vec![Felt::new(7)]);
+        assert_eq!(
+            state.call_contract(&Felt::zero(), &Felt::new(1), &Felt::new(2), &[]),
+            Ok(vec![Felt::new(7)])
+        );
+    }
+
+    #[test]
+    fn call_contract_unknown_call() {
+        let mut state = InMemoryState::new();
+        assert_eq!(
+            state.call_contract(&Felt::zero(), &Felt::new(1), &Felt::new(2), &[]),
+            Err(SyscallError::UnknownContractCall(
+                Felt::new(1),
+                Felt::new(2)
+            ))
+        );
+    }
+
+    #[test]
+    fn library_call_unknown_call() {
+        let mut state = InMemoryState::new();
+        assert_eq!(
+            state.library_call(&Felt::zero(), &Felt::new(1), &Felt::new(2), &[]),
+            Err(SyscallError::UnknownLibraryCall(Felt::new(1), Felt::new(2)))
+        );
+    }
+}
@@ -0,0 +1,699 @@
+use crate::stdlib::{any::Any, collections::HashMap, prelude::*};
+use crate::{
+    hint_processor::{
+        builtin_hint_processor::{
+            builtin_hint_processor_definition::{BuiltinHintProcessor, HintProcessorData},
+            hint_utils::get_ptr_from_var_name,
+        },
+        hint_processor_definition::HintProcessor,
+        starknet::{hint_code, state::StarknetState},
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{
+            hint_errors::HintError, syscall_errors::SyscallError, vm_errors::VirtualMachineError,
+        },
+        vm_core::VirtualMachine,
+    },
+};
+use felt::{Felt, FeltOps, NewFelt};
+use num_traits::ToPrimitive;
+
+/// The block and transaction the contract runs in, as returned by the `get_*` syscalls.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExecutionContext {
+    pub caller_address: Felt,
+    pub contract_address: Felt,
+    pub sequencer_address: Felt,
+    pub block_number: Felt,
+    pub block_timestamp: Felt,
+    pub tx_info: TxInfo,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TxInfo {
+    pub version: Felt,
+    pub account_contract_address: Felt,
+    pub max_fee: Felt,
+    pub signature: Vec<Felt>,
+    pub transaction_hash: Felt,
+    pub chain_id: Felt,
+    pub nonce: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct L2ToL1Message {
+    pub to_address: Felt,
+    pub payload: Vec<Felt>,
+}
+
+/// Executes the deprecated StarkNet syscall hints against a `StarknetState`, and every other
+/// hint with a `BuiltinHintProcessor`.
+pub struct SyscallHintProcessor<S: StarknetState> {
+    pub builtin_hint_processor: BuiltinHintProcessor,
+    pub state: S,
+    pub context: ExecutionContext,
+    pub events: Vec<Event>,
+    pub l2_to_l1_messages: Vec<L2ToL1Message>,
+    // The transaction info is written into memory by the first syscall that needs it.
+    tx_info_ptr: Option<Relocatable>,
+}
+
+impl<S: StarknetState> SyscallHintProcessor<S> {
+    pub fn new(
+        builtin_hint_processor: BuiltinHintProcessor,
+        state: S,
+        context: ExecutionContext,
+    ) -> Self {
+        SyscallHintProcessor {
+            builtin_hint_processor,
+            state,
+            context,
+            events: Vec::new(),
+            l2_to_l1_messages: Vec::new(),
+            tx_info_ptr: None,
+        }
+    }
+
+    fn call_contract(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "CallContract")?;
+        let contract_address = vm.get_integer(&(&syscall_ptr + 1_usize))?.into_owned();
+        let function_selector = vm.get_integer(&(&syscall_ptr + 2_usize))?.into_owned();
+        let calldata = get_felt_array(vm, &(&syscall_ptr + 3_usize), &(&syscall_ptr + 4_usize))?;
+        let retdata = self.state.call_contract(
+            &self.context.contract_address,
+            &contract_address,
+            &function_selector,
+            &calldata,
+        )?;
+        write_felt_array(vm, &(&syscall_ptr + 5_usize), &retdata)
+    }
+
+    fn library_call(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "LibraryCall")?;
+        let class_hash = vm.get_integer(&(&syscall_ptr + 1_usize))?.into_owned();
+        let function_selector = vm.get_integer(&(&syscall_ptr + 2_usize))?.into_owned();
+        let calldata = get_felt_array(vm, &(&syscall_ptr + 3_usize), &(&syscall_ptr + 4_usize))?;
+        let retdata = self.state.library_call(
+            &self.context.contract_address,
+            &class_hash,
+            &function_selector,
+            &calldata,
+        )?;
+        write_felt_array(vm, &(&syscall_ptr + 5_usize), &retdata)
+    }
+
+    fn storage_read(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "StorageRead")?;
+        let address = vm.get_integer(&(&syscall_ptr + 1_usize))?.into_owned();
+        let value = self
+            .state
+            .get_storage_at(&self.context.contract_address, &address);
+        vm.insert_value(&(&syscall_ptr + 2_usize), value)?;
+        Ok(())
+    }
+
+    fn storage_write(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "StorageWrite")?;
+        let address = vm.get_integer(&(&syscall_ptr + 1_usize))?.into_owned();
+        let value = vm.get_integer(&(&syscall_ptr + 2_usize))?.into_owned();
+        self.state
+            .set_storage_at(&self.context.contract_address, address, value);
+        Ok(())
+    }
+
+    fn emit_event(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "EmitEvent")?;
+        let keys = get_felt_array(vm, &(&syscall_ptr + 1_usize), &(&syscall_ptr + 2_usize))?;
+        let data = get_felt_array(vm, &(&syscall_ptr + 3_usize), &(&syscall_ptr + 4_usize))?;
+        self.events.push(Event { keys, data });
+        Ok(())
+    }
+
+    fn send_message_to_l1(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "SendMessageToL1")?;
+        let to_address = vm.get_integer(&(&syscall_ptr + 1_usize))?.into_owned();
+        let payload = get_felt_array(vm, &(&syscall_ptr + 2_usize), &(&syscall_ptr + 3_usize))?;
+        self.l2_to_l1_messages.push(L2ToL1Message {
+            to_address,
+            payload,
+        });
+        Ok(())
+    }
+
+    fn get_tx_info_ptr(&mut self, vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+        if let Some(tx_info_ptr) = self.tx_info_ptr {
+            return Ok(tx_info_ptr);
+        }
+        let tx_info = &self.context.tx_info;
+        let signature = felts_to_args(&tx_info.signature);
+        let signature_ptr = vm.gen_arg(&signature)?;
+        let tx_info_args = vec![
+            MaybeRelocatable::from(tx_info.version.clone()),
+            MaybeRelocatable::from(tx_info.account_contract_address.clone()),
+            MaybeRelocatable::from(tx_info.max_fee.clone()),
+            MaybeRelocatable::from(Felt::new(tx_info.signature.len())),
+            signature_ptr,
+            MaybeRelocatable::from(tx_info.transaction_hash.clone()),
+            MaybeRelocatable::from(tx_info.chain_id.clone()),
+            MaybeRelocatable::from(tx_info.nonce.clone()),
+        ];
+        let tx_info_ptr: Relocatable = vm
+            .gen_arg(&tx_info_args)?
+            .try_into()
+            .map_err(VirtualMachineError::MemoryError)?;
+        self.tx_info_ptr = Some(tx_info_ptr);
+        Ok(tx_info_ptr)
+    }
+
+    fn get_tx_info(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "GetTxInfo")?;
+        let tx_info_ptr = self.get_tx_info_ptr(vm)?;
+        vm.insert_value(&(&syscall_ptr + 1_usize), tx_info_ptr)?;
+        Ok(())
+    }
+
+    // The signature is the one stored in the transaction info, at its fourth and fifth members.
+    fn get_tx_signature(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        check_selector(vm, &syscall_ptr, "GetTxSignature")?;
+        let tx_info_ptr = self.get_tx_info_ptr(vm)?;
+        let signature_len = vm.get_integer(&(&tx_info_ptr + 3_usize))?.into_owned();
+        let signature = vm.get_relocatable(&(&tx_info_ptr + 4_usize))?;
+        vm.insert_value(&(&syscall_ptr + 1_usize), signature_len)?;
+        vm.insert_value(&(&syscall_ptr + 2_usize), signature)?;
+        Ok(())
+    }
+
+    fn get_caller_address(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        write_context_value(
+            vm,
+            &syscall_ptr,
+            "GetCallerAddress",
+            &self.context.caller_address,
+        )
+    }
+
+    fn get_contract_address(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        write_context_value(
+            vm,
+            &syscall_ptr,
+            "GetContractAddress",
+            &self.context.contract_address,
+        )
+    }
+
+    fn get_sequencer_address(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        write_context_value(
+            vm,
+            &syscall_ptr,
+            "GetSequencerAddress",
+            &self.context.sequencer_address,
+        )
+    }
+
+    fn get_block_number(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        write_context_value(
+            vm,
+            &syscall_ptr,
+            "GetBlockNumber",
+            &self.context.block_number,
+        )
+    }
+
+    fn get_block_timestamp(
+        &mut self,
+        vm: &mut VirtualMachine,
+        syscall_ptr: Relocatable,
+    ) -> Result<(), HintError> {
+        write_context_value(
+            vm,
+            &syscall_ptr,
+            "GetBlockTimestamp",
+            &self.context.block_timestamp,
+        )
+    }
+}
+
+type Syscall<S> =
+    fn(&mut SyscallHintProcessor<S>, &mut VirtualMachine, Relocatable) -> Result<(), HintError>;
+
+impl<S: StarknetState> HintProcessor for SyscallHintProcessor<S> {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any + Send>,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<(), HintError> {
+        let hint = hint_data
+            .downcast_ref::<HintProcessorData>()
+            .ok_or(HintError::WrongHintData)?;
+
+        let syscall: Syscall<S> = match &*hint.code {
+            hint_code::CALL_CONTRACT => Self::call_contract,
+            hint_code::LIBRARY_CALL => Self::library_call,
+            hint_code::STORAGE_READ => Self::storage_read,
+            hint_code::STORAGE_WRITE => Self::storage_write,
+            hint_code::EMIT_EVENT => Self::emit_event,
+            hint_code::SEND_MESSAGE_TO_L1 => Self::send_message_to_l1,
+            hint_code::GET_TX_INFO => Self::get_tx_info,
+            hint_code::GET_TX_SIGNATURE => Self::get_tx_signature,
+            hint_code::GET_CALLER_ADDRESS => Self::get_caller_address,
+            hint_code::GET_CONTRACT_ADDRESS => Self::get_contract_address,
+            hint_code::GET_SEQUENCER_ADDRESS => Self::get_sequencer_address,
+            hint_code::GET_BLOCK_NUMBER => Self::get_block_number,
+            hint_code::GET_BLOCK_TIMESTAMP => Self::get_block_timestamp,
+            _ => {
+                return self.builtin_hint_processor.execute_hint(
+                    vm,
+                    exec_scopes,
+                    hint_data,
+                    constants,
+                )
+            }
+        };
+        let syscall_ptr =
+            get_ptr_from_var_name("syscall_ptr", vm, &hint.ids_data, &hint.ap_tracking)?;
+        syscall(self, vm, syscall_ptr)
+    }
+}
+
+// Each syscall request starts with the name of the syscall, encoded as a Cairo short string.
+fn check_selector(
+    vm: &VirtualMachine,
+    syscall_ptr: &Relocatable,
+    syscall_name: &'static str,
+) -> Result<(), HintError> {
+    let selector = vm.get_integer(syscall_ptr)?;
+    if selector.as_ref() != &Felt::from_bytes_be(syscall_name.as_bytes()) {
+        return Err(SyscallError::UnexpectedSelector(syscall_name, selector.into_owned()).into());
+    }
+    Ok(())
+}
+
+// Reads an array given by its length at len_addr and a pointer to it at ptr_addr.
+fn get_felt_array(
+    vm: &VirtualMachine,
+    len_addr: &Relocatable,
+    ptr_addr: &Relocatable,
+) -> Result<Vec<Felt>, HintError> {
+    let len = vm
+        .get_integer(len_addr)?
+        .to_usize()
+        .ok_or(HintError::BigintToUsizeFail)?;
+    let ptr = vm.get_relocatable(ptr_addr)?;
+    Ok(vm
+        .get_integer_range(&ptr, len)?
+        .into_iter()
+        .map(|value| value.into_owned())
+        .collect())
+}
+
+// Writes the array into a new segment, and its length and a pointer to it at len_addr.
+fn write_felt_array(
+    vm: &mut VirtualMachine,
+    len_addr: &Relocatable,
+    values: &[Felt],
+) -> Result<(), HintError> {
+    let ptr = vm.gen_arg(&felts_to_args(values))?;
+    vm.insert_value(len_addr, Felt::new(values.len()))?;
+    vm.insert_value(&(len_addr + 1_usize), ptr)?;
+    Ok(())
+}
+
+// Responds to the syscalls that return a single value from the execution context.
+fn write_context_value(
+    vm: &mut VirtualMachine,
+    syscall_ptr: &Relocatable,
+    syscall_name: &'static str,
+    value: &Felt,
+) -> Result<(), HintError> {
+    check_selector(vm, syscall_ptr, syscall_name)?;
+    vm.insert_value(&(syscall_ptr + 1_usize), value.clone())?;
+    Ok(())
+}
+
+fn felts_to_args(values: &[Felt]) -> Vec<MaybeRelocatable> {
+    values.iter().cloned().map(MaybeRelocatable::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        any_box,
+        hint_processor::{
+            hint_processor_definition::HintReference, starknet::state::InMemoryState,
+        },
+        utils::test_utils::*,
+    };
+    use num_traits::Zero;
+
+    fn selector(syscall_name: &str) -> Felt {
+        Felt::from_bytes_be(syscall_name.as_bytes())
+    }
+
+    fn syscall_hint_processor() -> SyscallHintProcessor<InMemoryState> {
+        SyscallHintProcessor::new(
+            BuiltinHintProcessor::new_empty(),
+            InMemoryState::new(),
+            ExecutionContext {
+                caller_address: Felt::new(11),
+                contract_address: Felt::new(22),
+                block_number: Felt::new(33),
+                tx_info: TxInfo {
+                    signature: vec![Felt::new(5), Felt::new(6)],
+                    chain_id: Felt::new(44),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+    }
+
+    // The syscall_ptr reference points to (1, 0), where the pointer to the request at (2, 0) is.
+    fn vm_with_syscall_request(request: Vec<MaybeRelocatable>) -> VirtualMachine {
+        let mut vm = vm!();
+        for _ in 0..3 {
+            vm.segments.add(&mut vm.memory);
+        }
+        vm.insert_value(&Relocatable::from((1, 0)), Relocatable::from((2, 0)))
+            .unwrap();
+        vm.load_data(&MaybeRelocatable::from((2, 0)), &request)
+            .unwrap();
+        vm.run_context.fp = 1;
+        vm
+    }
+
+    fn execute_syscall(
+        hint_processor: &mut SyscallHintProcessor<InMemoryState>,
+        vm: &mut VirtualMachine,
+        hint_code: &str,
+    ) -> Result<(), HintError> {
+        let hint_data = HintProcessorData::new_default(
+            hint_code.to_string(),
+            HashMap::from([("syscall_ptr".to_string(), HintReference::new_simple(-1))]),
+        );
+        hint_processor.execute_hint(
+            vm,
+            exec_scopes_ref!(),
+            &any_box!(hint_data),
+            &HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn storage_write_then_read() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![
+            selector("StorageWrite").into(),
+            Felt::new(7).into(),
+            Felt::new(8).into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::STORAGE_WRITE),
+            Ok(())
+        );
+        assert_eq!(
+            hint_processor
+                .state
+                .get_storage_at(&Felt::new(22), &Felt::new(7)),
+            Felt::new(8)
+        );
+
+        let mut vm =
+            vm_with_syscall_request(vec![selector("StorageRead").into(), Felt::new(7).into()]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::STORAGE_READ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 2), 8)];
+    }
+
+    #[test]
+    fn storage_read_wrong_selector() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm =
+            vm_with_syscall_request(vec![selector("StorageWrite").into(), Felt::new(7).into()]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::STORAGE_READ),
+            Err(HintError::Syscall(SyscallError::UnexpectedSelector(
+                "StorageRead",
+                selector("StorageWrite")
+            )))
+        );
+    }
+
+    #[test]
+    fn get_caller_address() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![selector("GetCallerAddress").into()]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::GET_CALLER_ADDRESS),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 1), 11)];
+    }
+
+    #[test]
+    fn get_block_number() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![selector("GetBlockNumber").into()]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::GET_BLOCK_NUMBER),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 1), 33)];
+    }
+
+    #[test]
+    fn get_tx_info() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![selector("GetTxInfo").into()]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::GET_TX_INFO),
+            Ok(())
+        );
+        // The signature is written into segment 3 and the transaction info into segment 4.
+        check_memory![
+            vm.memory,
+            ((2, 1), (4, 0)),
+            ((3, 0), 5),
+            ((3, 1), 6),
+            ((4, 3), 2),
+            ((4, 4), (3, 0)),
+            ((4, 6), 44)
+        ];
+    }
+
+    #[test]
+    fn get_tx_signature_reuses_tx_info() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![
+            selector("GetTxInfo").into(),
+            Felt::zero().into(),
+            selector("GetTxSignature").into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::GET_TX_INFO),
+            Ok(())
+        );
+        // Move the syscall_ptr reference to the second request.
+        vm.insert_value(&Relocatable::from((1, 1)), Relocatable::from((2, 2)))
+            .unwrap();
+        vm.run_context.fp = 2;
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::GET_TX_SIGNATURE),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 3), 2), ((2, 4), (3, 0))];
+        assert_eq!(vm.segments.num_segments, 5);
+    }
+
+    #[test]
+    fn call_contract_writes_retdata() {
+        let mut hint_processor = syscall_hint_processor();
+        hint_processor.state.set_call_result(
+            Felt::new(1),
+            Felt::new(2),
+            vec![Felt::new(3), Felt::new(4)],
+        );
+        let mut vm = vm_with_syscall_request(vec![
+            selector("CallContract").into(),
+            Felt::new(1).into(),
+            Felt::new(2).into(),
+            Felt::zero().into(),
+            Relocatable::from((2, 0)).into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::CALL_CONTRACT),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 5), 2),
+            ((2, 6), (3, 0)),
+            ((3, 0), 3),
+            ((3, 1), 4)
+        ];
+    }
+
+    #[test]
+    fn call_contract_unknown_call() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![
+            selector("CallContract").into(),
+            Felt::new(1).into(),
+            Felt::new(2).into(),
+            Felt::zero().into(),
+            Relocatable::from((2, 0)).into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::CALL_CONTRACT),
+            Err(HintError::Syscall(SyscallError::UnknownContractCall(
+                Felt::new(1),
+                Felt::new(2)
+            )))
+        );
+    }
+
+    #[test]
+    fn emit_event() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![
+            selector("EmitEvent").into(),
+            Felt::new(1).into(),
+            Relocatable::from((2, 5)).into(),
+            Felt::new(2).into(),
+            Relocatable::from((2, 6)).into(),
+            Felt::new(7).into(),
+            Felt::new(8).into(),
+            Felt::new(9).into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::EMIT_EVENT),
+            Ok(())
+        );
+        assert_eq!(
+            hint_processor.events,
+            vec![Event {
+                keys: vec![Felt::new(7)],
+                data: vec![Felt::new(8), Felt::new(9)],
+            }]
+        );
+    }
+
+    #[test]
+    fn send_message_to_l1() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![
+            selector("SendMessageToL1").into(),
+            Felt::new(1).into(),
+            Felt::new(1).into(),
+            Relocatable::from((2, 4)).into(),
+            Felt::new(5).into(),
+        ]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::SEND_MESSAGE_TO_L1),
+            Ok(())
+        );
+        assert_eq!(
+            hint_processor.l2_to_l1_messages,
+            vec![L2ToL1Message {
+                to_address: Felt::new(1),
+                payload: vec![Felt::new(5)],
+            }]
+        );
+    }
+
+    #[test]
+    fn other_hints_run_on_builtin_hint_processor() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![]);
+        vm.run_context.ap = 1;
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, "memory[ap] = segments.add()"),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 1), (3, 0))];
+    }
+
+    #[test]
+    fn unknown_hint() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, "print(ids.x)"),
+            Err(HintError::UnknownHint("print(ids.x)".to_string()))
+        );
+    }
+
+    #[test]
+    fn storage_read_without_request() {
+        let mut hint_processor = syscall_hint_processor();
+        let mut vm = vm_with_syscall_request(vec![]);
+        assert_eq!(
+            execute_syscall(&mut hint_processor, &mut vm, hint_code::STORAGE_READ),
+            Err(HintError::Internal(VirtualMachineError::ExpectedInteger(
+                MaybeRelocatable::from((2, 0))
+            )))
+        );
+    }
+}
@@ -1,8 +1,20 @@
 #![deny(warnings)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("cairo-vm needs either the `std` or the `alloc` feature");
+
+pub mod air_private_input;
+pub mod air_public_input;
 pub mod cairo_run;
 pub mod hint_processor;
 pub mod math_utils;
+pub mod poseidon_hash;
 pub mod serde;
+mod stdlib;
 pub mod types;
 pub mod utils;
 pub mod vm;
@@ -1,11 +1,27 @@
 #![deny(warnings)]
 use cairo_vm::cairo_run;
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use cairo_vm::hint_processor::starknet::state::InMemoryState;
+use cairo_vm::hint_processor::starknet::syscall_handler::{ExecutionContext, SyscallHintProcessor};
+use cairo_vm::types::contract_class::{get_selector_from_name, ContractClass};
+use cairo_vm::types::layout::CairoLayout;
+use cairo_vm::types::program::Program;
+use cairo_vm::types::relocatable::MaybeRelocatable;
+use cairo_vm::vm::coverage::Coverage;
+use cairo_vm::vm::debugger::Debugger;
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::errors::runner_errors::RunnerError;
 use cairo_vm::vm::errors::trace_errors::TraceError;
-use clap::{Parser, ValueHint};
-use std::path::PathBuf;
+use cairo_vm::vm::profiler::Profile;
+use cairo_vm::vm::runners::cairo_pie::CairoPie;
+use cairo_vm::vm::runners::cairo_runner::{CairoArg, CairoRunner};
+use cairo_vm::vm::vm_core::{RunLimits, VirtualMachine};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueHint};
+use felt::Felt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 #[cfg(feature = "with_mimalloc")]
 use mimalloc::MiMalloc;
@@ -16,9 +32,12 @@ static ALLOC: MiMalloc = MiMalloc;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    #[clap(value_parser, value_hint=ValueHint::FilePath)]
-    filename: PathBuf,
+    #[clap(subcommand)]
+    command: Option<Command>,
+    #[clap(value_parser, value_hint=ValueHint::FilePath, required = true)]
+    filename: Option<PathBuf>,
     #[clap(long = "--trace_file", value_parser)]
     trace_file: Option<PathBuf>,
     #[structopt(long = "--print_output")]
@@ -30,52 +49,350 @@ struct Args {
     memory_file: Option<PathBuf>,
     #[clap(long = "--layout", default_value = "plain", validator=validate_layout)]
     layout: String,
+    #[clap(long = "--layout_file", value_parser, value_hint = ValueHint::FilePath)]
+    layout_file: Option<PathBuf>,
     #[structopt(long = "--proof_mode")]
     proof_mode: bool,
+    #[structopt(long = "--debug")]
+    debug: bool,
+    #[clap(long = "--report", value_parser)]
+    report: Option<PathBuf>,
+    #[clap(long = "--args", value_parser = parse_arg, multiple_values = true)]
+    args: Vec<CairoArg>,
+    #[clap(long = "--args_file", value_parser = parse_args_file, conflicts_with = "args")]
+    args_file: Option<ArgsFile>,
+    #[clap(
+        long = "--signatures_file",
+        value_parser = parse_signatures_file,
+        conflicts_with = "run_from_cairo_pie"
+    )]
+    signatures_file: Option<SignaturesFile>,
+    #[clap(
+        long = "--cairo_pie_output",
+        value_parser,
+        conflicts_with = "proof_mode"
+    )]
+    cairo_pie_output: Option<PathBuf>,
+    #[clap(
+        long = "--run_from_cairo_pie",
+        conflicts_with_all = &["proof_mode", "debug", "args", "args_file"]
+    )]
+    run_from_cairo_pie: bool,
+    #[clap(long = "--air_public_input", value_parser, requires = "proof_mode")]
+    air_public_input: Option<PathBuf>,
+    #[clap(
+        long = "--air_private_input",
+        value_parser,
+        requires_all = &["proof_mode", "trace_file", "memory_file"]
+    )]
+    air_private_input: Option<PathBuf>,
+    #[clap(long = "--profile_output", value_parser)]
+    profile_output: Option<PathBuf>,
+    #[clap(long = "--profile_resource", default_value = "steps")]
+    profile_resource: String,
+    #[clap(long = "--coverage", value_parser)]
+    coverage: Option<PathBuf>,
+    #[clap(long = "--max_steps", value_parser)]
+    max_steps: Option<usize>,
+    #[clap(long = "--max_memory_cells", value_parser)]
+    max_memory_cells: Option<usize>,
 }
 
-fn validate_layout(value: &str) -> Result<(), String> {
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs an external function of a deprecated StarkNet contract class
+    RunContract(RunContractArgs),
+}
+
+#[derive(ClapArgs, Debug)]
+struct RunContractArgs {
+    #[clap(value_parser, value_hint=ValueHint::FilePath)]
+    contract_class: PathBuf,
+    /// Name of the function to run, or its selector as a 0x-prefixed hex number
+    #[clap(long = "--entrypoint", value_parser = parse_selector)]
+    selector: Felt,
+    #[clap(long = "--calldata", value_parser = parse_felt, multiple_values = true)]
+    calldata: Vec<Felt>,
+    #[clap(long = "--layout", default_value = "all", validator=validate_layout)]
+    layout: String,
+    #[clap(long = "--max_steps", value_parser)]
+    max_steps: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct ArgsFile(Vec<CairoArg>);
+
+#[derive(Clone, Debug)]
+struct SignaturesFile(Vec<(usize, (Felt, Felt))>);
+
+fn parse_arg(value: &str) -> Result<CairoArg, String> {
+    value.parse().map_err(|e: RunnerError| e.to_string())
+}
+
+// The file holds a JSON array with one element per argument, see `CairoArg`'s `TryFrom`
+// implementation for the accepted values.
+fn parse_args_file(path: &str) -> Result<ArgsFile, String> {
+    let file = File::open(path).map_err(|e| format!("{path}: {e}"))?;
+    let value: serde_json::Value =
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| format!("{path}: {e}"))?;
     match value {
-        "plain" | "small" | "dex" | "bitwise" | "perpetual_with_bitwise" | "recursive" | "all" => {
-            Ok(())
-        }
-        _ => Err(format!("{} is not a valid layout", value)),
+        serde_json::Value::Array(args) => args
+            .iter()
+            .map(CairoArg::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map(ArgsFile)
+            .map_err(|e| e.to_string()),
+        _ => Err(format!("{path}: expected an array of arguments")),
+    }
+}
+
+// The file holds a JSON array with a `[offset, [r, s]]` element per signature, where offset is
+// the position of the signed public key in the ecdsa builtin's segment. The signature values
+// are parsed like field element arguments.
+fn parse_signatures_file(path: &str) -> Result<SignaturesFile, String> {
+    let file = File::open(path).map_err(|e| format!("{path}: {e}"))?;
+    let signatures: Vec<(usize, (serde_json::Value, serde_json::Value))> =
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| format!("{path}: {e}"))?;
+    let parse_value = |value: &serde_json::Value| match CairoArg::try_from(value) {
+        Ok(CairoArg::Single(MaybeRelocatable::Int(value))) => Ok(value),
+        _ => Err(format!("{path}: {value} is not a field element")),
+    };
+    signatures
+        .iter()
+        .map(|(offset, (r, s))| Ok((*offset, (parse_value(r)?, parse_value(s)?))))
+        .collect::<Result<Vec<_>, String>>()
+        .map(SignaturesFile)
+}
+
+fn parse_felt(value: &str) -> Result<Felt, String> {
+    match parse_arg(value)? {
+        CairoArg::Single(MaybeRelocatable::Int(value)) => Ok(value),
+        _ => Err(format!("{value} is not a field element")),
+    }
+}
+
+fn parse_selector(value: &str) -> Result<Felt, String> {
+    if value.starts_with("0x") {
+        parse_felt(value)
+    } else {
+        Ok(get_selector_from_name(value))
+    }
+}
+
+fn validate_layout(value: &str) -> Result<(), String> {
+    match CairoLayout::from_name(value) {
+        Some(_) => Ok(()),
+        None => Err(format!("{} is not a valid layout", value)),
+    }
+}
+
+// Runs the program interactively, returning None if the session was closed before the
+// program reached its end.
+fn run_debugger(
+    filename: &Path,
+    args: &Args,
+    cairo_run_config: &cairo_run::CairoRunConfig,
+    hint_executor: &mut BuiltinHintProcessor,
+) -> Result<Option<(CairoRunner, VirtualMachine)>, CairoRunError> {
+    let program = Program::from_file(filename, Some(&args.entrypoint))?;
+    let mut cairo_runner = CairoRunner::new_with_layout(
+        &program,
+        cairo_run_config.cairo_layout()?,
+        cairo_run_config.proof_mode,
+    );
+    let mut vm = VirtualMachine::new(cairo_run_config.trace_enabled);
+    vm.set_run_limits(cairo_run_config.run_limits);
+    let end = cairo_runner.initialize_with_args(&mut vm, cairo_run_config.args)?;
+    cairo_run::add_signatures(&mut vm, cairo_run_config.signatures)?;
+
+    let finished = Debugger::new(&mut cairo_runner, &mut vm, hint_executor, end)?
+        .run(&mut io::stdin().lock(), &mut io::stdout())?;
+    if !finished {
+        return Ok(None);
+    }
+    cairo_run::finalize_run(&mut cairo_runner, &mut vm, args.print_output, hint_executor)?;
+    Ok(Some((cairo_runner, vm)))
+}
+
+fn run_contract(args: &RunContractArgs) -> Result<(), CairoRunError> {
+    let contract_class = ContractClass::from_file(&args.contract_class)?;
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        layout: &args.layout,
+        run_limits: RunLimits {
+            max_steps: args.max_steps,
+            ..RunLimits::default()
+        },
+        ..cairo_run::CairoRunConfig::default()
+    };
+    let mut hint_executor = SyscallHintProcessor::new(
+        BuiltinHintProcessor::new_empty(),
+        InMemoryState::new(),
+        ExecutionContext::default(),
+    );
+    let retdata = cairo_run::cairo_run_contract_entrypoint(
+        &contract_class,
+        &args.selector,
+        &args.calldata,
+        &cairo_run_config,
+        &mut hint_executor,
+    )?;
+    println!("Retdata:");
+    for value in retdata {
+        println!("{}", value);
     }
+    Ok(())
 }
 
 fn main() -> Result<(), CairoRunError> {
     let args = Args::parse();
-    let trace_enabled = args.trace_file.is_some();
+    // Clap requires the program file unless a subcommand is given.
+    let filename = match (&args.command, &args.filename) {
+        (Some(Command::RunContract(contract_args)), _) => {
+            return run_contract(contract_args).map_err(|error| {
+                println!("{}", error);
+                error
+            })
+        }
+        (None, Some(filename)) => filename.clone(),
+        (None, None) => unreachable!(),
+    };
+    // The public input, the profile and the coverage are computed from the trace.
+    let trace_enabled = args.trace_file.is_some()
+        || args.air_public_input.is_some()
+        || args.profile_output.is_some()
+        || args.coverage.is_some();
     let mut hint_executor = BuiltinHintProcessor::new_empty();
-    let cairo_runner = match cairo_run::cairo_run(
-        &args.filename,
-        &args.entrypoint,
+    let entrypoint_args = match &args.args_file {
+        Some(ArgsFile(file_args)) => file_args,
+        None => &args.args,
+    };
+    let custom_layout = match &args.layout_file {
+        Some(path) => Some(CairoLayout::from_file(path)?),
+        None => None,
+    };
+    let start = Instant::now();
+    let cairo_run_config = cairo_run::CairoRunConfig {
+        entrypoint: &args.entrypoint,
         trace_enabled,
-        args.print_output,
-        &args.layout,
-        args.proof_mode,
-        &mut hint_executor,
-    ) {
-        Ok(runner) => runner,
+        print_output: args.print_output,
+        layout: &args.layout,
+        custom_layout: custom_layout.as_ref(),
+        proof_mode: args.proof_mode,
+        args: entrypoint_args,
+        run_limits: RunLimits {
+            max_steps: args.max_steps,
+            max_memory_cells: args.max_memory_cells,
+            max_segments: None,
+        },
+        signatures: match &args.signatures_file {
+            Some(SignaturesFile(signatures)) => signatures,
+            None => &[],
+        },
+    };
+    let result = if args.debug {
+        run_debugger(&filename, &args, &cairo_run_config, &mut hint_executor)
+    } else if args.run_from_cairo_pie {
+        CairoPie::read_zip_file(&filename)
+            .map_err(CairoRunError::from)
+            .and_then(|cairo_pie| {
+                cairo_run::cairo_run_pie(&cairo_pie, &cairo_run_config, &mut hint_executor)
+            })
+            .map(Some)
+    } else {
+        cairo_run::cairo_run_with_config(&filename, &cairo_run_config, &mut hint_executor).map(Some)
+    };
+    let (cairo_runner, mut vm) = match result {
+        Ok(Some(run)) => run,
+        Ok(None) => return Ok(()),
         Err(error) => {
             println!("{}", error);
             return Err(error);
         }
     };
+    let run_time = start.elapsed();
 
-    if let Some(trace_path) = args.trace_file {
+    if let Some(trace_path) = &args.trace_file {
         let relocated_trace = cairo_runner
             .relocated_trace
             .as_ref()
             .ok_or(CairoRunError::Trace(TraceError::TraceNotEnabled))?;
-        match cairo_run::write_binary_trace(relocated_trace, &trace_path) {
+        match cairo_run::write_binary_trace(relocated_trace, trace_path) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    if let Some(memory_path) = &args.memory_file {
+        match cairo_run::write_binary_memory(&cairo_runner.relocated_memory, memory_path) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    if let Some(public_input_path) = args.air_public_input {
+        let public_input = cairo_runner.get_air_public_input(&vm)?;
+        match cairo_run::write_air_public_input(&public_input, &public_input_path) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    // Clap ensures the trace and memory files were given along with the private input.
+    if let (Some(private_input_path), Some(trace_path), Some(memory_path)) =
+        (args.air_private_input, &args.trace_file, &args.memory_file)
+    {
+        let private_input = cairo_runner.get_air_private_input(&vm);
+        let (trace_path, memory_path) =
+            match (fs::canonicalize(trace_path), fs::canonicalize(memory_path)) {
+                (Ok(trace_path), Ok(memory_path)) => (trace_path, memory_path),
+                _ => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+            };
+        match cairo_run::write_air_private_input(
+            &private_input,
+            &trace_path,
+            &memory_path,
+            &private_input_path,
+        ) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    if let Some(cairo_pie_path) = args.cairo_pie_output {
+        cairo_runner
+            .get_cairo_pie(&vm)?
+            .write_zip_file(&cairo_pie_path)?;
+    }
+
+    if let Some(profile_path) = args.profile_output {
+        let profile = Profile::new(&cairo_runner, &vm)?;
+        let result = File::create(profile_path).and_then(|file| {
+            let mut buffer = BufWriter::new(file);
+            profile.write_collapsed_stacks(&mut buffer, &args.profile_resource)?;
+            buffer.flush()
+        });
+        match result {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    if let Some(coverage_path) = args.coverage {
+        let coverage = Coverage::new(&cairo_runner, &vm)?;
+        let result = File::create(coverage_path).and_then(|file| {
+            let mut buffer = BufWriter::new(file);
+            coverage.write_lcov(&mut buffer)?;
+            buffer.flush()
+        });
+        match result {
             Ok(()) => (),
             Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
         }
     }
 
-    if let Some(memory_path) = args.memory_file {
-        match cairo_run::write_binary_memory(&cairo_runner.relocated_memory, &memory_path) {
+    if let Some(report_path) = args.report {
+        let report = cairo_run::ExecutionReport::new(&cairo_runner, &mut vm, run_time)?;
+        match cairo_run::write_execution_report(&report, &report_path) {
             Ok(()) => (),
             Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
         }
@@ -97,7 +414,12 @@ mod tests {
             "bitwise",
             "perpetual_with_bitwise",
             "recursive",
+            "starknet",
+            "starknet_with_keccak",
+            "recursive_large_output",
+            "all_cairo",
             "all",
+            "dynamic",
         ];
 
         for layout in valid_layouts {
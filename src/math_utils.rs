@@ -1,9 +1,9 @@
+use crate::stdlib::ops::Shr;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use felt::Felt;
 use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{One, Signed, Zero};
-use std::ops::Shr;
 
 ///Returns the integer square root of the nonnegative integer n.
 ///This is the floor of the exact square root of n.
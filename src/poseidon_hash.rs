@@ -0,0 +1,162 @@
+use crate::stdlib::prelude::*;
+use felt::{Felt, FeltOps, NewFelt};
+use lazy_static::lazy_static;
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha256};
+
+// StarkNet's poseidon hash runs the Hades permutation over a state of three field elements,
+// with half of the full rounds before the partial rounds and half after them.
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 83;
+
+lazy_static! {
+    // The constant added to element j of the state in round i is sha256("Hades{3 * i + j}"),
+    // read as a big endian integer and reduced modulo the field prime.
+    static ref ROUND_CONSTANTS: Vec<[Felt; 3]> = (0..FULL_ROUNDS + PARTIAL_ROUNDS)
+        .map(|round| {
+            [0, 1, 2].map(|i| {
+                let digest = Sha256::digest(format!("Hades{}", 3 * round + i).as_bytes());
+                Felt::from_bytes_be(&digest)
+            })
+        })
+        .collect();
+}
+
+/// Applies the Hades permutation used by the poseidon builtin to the state.
+pub fn hades_permutation(state: &mut [Felt; 3]) {
+    for (round, round_constants) in ROUND_CONSTANTS.iter().enumerate() {
+        for (value, constant) in state.iter_mut().zip(round_constants) {
+            *value += constant;
+        }
+        let is_full_round = round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + PARTIAL_ROUNDS;
+        if is_full_round {
+            for value in state.iter_mut() {
+                *value = cube(value);
+            }
+        } else {
+            state[2] = cube(&state[2]);
+        }
+        mix(state);
+    }
+}
+
+/// Computes the poseidon hash of two field elements, as `poseidon_hash` in
+/// `starkware.cairo.common.poseidon_hash` does.
+pub fn poseidon_hash(x: &Felt, y: &Felt) -> Felt {
+    let mut state = [x.clone(), y.clone(), Felt::new(2)];
+    hades_permutation(&mut state);
+    state[0].clone()
+}
+
+/// Computes the poseidon hash of a single field element.
+pub fn poseidon_hash_single(x: &Felt) -> Felt {
+    let mut state = [x.clone(), Felt::zero(), Felt::one()];
+    hades_permutation(&mut state);
+    state[0].clone()
+}
+
+/// Computes the poseidon hash of a sequence of field elements with the sponge construction
+/// used by `poseidon_hash_many`: the input is padded with a 1 and then with zeros up to an
+/// even length, and absorbed two elements at a time.
+pub fn poseidon_hash_many(elements: &[Felt]) -> Felt {
+    let mut padded = elements.to_vec();
+    padded.push(Felt::one());
+    if padded.len() % 2 == 1 {
+        padded.push(Felt::zero());
+    }
+    let mut state = [Felt::zero(), Felt::zero(), Felt::zero()];
+    for pair in padded.chunks(2) {
+        state[0] += &pair[0];
+        state[1] += &pair[1];
+        hades_permutation(&mut state);
+    }
+    state[0].clone()
+}
+
+fn cube(value: &Felt) -> Felt {
+    value * value * value
+}
+
+// Multiplies the state by the MDS matrix [[3, 1, 1], [1, -1, 1], [1, 1, -2]].
+fn mix(state: &mut [Felt; 3]) {
+    let [a, b, c] = state;
+    let sum = &*a + &*b + &*c;
+    *a = sum.clone() + &*a + &*a;
+    *b = sum.clone() - &*b - &*b;
+    *c = sum - &*c - &*c - &*c;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felt_hex(hex: &str) -> Felt {
+        Felt::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    #[test]
+    fn hades_permutation_zero_state() {
+        let mut state = [Felt::zero(), Felt::zero(), Felt::zero()];
+        hades_permutation(&mut state);
+        assert_eq!(
+            state,
+            [
+                felt_hex("79e8d1e78258000a28fc9d49e233bc6852357968577b1e386550ed6a9086133"),
+                felt_hex("3840d003d0f3f96dbb796ff6aa6a63be5b5404b91ccaabca256154cbb6fb984"),
+                felt_hex("1eb39da3f7d3b04142d0ac83d9da00c9325a61fb2ef326e50b70eaa8a3c7cc7"),
+            ]
+        );
+    }
+
+    #[test]
+    fn hades_permutation_hash_of_two_elements() {
+        // poseidon_hash(1, 2) is the first element of the permutation of [1, 2, 2].
+        let mut state = [Felt::new(1), Felt::new(2), Felt::new(2)];
+        hades_permutation(&mut state);
+        assert_eq!(
+            state[0],
+            felt_hex("5d44a3decb2b2e0cc71071f7b802f45dd792d064f0fc7316c46514f70f9891a")
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_two_elements() {
+        assert_eq!(
+            poseidon_hash(&Felt::new(1), &Felt::new(2)),
+            felt_hex("5d44a3decb2b2e0cc71071f7b802f45dd792d064f0fc7316c46514f70f9891a")
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_single_element() {
+        assert_eq!(
+            poseidon_hash_single(&Felt::new(1)),
+            felt_hex("6d226d4c804cd74567f5ac59c6a4af1fe2a6eced19fb7560a9124579877da25")
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_many_no_elements() {
+        assert_eq!(
+            poseidon_hash_many(&[]),
+            felt_hex("2272be0f580fd156823304800919530eaa97430e972d7213ee13f4fbf7a5dbc")
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_many_odd_number_of_elements() {
+        let elements: Vec<Felt> = (1..14).map(Felt::new).collect();
+        assert_eq!(
+            poseidon_hash_many(&elements),
+            felt_hex("2c48a4b530110b8701059a92177c031177812c94847f306738b1ada5e4ed5e4")
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_many_even_number_of_elements() {
+        assert_eq!(
+            poseidon_hash_many(&[Felt::new(1), Felt::new(2)]),
+            felt_hex("371cb6995ea5e7effcd2e174de264b5b407027a75a231a70c2c8d196107f0e7")
+        );
+    }
+}
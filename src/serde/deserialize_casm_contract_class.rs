@@ -0,0 +1,183 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+use crate::{
+    serde::{
+        deserialize_contract_class::EntryPointType,
+        deserialize_program::{
+            deserialize_array_of_bigint_hex, deserialize_felt_hex, ApTracking, FlowTrackingData,
+            HintParams,
+        },
+    },
+    types::{
+        casm_contract_class::CasmContractClass,
+        errors::{contract_class_errors::ContractClassError, program_errors::ProgramError},
+        program::Program,
+        relocatable::MaybeRelocatable,
+    },
+};
+use felt::{Felt, PRIME_STR};
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+#[derive(Deserialize, Debug)]
+pub struct CasmContractClassJson {
+    pub prime: String,
+    pub compiler_version: String,
+    #[serde(deserialize_with = "deserialize_array_of_bigint_hex")]
+    pub bytecode: Vec<MaybeRelocatable>,
+    pub hints: Vec<(usize, Vec<serde_json::Value>)>,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<CasmContractEntryPoint>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CasmContractEntryPoint {
+    #[serde(deserialize_with = "deserialize_felt_hex")]
+    pub selector: Felt,
+    pub offset: usize,
+    pub builtins: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+pub fn deserialize_casm_contract_class(
+    reader: impl Read,
+) -> Result<CasmContractClass, ContractClassError> {
+    let casm_json: CasmContractClassJson = serde_json::from_reader(reader)?;
+    parse_casm_contract_class_json(casm_json)
+}
+
+pub fn deserialize_casm_contract_class_from_bytes(
+    bytes: &[u8],
+) -> Result<CasmContractClass, ContractClassError> {
+    let casm_json: CasmContractClassJson = serde_json::from_slice(bytes)?;
+    parse_casm_contract_class_json(casm_json)
+}
+
+// Cairo 1 hints aren't Python code but structured objects. Each one is kept as its JSON
+// serialization in the `code` of a `HintParams`, with no references, for a hint processor
+// that understands them to parse back.
+pub(crate) fn parse_casm_contract_class_json(
+    casm_json: CasmContractClassJson,
+) -> Result<CasmContractClass, ContractClassError> {
+    if PRIME_STR != casm_json.prime {
+        return Err(ProgramError::PrimeDiffers(casm_json.prime).into());
+    }
+
+    let mut hints = HashMap::new();
+    for (pc, pc_hints) in casm_json.hints {
+        let hint_params = pc_hints
+            .iter()
+            .map(|hint| {
+                Ok(HintParams {
+                    code: serde_json::to_string(hint)?,
+                    accessible_scopes: Vec::new(),
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+        hints.insert(pc, hint_params);
+    }
+
+    Ok(CasmContractClass {
+        compiler_version: casm_json.compiler_version,
+        program: Program {
+            data: casm_json.bytecode,
+            hints,
+            ..Program::default()
+        },
+        entry_points_by_type: casm_json.entry_points_by_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    const CASM_CONTRACT_CLASS: &str = r#"{
+        "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+        "compiler_version": "1.0.0",
+        "bytecode": ["0xa0680017fff8000", "0x7", "0x482680017ffa8000", "0x208b7fff7fff7ffe"],
+        "hints": [
+            [0, [{ "TestLessThanOrEqual": {
+                "lhs": { "Immediate": "0x0" },
+                "rhs": { "Deref": { "register": "FP", "offset": -6 } },
+                "dst": { "register": "AP", "offset": 0 }
+            } }]],
+            [2, [
+                { "AllocSegment": { "dst": { "register": "AP", "offset": 0 } } },
+                { "AllocSegment": { "dst": { "register": "AP", "offset": 1 } } }
+            ]]
+        ],
+        "entry_points_by_type": {
+            "CONSTRUCTOR": [],
+            "EXTERNAL": [{ "selector": "0x1b", "offset": 0, "builtins": ["range_check"] }],
+            "L1_HANDLER": []
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_casm_contract_class_bytecode_and_entry_points() {
+        let casm_contract_class =
+            deserialize_casm_contract_class(CASM_CONTRACT_CLASS.as_bytes()).unwrap();
+        assert_eq!(casm_contract_class.compiler_version, "1.0.0");
+        assert_eq!(
+            casm_contract_class.program.data,
+            [
+                MaybeRelocatable::from(Felt::new(0xa0680017fff8000_i64)),
+                MaybeRelocatable::from(Felt::new(7)),
+                MaybeRelocatable::from(Felt::new(0x482680017ffa8000_u64)),
+                MaybeRelocatable::from(Felt::new(0x208b7fff7fff7ffe_u64)),
+            ]
+        );
+        assert_eq!(
+            casm_contract_class.entry_points_by_type[&EntryPointType::External],
+            [CasmContractEntryPoint {
+                selector: Felt::new(0x1b),
+                offset: 0,
+                builtins: vec!["range_check".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn deserialize_casm_contract_class_hints() {
+        let casm_contract_class =
+            deserialize_casm_contract_class(CASM_CONTRACT_CLASS.as_bytes()).unwrap();
+        let hints = &casm_contract_class.program.hints;
+        assert_eq!(hints.len(), 2);
+        assert_eq!(hints[&0].len(), 1);
+        assert_eq!(
+            hints[&2]
+                .iter()
+                .map(|hint| hint.code.as_str())
+                .collect::<Vec<_>>(),
+            [
+                r#"{"AllocSegment":{"dst":{"offset":0,"register":"AP"}}}"#,
+                r#"{"AllocSegment":{"dst":{"offset":1,"register":"AP"}}}"#,
+            ]
+        );
+        assert!(hints[&2][0].flow_tracking_data.reference_ids.is_empty());
+    }
+
+    #[test]
+    fn deserialize_casm_contract_class_wrong_prime() {
+        let casm_json = CASM_CONTRACT_CLASS.replace("0x8000000000000110", "0x8000000000000120");
+        assert!(matches!(
+            deserialize_casm_contract_class(casm_json.as_bytes()),
+            Err(ContractClassError::Program(ProgramError::PrimeDiffers(_)))
+        ));
+    }
+
+    #[test]
+    fn deserialize_casm_contract_class_cairo_0_program() {
+        let reader =
+            std::fs::File::open("cairo_programs/manually_compiled/valid_program_a.json").unwrap();
+        assert!(matches!(
+            deserialize_casm_contract_class(reader),
+            Err(ContractClassError::Parse(_))
+        ));
+    }
+}
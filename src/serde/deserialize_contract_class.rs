@@ -0,0 +1,133 @@
+use crate::stdlib::{collections::HashMap, prelude::*};
+use crate::{
+    serde::deserialize_program::{deserialize_felt_hex, parse_program_json, ProgramJson},
+    types::{contract_class::ContractClass, errors::contract_class_errors::ContractClassError},
+};
+use felt::Felt;
+use num_traits::ToPrimitive;
+use serde::{de, Deserialize, Deserializer};
+#[cfg(feature = "std")]
+use std::io::Read;
+
+#[derive(Deserialize, Debug)]
+pub struct ContractClassJson {
+    pub program: ProgramJson,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+    #[serde(default)]
+    pub abi: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EntryPointType {
+    External,
+    L1Handler,
+    Constructor,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractEntryPoint {
+    #[serde(deserialize_with = "deserialize_felt_hex")]
+    pub selector: Felt,
+    #[serde(deserialize_with = "deserialize_offset")]
+    pub offset: usize,
+}
+
+// Offsets are written as hex strings, like the program data.
+fn deserialize_offset<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+    deserialize_felt_hex(d)?
+        .to_usize()
+        .ok_or_else(|| de::Error::custom("entrypoint offset out of range"))
+}
+
+#[cfg(feature = "std")]
+pub fn deserialize_contract_class(reader: impl Read) -> Result<ContractClass, ContractClassError> {
+    let contract_class_json: ContractClassJson = serde_json::from_reader(reader)?;
+    parse_contract_class_json(contract_class_json)
+}
+
+pub fn deserialize_contract_class_from_bytes(
+    bytes: &[u8],
+) -> Result<ContractClass, ContractClassError> {
+    let contract_class_json: ContractClassJson = serde_json::from_slice(bytes)?;
+    parse_contract_class_json(contract_class_json)
+}
+
+fn parse_contract_class_json(
+    contract_class_json: ContractClassJson,
+) -> Result<ContractClass, ContractClassError> {
+    Ok(ContractClass {
+        program: parse_program_json(contract_class_json.program, None)?,
+        entry_points_by_type: contract_class_json.entry_points_by_type,
+        abi: contract_class_json.abi,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    const CONTRACT_CLASS: &str = r#"{
+        "abi": [{ "name": "echo", "type": "function", "inputs": [], "outputs": [] }],
+        "entry_points_by_type": {
+            "CONSTRUCTOR": [],
+            "EXTERNAL": [{ "offset": "0x2", "selector": "0x1b" }],
+            "L1_HANDLER": []
+        },
+        "program": {
+            "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+            "builtins": ["pedersen", "range_check"],
+            "data": ["0x480a7ffc7fff8000", "0x480a7ffd7fff8000", "0x208b7fff7fff7ffe"],
+            "identifiers": {},
+            "hints": {},
+            "reference_manager": { "references": [] },
+            "attributes": [],
+            "debug_info": null,
+            "main_scope": "__main__",
+            "compiler_version": "0.10.3"
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_contract_class_entry_points() {
+        let contract_class = deserialize_contract_class(CONTRACT_CLASS.as_bytes()).unwrap();
+        assert_eq!(
+            contract_class.entry_points_by_type,
+            HashMap::from([
+                (EntryPointType::Constructor, vec![]),
+                (
+                    EntryPointType::External,
+                    vec![ContractEntryPoint {
+                        selector: Felt::new(0x1b),
+                        offset: 2,
+                    }]
+                ),
+                (EntryPointType::L1Handler, vec![]),
+            ])
+        );
+        assert_eq!(contract_class.program.builtins, ["pedersen", "range_check"]);
+        assert_eq!(contract_class.program.data.len(), 3);
+        assert_eq!(contract_class.program.main, None);
+        assert!(contract_class.abi.is_some());
+    }
+
+    #[test]
+    fn deserialize_contract_class_without_program() {
+        let contract_class_json = r#"{ "entry_points_by_type": {} }"#;
+        assert!(matches!(
+            deserialize_contract_class(contract_class_json.as_bytes()),
+            Err(ContractClassError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn deserialize_contract_class_wrong_prime() {
+        let contract_class_json =
+            CONTRACT_CLASS.replace("0x8000000000000110", "0x8000000000000120");
+        assert!(matches!(
+            deserialize_contract_class(contract_class_json.as_bytes()),
+            Err(ContractClassError::Program(_))
+        ));
+    }
+}
@@ -0,0 +1,414 @@
+use crate::stdlib::prelude::*;
+use crate::types::{
+    errors::layout_errors::LayoutError,
+    instance_definitions::{
+        bitwise_instance_def::BitwiseInstanceDef, builtins_instance_def::BuiltinsInstanceDef,
+        cpu_instance_def::CpuInstanceDef, diluted_pool_instance_def::DilutedPoolInstanceDef,
+        ec_op_instance_def::EcOpInstanceDef, ecdsa_instance_def::EcdsaInstanceDef,
+        keccak_instance_def::KeccakInstanceDef, pedersen_instance_def::PedersenInstanceDef,
+        poseidon_instance_def::PoseidonInstanceDef,
+        range_check_instance_def::RangeCheckInstanceDef,
+    },
+    layout::CairoLayout,
+};
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+// Each step uses three range check units for the offsets of its instruction, and four memory
+// units for the instruction and its operands.
+const RC_UNITS_PER_INSTRUCTION: u32 = 3;
+const MEMORY_UNITS_PER_INSTRUCTION: u32 = 4;
+// The runner computes `1 << n_bits` as a usize, which has to fit on 32-bit targets too, and the
+// diluted form of an `n_bits` value, `spacing` bits apart, has to fit in a felt.
+const MAX_DILUTED_N_BITS: u32 = 31;
+const MAX_DILUTED_TOTAL_BITS: u32 = 251;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct LayoutJson {
+    pub name: String,
+    pub rc_units: u32,
+    pub public_memory_fraction: u32,
+    pub memory_units_per_step: u32,
+    #[serde(default)]
+    pub diluted_pool: Option<DilutedPoolJson>,
+    #[serde(default)]
+    pub builtins: BuiltinsJson,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct BuiltinsJson {
+    #[serde(default)]
+    pub output: bool,
+    pub pedersen: Option<PedersenJson>,
+    pub range_check: Option<RangeCheckJson>,
+    pub ecdsa: Option<RatioJson>,
+    pub bitwise: Option<RatioJson>,
+    pub ec_op: Option<RatioJson>,
+    pub keccak: Option<RatioJson>,
+    pub poseidon: Option<RatioJson>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RatioJson {
+    pub ratio: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PedersenJson {
+    pub ratio: u32,
+    #[serde(default = "default_pedersen_repetitions")]
+    pub repetitions: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RangeCheckJson {
+    pub ratio: u32,
+    #[serde(default = "default_range_check_n_parts")]
+    pub n_parts: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DilutedPoolJson {
+    pub units_per_step: u32,
+    pub spacing: u32,
+    pub n_bits: u32,
+}
+
+fn default_pedersen_repetitions() -> u32 {
+    PedersenInstanceDef::default()._repetitions
+}
+
+fn default_range_check_n_parts() -> u32 {
+    RangeCheckInstanceDef::default().n_parts
+}
+
+// Rejects the values the runner would divide by or subtract from.
+fn check_value(field: &'static str, value: u32, min: u32) -> Result<u32, LayoutError> {
+    if value < min {
+        return Err(LayoutError::InvalidValue(field, value));
+    }
+    Ok(value)
+}
+
+fn check_max(field: &'static str, value: u32, max: u32) -> Result<u32, LayoutError> {
+    if value > max {
+        return Err(LayoutError::InvalidValue(field, value));
+    }
+    Ok(value)
+}
+
+#[cfg(feature = "std")]
+pub fn deserialize_layout(reader: impl Read) -> Result<CairoLayout, LayoutError> {
+    let layout_json: LayoutJson = serde_json::from_reader(reader)?;
+    parse_layout_json(layout_json)
+}
+
+pub fn deserialize_layout_from_bytes(bytes: &[u8]) -> Result<CairoLayout, LayoutError> {
+    let layout_json: LayoutJson = serde_json::from_slice(bytes)?;
+    parse_layout_json(layout_json)
+}
+
+fn parse_layout_json(layout_json: LayoutJson) -> Result<CairoLayout, LayoutError> {
+    let builtins = layout_json.builtins;
+    let builtins = BuiltinsInstanceDef {
+        _output: builtins.output,
+        pedersen: match builtins.pedersen {
+            Some(pedersen) => Some(PedersenInstanceDef::new(
+                check_value("pedersen.ratio", pedersen.ratio, 1)?,
+                check_value("pedersen.repetitions", pedersen.repetitions, 1)?,
+            )),
+            None => None,
+        },
+        range_check: match builtins.range_check {
+            Some(range_check) => Some(RangeCheckInstanceDef::new(
+                check_value("range_check.ratio", range_check.ratio, 1)?,
+                check_value("range_check.n_parts", range_check.n_parts, 1)?,
+            )),
+            None => None,
+        },
+        _ecdsa: match builtins.ecdsa {
+            Some(ecdsa) => Some(EcdsaInstanceDef::new(check_value(
+                "ecdsa.ratio",
+                ecdsa.ratio,
+                1,
+            )?)),
+            None => None,
+        },
+        bitwise: match builtins.bitwise {
+            Some(bitwise) => Some(BitwiseInstanceDef::new(check_value(
+                "bitwise.ratio",
+                bitwise.ratio,
+                1,
+            )?)),
+            None => None,
+        },
+        ec_op: match builtins.ec_op {
+            Some(ec_op) => Some(EcOpInstanceDef::new(check_value(
+                "ec_op.ratio",
+                ec_op.ratio,
+                1,
+            )?)),
+            None => None,
+        },
+        keccak: match builtins.keccak {
+            Some(keccak) => Some(KeccakInstanceDef::new(check_value(
+                "keccak.ratio",
+                keccak.ratio,
+                1,
+            )?)),
+            None => None,
+        },
+        poseidon: match builtins.poseidon {
+            Some(poseidon) => Some(PoseidonInstanceDef::new(check_value(
+                "poseidon.ratio",
+                poseidon.ratio,
+                1,
+            )?)),
+            None => None,
+        },
+    };
+
+    let diluted_pool_instance_def = match layout_json.diluted_pool {
+        Some(diluted_pool) => {
+            let spacing = check_value("diluted_pool.spacing", diluted_pool.spacing, 1)?;
+            let n_bits = check_max(
+                "diluted_pool.n_bits",
+                check_value("diluted_pool.n_bits", diluted_pool.n_bits, 1)?,
+                MAX_DILUTED_N_BITS,
+            )?;
+            check_max(
+                "diluted_pool.spacing * diluted_pool.n_bits",
+                spacing.saturating_mul(n_bits),
+                MAX_DILUTED_TOTAL_BITS,
+            )?;
+            Some(DilutedPoolInstanceDef::new(
+                check_value(
+                    "diluted_pool.units_per_step",
+                    diluted_pool.units_per_step,
+                    1,
+                )?,
+                spacing,
+                n_bits,
+            ))
+        }
+        None => None,
+    };
+
+    // A fraction of the memory units of each step goes to public memory, and at least four of
+    // the rest have to be left for the instruction.
+    let public_memory_fraction = check_value(
+        "public_memory_fraction",
+        layout_json.public_memory_fraction,
+        2,
+    )?;
+    let memory_units_per_step = layout_json.memory_units_per_step;
+    if (memory_units_per_step as u64) * (public_memory_fraction as u64 - 1)
+        < MEMORY_UNITS_PER_INSTRUCTION as u64 * public_memory_fraction as u64
+    {
+        return Err(LayoutError::InvalidValue(
+            "memory_units_per_step",
+            memory_units_per_step,
+        ));
+    }
+
+    Ok(CairoLayout {
+        _name: layout_json.name,
+        _cpu_component_step: 1,
+        rc_units: check_value("rc_units", layout_json.rc_units, RC_UNITS_PER_INSTRUCTION)?,
+        builtins,
+        _public_memory_fraction: public_memory_fraction,
+        _memory_units_per_step: memory_units_per_step,
+        diluted_pool_instance_def,
+        _n_trace_colums: 0,
+        _cpu_instance_def: CpuInstanceDef::default(),
+        dynamic: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_layout_with_all_fields() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 16,
+            "diluted_pool": { "units_per_step": 2, "spacing": 4, "n_bits": 16 },
+            "builtins": {
+                "output": true,
+                "pedersen": { "ratio": 32, "repetitions": 1 },
+                "range_check": { "ratio": 16 },
+                "ecdsa": { "ratio": 2048 },
+                "bitwise": { "ratio": 64 },
+                "ec_op": { "ratio": 1024 },
+                "keccak": { "ratio": 4096 },
+                "poseidon": { "ratio": 256 }
+            }
+        }"#;
+        let layout = deserialize_layout(layout_json.as_bytes()).unwrap();
+
+        assert_eq!(layout._name, "custom");
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 16);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(2, 4, 16))
+        );
+        assert_eq!(
+            layout.builtins,
+            BuiltinsInstanceDef {
+                _output: true,
+                pedersen: Some(PedersenInstanceDef::new(32, 1)),
+                range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+                _ecdsa: Some(EcdsaInstanceDef::new(2048)),
+                bitwise: Some(BitwiseInstanceDef::new(64)),
+                ec_op: Some(EcOpInstanceDef::new(1024)),
+                keccak: Some(KeccakInstanceDef::new(4096)),
+                poseidon: Some(PoseidonInstanceDef::new(256)),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_layout_without_builtins() {
+        let layout_json = r#"{
+            "name": "tiny",
+            "rc_units": 16,
+            "public_memory_fraction": 4,
+            "memory_units_per_step": 8
+        }"#;
+        let layout = deserialize_layout(layout_json.as_bytes()).unwrap();
+
+        assert_eq!(layout.builtins, BuiltinsInstanceDef::plain());
+        assert_eq!(layout.diluted_pool_instance_def, None);
+    }
+
+    #[test]
+    fn deserialize_layout_zero_ratio() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "builtins": { "bitwise": { "ratio": 0 } }
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue("bitwise.ratio", 0))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_too_few_rc_units() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 2,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue("rc_units", 2))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_public_memory_fraction_one() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 1,
+            "memory_units_per_step": 8
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue("public_memory_fraction", 1))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_no_memory_units_left_for_instruction() {
+        // Half of the 6 units per step are public, which leaves 3 for the instruction.
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 2,
+            "memory_units_per_step": 6
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue("memory_units_per_step", 6))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_diluted_n_bits_too_large() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "diluted_pool": { "units_per_step": 2, "spacing": 1, "n_bits": 64 }
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue("diluted_pool.n_bits", 64))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_diluted_spacing_too_large() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "diluted_pool": { "units_per_step": 2, "spacing": 16, "n_bits": 16 }
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::InvalidValue(
+                "diluted_pool.spacing * diluted_pool.n_bits",
+                256
+            ))
+        ));
+    }
+
+    #[test]
+    fn deserialize_layout_named_dynamic() {
+        let layout_json = r#"{
+            "name": "dynamic",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8
+        }"#;
+        let layout = deserialize_layout(layout_json.as_bytes()).unwrap();
+        assert!(!layout.is_dynamic());
+    }
+
+    #[test]
+    fn deserialize_layout_unknown_builtin() {
+        let layout_json = r#"{
+            "name": "custom",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "builtins": { "sha256": { "ratio": 8 } }
+        }"#;
+        assert!(matches!(
+            deserialize_layout(layout_json.as_bytes()),
+            Err(LayoutError::Parse(_))
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::stdlib::{collections::HashMap, fmt, prelude::*};
 use crate::{
     serde::deserialize_utils,
     types::{
@@ -8,7 +9,8 @@ use crate::{
 use felt::{Felt, FeltOps, PRIME_STR};
 use serde::{de, de::MapAccess, de::SeqAccess, Deserialize, Deserializer};
 use serde_json::Number;
-use std::{collections::HashMap, fmt, io::Read};
+#[cfg(feature = "std")]
+use std::io::Read;
 
 #[derive(Deserialize, Debug)]
 pub struct ProgramJson {
@@ -306,17 +308,35 @@ pub fn deserialize_value_address<'de, D: Deserializer<'de>>(
     d.deserialize_str(ValueAddressVisitor)
 }
 
+#[cfg(feature = "std")]
 pub fn deserialize_program_json(reader: impl Read) -> Result<ProgramJson, ProgramError> {
     let program_json = serde_json::from_reader(reader)?;
     Ok(program_json)
 }
 
+#[cfg(feature = "std")]
 pub fn deserialize_program(
     reader: impl Read,
     entrypoint: Option<&str>,
 ) -> Result<Program, ProgramError> {
     let program_json: ProgramJson = deserialize_program_json(reader)?;
+    parse_program_json(program_json, entrypoint)
+}
 
+/// Same as `deserialize_program`, but reads the program from a byte slice, which doesn't need
+/// `std`.
+pub fn deserialize_program_from_bytes(
+    bytes: &[u8],
+    entrypoint: Option<&str>,
+) -> Result<Program, ProgramError> {
+    let program_json: ProgramJson = serde_json::from_slice(bytes)?;
+    parse_program_json(program_json, entrypoint)
+}
+
+pub(crate) fn parse_program_json(
+    program_json: ProgramJson,
+    entrypoint: Option<&str>,
+) -> Result<Program, ProgramError> {
     if PRIME_STR != program_json.prime {
         return Err(ProgramError::PrimeDiffers(program_json.prime));
     }
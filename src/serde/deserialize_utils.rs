@@ -1,3 +1,4 @@
+use crate::stdlib::{fmt, num::ParseIntError, prelude::*, str::FromStr};
 use crate::{
     serde::deserialize_program::{OffsetValue, ValueAddress},
     types::instruction::Register,
@@ -17,7 +18,6 @@ use nom::{
 };
 use num_integer::Integer;
 use parse_hyperlinks::take_until_unbalanced;
-use std::{fmt, num::ParseIntError, str::FromStr};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ReferenceParseError {
@@ -1,2 +1,5 @@
+pub mod deserialize_casm_contract_class;
+pub mod deserialize_contract_class;
+pub mod deserialize_layout;
 pub mod deserialize_program;
 pub mod deserialize_utils;
@@ -0,0 +1,56 @@
+//! The parts of the standard library used by the VM. They come from `std` when the `std`
+//! feature is enabled, and from `core`, `alloc` and `hashbrown` otherwise, so the rest of the
+//! crate can import them from the same paths either way.
+
+pub use core::{any, cell, cmp, fmt, hash, iter, mem, num, ops, str, time};
+
+#[cfg(not(feature = "std"))]
+pub use alloc::{borrow, boxed, format, rc, string, vec};
+#[cfg(feature = "std")]
+pub use std::{borrow, boxed, format, rc, string, vec};
+
+pub mod collections {
+    #[cfg(not(feature = "std"))]
+    pub use alloc::collections::{BTreeMap, BTreeSet};
+    #[cfg(not(feature = "std"))]
+    pub use hashbrown::{HashMap, HashSet};
+    #[cfg(feature = "std")]
+    pub use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+}
+
+/// `alloc` has no mutex, so `spin`'s is used without `std`. `lock` hides the difference between
+/// their APIs.
+pub mod sync {
+    #[cfg(not(feature = "std"))]
+    pub use alloc::sync::Arc;
+    #[cfg(not(feature = "std"))]
+    pub use spin::{Mutex, MutexGuard};
+    #[cfg(feature = "std")]
+    pub use std::sync::{Arc, Mutex, MutexGuard};
+
+    /// Locks `mutex`, ignoring poisoning. A panic while holding one of the VM's locks already
+    /// leaves the run in an unusable state, so there's nothing to recover.
+    #[cfg(feature = "std")]
+    pub fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock()
+    }
+}
+
+/// The items of the `std` prelude that aren't in the `core` one.
+pub mod prelude {
+    pub use super::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+}
@@ -0,0 +1,119 @@
+#[cfg(feature = "std")]
+use crate::serde::deserialize_casm_contract_class::deserialize_casm_contract_class;
+use crate::{
+    serde::{
+        deserialize_casm_contract_class::{
+            deserialize_casm_contract_class_from_bytes, CasmContractEntryPoint,
+        },
+        deserialize_contract_class::EntryPointType,
+    },
+    stdlib::{collections::HashMap, prelude::*},
+    types::{errors::contract_class_errors::ContractClassError, program::Program},
+};
+use felt::Felt;
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+/// A Cairo 1 contract class compiled to CASM by `starknet-sierra-compile`. Its bytecode and
+/// hints are kept in `program`, while the builtins each entrypoint takes are listed in the
+/// entrypoint itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CasmContractClass {
+    pub compiler_version: String,
+    pub program: Program,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<CasmContractEntryPoint>>,
+}
+
+impl CasmContractClass {
+    #[cfg(feature = "std")]
+    pub fn from_file(path: &Path) -> Result<CasmContractClass, ContractClassError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        deserialize_casm_contract_class(reader)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_reader(reader: impl Read) -> Result<CasmContractClass, ContractClassError> {
+        deserialize_casm_contract_class(reader)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<CasmContractClass, ContractClassError> {
+        deserialize_casm_contract_class_from_bytes(bytes)
+    }
+
+    /// Returns the external entrypoint with the given selector.
+    pub fn get_external_entrypoint(
+        &self,
+        selector: &Felt,
+    ) -> Result<&CasmContractEntryPoint, ContractClassError> {
+        self.entry_points_by_type
+            .get(&EntryPointType::External)
+            .and_then(|entry_points| {
+                entry_points
+                    .iter()
+                    .find(|entry_point| &entry_point.selector == selector)
+            })
+            .ok_or_else(|| ContractClassError::EntrypointNotFound(selector.clone()))
+    }
+
+    /// Returns a program that runs the given entrypoint with the builtins it takes, which can
+    /// be passed to `CairoRunner::new`.
+    pub fn get_entrypoint_program(&self, entry_point: &CasmContractEntryPoint) -> Program {
+        Program {
+            builtins: entry_point.builtins.clone(),
+            main: Some(entry_point.offset),
+            ..self.program.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    #[test]
+    fn get_entrypoint_program() {
+        let casm_contract_class = CasmContractClass::from_reader(
+            r#"{
+                "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+                "compiler_version": "1.0.0",
+                "bytecode": ["0x208b7fff7fff7ffe", "0x480680017fff8000", "0x208b7fff7fff7ffe"],
+                "hints": [],
+                "entry_points_by_type": {
+                    "EXTERNAL": [
+                        { "selector": "0x1", "offset": 0, "builtins": [] },
+                        { "selector": "0x2", "offset": 1, "builtins": ["pedersen", "range_check"] }
+                    ]
+                }
+            }"#
+            .as_bytes(),
+        )
+        .unwrap();
+        let entry_point = casm_contract_class
+            .get_external_entrypoint(&Felt::new(2))
+            .unwrap();
+        let program = casm_contract_class.get_entrypoint_program(entry_point);
+        assert_eq!(program.builtins, ["pedersen", "range_check"]);
+        assert_eq!(program.main, Some(1));
+        assert_eq!(program.data, casm_contract_class.program.data);
+    }
+
+    #[test]
+    fn get_external_entrypoint_not_found() {
+        let casm_contract_class = CasmContractClass {
+            compiler_version: "1.0.0".to_string(),
+            program: Program::default(),
+            entry_points_by_type: HashMap::new(),
+        };
+        assert!(matches!(
+            casm_contract_class.get_external_entrypoint(&Felt::new(1)),
+            Err(ContractClassError::EntrypointNotFound(selector)) if selector == Felt::new(1)
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "std")]
+use crate::serde::deserialize_contract_class::deserialize_contract_class;
+use crate::{
+    serde::deserialize_contract_class::{
+        deserialize_contract_class_from_bytes, ContractEntryPoint, EntryPointType,
+    },
+    stdlib::{collections::HashMap, prelude::*},
+    types::{errors::contract_class_errors::ContractClassError, program::Program},
+};
+use felt::{Felt, FeltOps};
+use sha3::{Digest, Keccak256};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+/// A deprecated StarkNet contract class, as compiled by `starknet-compile`: a program and
+/// the offsets of the functions it exposes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractClass {
+    pub program: Program,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+    pub abi: Option<serde_json::Value>,
+}
+
+impl ContractClass {
+    #[cfg(feature = "std")]
+    pub fn from_file(path: &Path) -> Result<ContractClass, ContractClassError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        deserialize_contract_class(reader)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_reader(reader: impl Read) -> Result<ContractClass, ContractClassError> {
+        deserialize_contract_class(reader)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<ContractClass, ContractClassError> {
+        deserialize_contract_class_from_bytes(bytes)
+    }
+
+    /// Returns the external entrypoint with the given selector.
+    pub fn get_external_entrypoint(
+        &self,
+        selector: &Felt,
+    ) -> Result<&ContractEntryPoint, ContractClassError> {
+        self.entry_points_by_type
+            .get(&EntryPointType::External)
+            .and_then(|entry_points| {
+                entry_points
+                    .iter()
+                    .find(|entry_point| &entry_point.selector == selector)
+            })
+            .ok_or_else(|| ContractClassError::EntrypointNotFound(selector.clone()))
+    }
+}
+
+/// Returns the selector of the function with the given name: the keccak256 hash of the
+/// name, truncated to 250 bits.
+pub fn get_selector_from_name(name: &str) -> Felt {
+    let mut hash = Keccak256::digest(name.as_bytes());
+    hash[0] &= 0b0000_0011;
+    Felt::from_bytes_be(&hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use felt::NewFelt;
+
+    fn contract_class() -> ContractClass {
+        ContractClass {
+            program: Program::default(),
+            entry_points_by_type: HashMap::from([
+                (
+                    EntryPointType::External,
+                    vec![
+                        ContractEntryPoint {
+                            selector: Felt::new(1),
+                            offset: 10,
+                        },
+                        ContractEntryPoint {
+                            selector: Felt::new(2),
+                            offset: 20,
+                        },
+                    ],
+                ),
+                (
+                    EntryPointType::L1Handler,
+                    vec![ContractEntryPoint {
+                        selector: Felt::new(3),
+                        offset: 30,
+                    }],
+                ),
+            ]),
+            abi: None,
+        }
+    }
+
+    #[test]
+    fn get_external_entrypoint() {
+        let contract_class = contract_class();
+        assert_eq!(
+            contract_class
+                .get_external_entrypoint(&Felt::new(2))
+                .map(|entry_point| entry_point.offset)
+                .ok(),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn get_external_entrypoint_l1_handler() {
+        let contract_class = contract_class();
+        assert!(matches!(
+            contract_class.get_external_entrypoint(&Felt::new(3)),
+            Err(ContractClassError::EntrypointNotFound(selector)) if selector == Felt::new(3)
+        ));
+    }
+
+    #[test]
+    fn get_selector_from_name_transfer() {
+        assert_eq!(
+            get_selector_from_name("transfer"),
+            Felt::parse_bytes(
+                b"83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e",
+                16
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn get_selector_from_name_execute() {
+        assert_eq!(
+            get_selector_from_name("__execute__"),
+            Felt::parse_bytes(
+                b"15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad",
+                16
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use super::program_errors::ProgramError;
+use felt::Felt;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
+
+#[derive(Debug, Error)]
+pub enum ContractClassError {
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    #[error("No external entrypoint with selector {0}")]
+    EntrypointNotFound(Felt),
+}
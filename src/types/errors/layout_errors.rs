@@ -0,0 +1,17 @@
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
+
+#[derive(Debug, Error)]
+pub enum LayoutError {
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+    #[error("Invalid value {1} for layout field {0}")]
+    InvalidValue(&'static str, u32),
+}
@@ -1 +1,3 @@
+pub mod contract_class_errors;
+pub mod layout_errors;
 pub mod program_errors;
@@ -1,9 +1,15 @@
+use crate::stdlib::prelude::*;
 use felt::PRIME_STR;
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 #[derive(Debug, Error)]
 pub enum ProgramError {
+    #[cfg(feature = "std")]
     #[error(transparent)]
     IO(#[from] io::Error),
     #[error(transparent)]
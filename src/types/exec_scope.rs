@@ -1,12 +1,17 @@
+use crate::stdlib::{
+    any::Any,
+    collections::HashMap,
+    prelude::*,
+    sync::{Arc, Mutex},
+};
 use crate::{
     any_box,
     hint_processor::builtin_hint_processor::dict_manager::DictManager,
     vm::errors::{exec_scope_errors::ExecScopeError, hint_errors::HintError},
 };
-use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 pub struct ExecutionScopes {
-    pub data: Vec<HashMap<String, Box<dyn Any>>>,
+    pub data: Vec<HashMap<String, Box<dyn Any + Send>>>,
 }
 
 impl ExecutionScopes {
@@ -16,7 +21,7 @@ impl ExecutionScopes {
         }
     }
 
-    pub fn enter_scope(&mut self, new_scope_locals: HashMap<String, Box<dyn Any>>) {
+    pub fn enter_scope(&mut self, new_scope_locals: HashMap<String, Box<dyn Any + Send>>) {
         self.data.push(new_scope_locals);
     }
 
@@ -32,14 +37,14 @@ impl ExecutionScopes {
     ///Returns a mutable reference to the dictionary containing the variables present in the current scope
     pub fn get_local_variables_mut(
         &mut self,
-    ) -> Result<&mut HashMap<String, Box<dyn Any>>, HintError> {
+    ) -> Result<&mut HashMap<String, Box<dyn Any + Send>>, HintError> {
         self.data
             .last_mut()
             .ok_or(HintError::FromScopeError(ExecScopeError::NoScopeError))
     }
 
     ///Returns a dictionary containing the variables present in the current scope
-    pub fn get_local_variables(&self) -> Result<&HashMap<String, Box<dyn Any>>, HintError> {
+    pub fn get_local_variables(&self) -> Result<&HashMap<String, Box<dyn Any + Send>>, HintError> {
         self.data
             .last()
             .ok_or(HintError::FromScopeError(ExecScopeError::NoScopeError))
@@ -53,7 +58,7 @@ impl ExecutionScopes {
     }
 
     ///Creates or updates an existing variable given its name and boxed value
-    pub fn assign_or_update_variable(&mut self, var_name: &str, var_value: Box<dyn Any>) {
+    pub fn assign_or_update_variable(&mut self, var_name: &str, var_value: Box<dyn Any + Send>) {
         if let Ok(local_variables) = self.get_local_variables_mut() {
             local_variables.insert(var_name.to_string(), var_value);
         }
@@ -93,7 +98,7 @@ impl ExecutionScopes {
     }
 
     ///Returns the value in the current execution scope that matches the name
-    pub fn get_any_boxed_ref(&self, name: &str) -> Result<&Box<dyn Any>, HintError> {
+    pub fn get_any_boxed_ref(&self, name: &str) -> Result<&Box<dyn Any + Send>, HintError> {
         if let Some(variable) = self.get_local_variables()?.get(name) {
             return Ok(variable);
         }
@@ -101,7 +106,7 @@ impl ExecutionScopes {
     }
 
     ///Returns the value in the current execution scope that matches the name
-    pub fn get_any_boxed_mut(&mut self, name: &str) -> Result<&mut Box<dyn Any>, HintError> {
+    pub fn get_any_boxed_mut(&mut self, name: &str) -> Result<&mut Box<dyn Any + Send>, HintError> {
         if let Some(variable) = self.get_local_variables_mut()?.get_mut(name) {
             return Ok(variable);
         }
@@ -142,10 +147,10 @@ impl ExecutionScopes {
     }
 
     ///Returns the value in the dict manager
-    pub fn get_dict_manager(&self) -> Result<Rc<RefCell<DictManager>>, HintError> {
-        let mut val: Option<Rc<RefCell<DictManager>>> = None;
+    pub fn get_dict_manager(&self) -> Result<Arc<Mutex<DictManager>>, HintError> {
+        let mut val: Option<Arc<Mutex<DictManager>>> = None;
         if let Some(variable) = self.get_local_variables()?.get("dict_manager") {
-            if let Some(dict_manager) = variable.downcast_ref::<Rc<RefCell<DictManager>>>() {
+            if let Some(dict_manager) = variable.downcast_ref::<Arc<Mutex<DictManager>>>() {
                 val = Some(dict_manager.clone());
             }
         }
@@ -167,12 +172,12 @@ impl ExecutionScopes {
     }
 
     ///Inserts the boxed value into the current scope
-    pub fn insert_box(&mut self, name: &str, value: Box<dyn Any>) {
+    pub fn insert_box(&mut self, name: &str, value: Box<dyn Any + Send>) {
         self.assign_or_update_variable(name, value);
     }
 
     ///Inserts the value into the current scope
-    pub fn insert_value<T: 'static>(&mut self, name: &str, value: T) {
+    pub fn insert_value<T: Any + Send>(&mut self, name: &str, value: T) {
         self.assign_or_update_variable(name, any_box!(value));
     }
 }
@@ -198,7 +203,7 @@ mod tests {
     #[test]
     fn get_local_variables_test() {
         let var_name = String::from("a");
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2));
 
         let scope = HashMap::from([(var_name, var_value)]);
 
@@ -218,14 +223,14 @@ mod tests {
     #[test]
     fn enter_new_scope_test() {
         let var_name = String::from("a");
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2_i32));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2_i32));
 
         let new_scope = HashMap::from([(var_name, var_value)]);
 
         let mut scopes = ExecutionScopes {
             data: vec![HashMap::from([(
                 String::from("b"),
-                (Box::new(Felt::one()) as Box<dyn Any>),
+                (Box::new(Felt::one()) as Box<dyn Any + Send>),
             )])],
         };
 
@@ -260,7 +265,7 @@ mod tests {
     #[test]
     fn exit_scope_test() {
         let var_name = String::from("a");
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2));
 
         let new_scope = HashMap::from([(var_name, var_value)]);
 
@@ -295,7 +300,7 @@ mod tests {
 
     #[test]
     fn assign_local_variable_test() {
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2));
 
         let mut scopes = ExecutionScopes::new();
 
@@ -316,13 +321,13 @@ mod tests {
     #[test]
     fn re_assign_local_variable_test() {
         let var_name = String::from("a");
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2));
 
         let scope = HashMap::from([(var_name, var_value)]);
 
         let mut scopes = ExecutionScopes { data: vec![scope] };
 
-        let var_value_new: Box<dyn Any> = Box::new(Felt::new(3));
+        let var_value_new: Box<dyn Any + Send> = Box::new(Felt::new(3));
 
         scopes.assign_or_update_variable("a", var_value_new);
 
@@ -341,7 +346,7 @@ mod tests {
     #[test]
     fn delete_local_variable_test() {
         let var_name = String::from("a");
-        let var_value: Box<dyn Any> = Box::new(Felt::new(2));
+        let var_value: Box<dyn Any + Send> = Box::new(Felt::new(2));
 
         let scope = HashMap::from([(var_name, var_value)]);
 
@@ -369,7 +374,7 @@ mod tests {
 
     #[test]
     fn get_listu64_test() {
-        let list_u64: Box<dyn Any> = Box::new(vec![20_u64, 18_u64]);
+        let list_u64: Box<dyn Any + Send> = Box::new(vec![20_u64, 18_u64]);
 
         let mut scopes = ExecutionScopes::default();
 
@@ -387,7 +392,7 @@ mod tests {
 
     #[test]
     fn get_u64_test() {
-        let u64: Box<dyn Any> = Box::new(9_u64);
+        let u64: Box<dyn Any + Send> = Box::new(9_u64);
 
         let mut scopes = ExecutionScopes::new();
 
@@ -412,7 +417,7 @@ mod tests {
 
     #[test]
     fn get_mut_int_ref_test() {
-        let bigint: Box<dyn Any> = Box::new(Felt::new(12));
+        let bigint: Box<dyn Any + Send> = Box::new(Felt::new(12));
 
         let mut scopes = ExecutionScopes::new();
         scopes.assign_or_update_variable("bigint", bigint);
@@ -422,7 +427,7 @@ mod tests {
 
     #[test]
     fn get_any_boxed_test() {
-        let list_u64: Box<dyn Any> = Box::new(vec![20_u64, 18_u64]);
+        let list_u64: Box<dyn Any + Send> = Box::new(vec![20_u64, 18_u64]);
 
         let mut scopes = ExecutionScopes::default();
 
@@ -1,10 +1,11 @@
 use super::{
     bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
     ecdsa_instance_def::EcdsaInstanceDef, keccak_instance_def::KeccakInstanceDef,
-    pedersen_instance_def::PedersenInstanceDef, range_check_instance_def::RangeCheckInstanceDef,
+    pedersen_instance_def::PedersenInstanceDef, poseidon_instance_def::PoseidonInstanceDef,
+    range_check_instance_def::RangeCheckInstanceDef,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct BuiltinsInstanceDef {
     pub(crate) _output: bool,
     pub(crate) pedersen: Option<PedersenInstanceDef>,
@@ -13,6 +14,7 @@ pub(crate) struct BuiltinsInstanceDef {
     pub(crate) bitwise: Option<BitwiseInstanceDef>,
     pub(crate) ec_op: Option<EcOpInstanceDef>,
     pub(crate) keccak: Option<KeccakInstanceDef>,
+    pub(crate) poseidon: Option<PoseidonInstanceDef>,
 }
 
 impl BuiltinsInstanceDef {
@@ -25,6 +27,7 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
@@ -37,6 +40,7 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
@@ -49,6 +53,7 @@ impl BuiltinsInstanceDef {
             bitwise: None,
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
@@ -61,6 +66,7 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::new(64)),
             ec_op: Some(EcOpInstanceDef::new(1024)),
             keccak: None,
+            poseidon: None,
         }
     }
 
@@ -73,6 +79,7 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::new(8)),
             ec_op: None,
             keccak: None,
+            poseidon: None,
         }
     }
 
@@ -85,6 +92,59 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::new(16)),
             ec_op: None,
             keccak: Some(KeccakInstanceDef::new(2048)),
+            poseidon: None,
+        }
+    }
+
+    pub(crate) fn starknet() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            _output: true,
+            pedersen: Some(PedersenInstanceDef::new(32, 1)),
+            range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+            _ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(64)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
+            keccak: None,
+            poseidon: Some(PoseidonInstanceDef::default()),
+        }
+    }
+
+    pub(crate) fn starknet_with_keccak() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            _output: true,
+            pedersen: Some(PedersenInstanceDef::new(32, 1)),
+            range_check: Some(RangeCheckInstanceDef::new(16, 8)),
+            _ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(64)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
+            keccak: Some(KeccakInstanceDef::new(2048)),
+            poseidon: Some(PoseidonInstanceDef::default()),
+        }
+    }
+
+    pub(crate) fn recursive_large_output() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            _output: true,
+            pedersen: Some(PedersenInstanceDef::new(128, 1)),
+            range_check: Some(RangeCheckInstanceDef::default()),
+            _ecdsa: None,
+            bitwise: Some(BitwiseInstanceDef::new(8)),
+            ec_op: None,
+            keccak: None,
+            poseidon: Some(PoseidonInstanceDef::new(8)),
+        }
+    }
+
+    pub(crate) fn all_cairo() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            _output: true,
+            pedersen: Some(PedersenInstanceDef::new(256, 1)),
+            range_check: Some(RangeCheckInstanceDef::default()),
+            _ecdsa: Some(EcdsaInstanceDef::new(2048)),
+            bitwise: Some(BitwiseInstanceDef::new(16)),
+            ec_op: Some(EcOpInstanceDef::new(1024)),
+            keccak: Some(KeccakInstanceDef::new(2048)),
+            poseidon: Some(PoseidonInstanceDef::new(256)),
         }
     }
 
@@ -97,6 +157,21 @@ impl BuiltinsInstanceDef {
             bitwise: Some(BitwiseInstanceDef::default()),
             ec_op: Some(EcOpInstanceDef::default()),
             keccak: None,
+            poseidon: None,
+        }
+    }
+
+    // The ratios are placeholders, the runner replaces them with the ones fitting the run.
+    pub(crate) fn dynamic() -> BuiltinsInstanceDef {
+        BuiltinsInstanceDef {
+            _output: true,
+            pedersen: Some(PedersenInstanceDef::default()),
+            range_check: Some(RangeCheckInstanceDef::default()),
+            _ecdsa: Some(EcdsaInstanceDef::default()),
+            bitwise: Some(BitwiseInstanceDef::default()),
+            ec_op: Some(EcOpInstanceDef::default()),
+            keccak: Some(KeccakInstanceDef::default()),
+            poseidon: Some(PoseidonInstanceDef::default()),
         }
     }
 }
@@ -181,4 +256,69 @@ mod tests {
         assert!(builtins.bitwise.is_some());
         assert!(builtins.ec_op.is_some());
     }
+
+    #[test]
+    fn get_builtins_starknet() {
+        let builtins = BuiltinsInstanceDef::starknet();
+        assert!(builtins._output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins._ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_none());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_starknet_with_keccak() {
+        let builtins = BuiltinsInstanceDef::starknet_with_keccak();
+        assert!(builtins._output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins._ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_some());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_recursive_large_output() {
+        let builtins = BuiltinsInstanceDef::recursive_large_output();
+        assert!(builtins._output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins._ecdsa.is_none());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_none());
+        assert!(builtins.keccak.is_none());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_all_cairo() {
+        let builtins = BuiltinsInstanceDef::all_cairo();
+        assert!(builtins._output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins._ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_some());
+        assert!(builtins.poseidon.is_some());
+    }
+
+    #[test]
+    fn get_builtins_dynamic() {
+        let builtins = BuiltinsInstanceDef::dynamic();
+        assert!(builtins._output);
+        assert!(builtins.pedersen.is_some());
+        assert!(builtins.range_check.is_some());
+        assert!(builtins._ecdsa.is_some());
+        assert!(builtins.bitwise.is_some());
+        assert!(builtins.ec_op.is_some());
+        assert!(builtins.keccak.is_some());
+        assert!(builtins.poseidon.is_some());
+    }
 }
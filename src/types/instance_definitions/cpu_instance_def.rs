@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct CpuInstanceDef {
     pub(crate) _safe_call: bool,
 }
@@ -1,7 +1,7 @@
 pub(crate) const _CELLS_PER_SIGNATURE: u32 = 2;
 pub(crate) const _INPUT_CELLS_PER_SIGNATURE: u32 = 2;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct EcdsaInstanceDef {
     pub(crate) ratio: u32,
     pub(crate) _repetitions: u32,
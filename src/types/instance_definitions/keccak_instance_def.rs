@@ -1,3 +1,5 @@
+use crate::stdlib::prelude::*;
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) struct KeccakInstanceDef {
     pub(crate) _ratio: u32,
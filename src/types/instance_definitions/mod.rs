@@ -6,4 +6,5 @@ pub mod ec_op_instance_def;
 pub mod ecdsa_instance_def;
 pub mod keccak_instance_def;
 pub mod pedersen_instance_def;
+pub mod poseidon_instance_def;
 pub mod range_check_instance_def;
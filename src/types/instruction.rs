@@ -1,4 +1,5 @@
-use felt::Felt;
+use crate::stdlib::fmt::{self, Display};
+use felt::{Felt, FeltOps};
 use num_traits::ToPrimitive;
 use serde::Deserialize;
 
@@ -82,6 +83,113 @@ impl Instruction {
     }
 }
 
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Register::AP => write!(f, "ap"),
+            Register::FP => write!(f, "fp"),
+        }
+    }
+}
+
+// Writes the memory cell at `register + offset`, as in `[fp + -3]`.
+fn write_cell(f: &mut fmt::Formatter, register: &Register, offset: isize) -> fmt::Result {
+    match offset {
+        0 => write!(f, "[{register}]"),
+        _ => write!(f, "[{register} + {offset}]"),
+    }
+}
+
+impl Instruction {
+    fn write_op0(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_cell(f, &self.op0_register, self.off1)
+    }
+
+    fn write_op1(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.op1_addr {
+            Op1Addr::Imm => match &self.imm {
+                Some(imm) => write!(f, "{}", imm.to_bigint()),
+                None => write!(f, "<missing immediate>"),
+            },
+            Op1Addr::AP => write_cell(f, &Register::AP, self.off2),
+            Op1Addr::FP => write_cell(f, &Register::FP, self.off2),
+            Op1Addr::Op0 => {
+                write!(f, "[")?;
+                self.write_op0(f)?;
+                match self.off2 {
+                    0 => write!(f, "]"),
+                    off2 => write!(f, " + {off2}]"),
+                }
+            }
+        }
+    }
+
+    fn write_res(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.res {
+            Res::Op1 | Res::Unconstrained => self.write_op1(f),
+            Res::Add => {
+                self.write_op0(f)?;
+                write!(f, " + ")?;
+                self.write_op1(f)
+            }
+            Res::Mul => {
+                self.write_op0(f)?;
+                write!(f, " * ")?;
+                self.write_op1(f)
+            }
+        }
+    }
+}
+
+/// Formats the instruction as Cairo assembly, such as `[ap] = [fp + -3] + 5; ap++` or
+/// `call rel 7`. Negative immediates are shown as signed values.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let jump_mode = match self.pc_update {
+            PcUpdate::JumpRel | PcUpdate::Jnz => "rel",
+            _ => "abs",
+        };
+        match (&self.opcode, &self.pc_update) {
+            (Opcode::Ret, _) => return write!(f, "ret"),
+            (Opcode::Call, _) => {
+                write!(f, "call {jump_mode} ")?;
+                return self.write_res(f);
+            }
+            (Opcode::AssertEq, _) => {
+                write_cell(f, &self.dst_register, self.off0)?;
+                write!(f, " = ")?;
+                self.write_res(f)?;
+            }
+            (Opcode::NOp, PcUpdate::Jnz) => {
+                write!(f, "jmp rel ")?;
+                self.write_op1(f)?;
+                write!(f, " if ")?;
+                write_cell(f, &self.dst_register, self.off0)?;
+                write!(f, " != 0")?;
+            }
+            (Opcode::NOp, PcUpdate::Jump | PcUpdate::JumpRel) => {
+                write!(f, "jmp {jump_mode} ")?;
+                self.write_res(f)?;
+            }
+            (Opcode::NOp, PcUpdate::Regular) => {
+                if self.ap_update == ApUpdate::Add {
+                    write!(f, "ap += ")?;
+                    return self.write_res(f);
+                }
+                write!(f, "nop")?;
+            }
+        }
+        match self.ap_update {
+            ApUpdate::Add1 => write!(f, "; ap++"),
+            ApUpdate::Add => {
+                write!(f, "; ap += ")?;
+                self.write_res(f)
+            }
+            ApUpdate::Regular | ApUpdate::Add2 => Ok(()),
+        }
+    }
+}
+
 // Returns True if the given instruction looks like a call instruction.
 pub(crate) fn is_call_instruction(encoded_instruction: &Felt, imm: Option<&Felt>) -> bool {
     let encoded_i64_instruction: i64 = match encoded_instruction.to_i64() {
@@ -117,4 +225,49 @@ mod tests {
         let encoded_instruction = Felt::new(4612671187288031229_i64);
         assert!(!is_call_instruction(&encoded_instruction, None));
     }
+
+    fn display(encoded_instruction: i64, imm: Option<Felt>) -> String {
+        decode_instruction(encoded_instruction, imm.as_ref())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn display_assert_eq() {
+        assert_eq!(
+            display(0x482680017ffd8000, Some(Felt::new(5))),
+            "[ap] = [fp + -3] + 5; ap++"
+        );
+        assert_eq!(
+            display(0x482680017ffd8000, Some(Felt::new(-1))),
+            "[ap] = [fp + -3] + -1; ap++"
+        );
+        assert_eq!(
+            display(0x40487ffd7fff8000, None),
+            "[ap] = [ap + -1] * [fp + -3]"
+        );
+        assert_eq!(
+            display(0x400380027ffc8001, None),
+            "[fp + 1] = [[fp + -4] + 2]"
+        );
+    }
+
+    #[test]
+    fn display_jumps() {
+        assert_eq!(
+            display(0x1104800180018000, Some(Felt::new(7))),
+            "call rel 7"
+        );
+        assert_eq!(display(0x208b7fff7fff7ffe, None), "ret");
+        assert_eq!(
+            display(0x20680017fff7fff, Some(Felt::new(3))),
+            "jmp rel 3 if [ap + -1] != 0"
+        );
+        assert_eq!(display(0x8b7ffd7fff7fff, None), "jmp abs [fp + -3]");
+    }
+
+    #[test]
+    fn display_ap_add() {
+        assert_eq!(display(0x40780017fff7fff, Some(Felt::new(5))), "ap += 5");
+    }
 }
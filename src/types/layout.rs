@@ -1,10 +1,24 @@
-use super::instance_definitions::{
-    builtins_instance_def::BuiltinsInstanceDef, cpu_instance_def::CpuInstanceDef,
-    diluted_pool_instance_def::DilutedPoolInstanceDef,
+use super::{
+    errors::layout_errors::LayoutError,
+    instance_definitions::{
+        builtins_instance_def::BuiltinsInstanceDef, cpu_instance_def::CpuInstanceDef,
+        diluted_pool_instance_def::DilutedPoolInstanceDef,
+    },
+};
+#[cfg(feature = "std")]
+use crate::serde::deserialize_layout::deserialize_layout;
+use crate::{serde::deserialize_layout::deserialize_layout_from_bytes, stdlib::prelude::*};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
 };
 
-#[derive(Debug)]
-pub(crate) struct CairoLayout {
+/// The builtins, along with their ratios, and the trace cells available to a run. Besides the
+/// predefined layouts, returned by `from_name`, custom layouts can be read from JSON files.
+#[derive(Clone, Debug)]
+pub struct CairoLayout {
     pub(crate) _name: String,
     pub(crate) _cpu_component_step: u32,
     pub(crate) rc_units: u32,
@@ -14,9 +28,53 @@ pub(crate) struct CairoLayout {
     pub(crate) diluted_pool_instance_def: Option<DilutedPoolInstanceDef>,
     pub(crate) _n_trace_colums: u32,
     pub(crate) _cpu_instance_def: CpuInstanceDef,
+    // Only set by `dynamic_instance`, so that a layout file can't opt into fitting by its name.
+    pub(crate) dynamic: bool,
 }
 
 impl CairoLayout {
+    /// Returns the predefined layout with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<CairoLayout> {
+        match name {
+            "plain" => Some(CairoLayout::plain_instance()),
+            "small" => Some(CairoLayout::small_instance()),
+            "dex" => Some(CairoLayout::dex_instance()),
+            "perpetual_with_bitwise" => Some(CairoLayout::perpetual_with_bitwise_instance()),
+            "bitwise" => Some(CairoLayout::bitwise_instance()),
+            "recursive" => Some(CairoLayout::recursive_instance()),
+            "starknet" => Some(CairoLayout::starknet_instance()),
+            "starknet_with_keccak" => Some(CairoLayout::starknet_with_keccak_instance()),
+            "recursive_large_output" => Some(CairoLayout::recursive_large_output_instance()),
+            "all_cairo" => Some(CairoLayout::all_cairo_instance()),
+            "all" => Some(CairoLayout::all_instance()),
+            "dynamic" => Some(CairoLayout::dynamic_instance()),
+            _ => None,
+        }
+    }
+
+    /// Whether the builtin ratios and the cells per step of the layout are fitted to the run
+    /// when it ends, instead of being fixed in advance.
+    pub fn is_dynamic(&self) -> bool {
+        self.dynamic
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_file(path: &Path) -> Result<CairoLayout, LayoutError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        deserialize_layout(reader)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_reader(reader: impl Read) -> Result<CairoLayout, LayoutError> {
+        deserialize_layout(reader)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<CairoLayout, LayoutError> {
+        deserialize_layout_from_bytes(bytes)
+    }
+
     pub(crate) fn plain_instance() -> CairoLayout {
         CairoLayout {
             _name: String::from("plain"),
@@ -28,6 +86,7 @@ impl CairoLayout {
             diluted_pool_instance_def: None,
             _n_trace_colums: 8,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -42,6 +101,7 @@ impl CairoLayout {
             diluted_pool_instance_def: None,
             _n_trace_colums: 25,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -56,6 +116,7 @@ impl CairoLayout {
             diluted_pool_instance_def: None,
             _n_trace_colums: 22,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -70,6 +131,7 @@ impl CairoLayout {
             diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(2, 4, 16)),
             _n_trace_colums: 10,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -84,6 +146,7 @@ impl CairoLayout {
             diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
             _n_trace_colums: 10,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -98,6 +161,67 @@ impl CairoLayout {
             diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
             _n_trace_colums: 11,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
+        }
+    }
+
+    pub(crate) fn starknet_instance() -> CairoLayout {
+        CairoLayout {
+            _name: String::from("starknet"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::starknet(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(2, 4, 16)),
+            _n_trace_colums: 10,
+            _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
+        }
+    }
+
+    pub(crate) fn starknet_with_keccak_instance() -> CairoLayout {
+        CairoLayout {
+            _name: String::from("starknet_with_keccak"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::starknet_with_keccak(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::new(4, 4, 16)),
+            _n_trace_colums: 15,
+            _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
+        }
+    }
+
+    pub(crate) fn recursive_large_output_instance() -> CairoLayout {
+        CairoLayout {
+            _name: String::from("recursive_large_output"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::recursive_large_output(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
+            _n_trace_colums: 13,
+            _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
+        }
+    }
+
+    pub(crate) fn all_cairo_instance() -> CairoLayout {
+        CairoLayout {
+            _name: String::from("all_cairo"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::all_cairo(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
+            _n_trace_colums: 11,
+            _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
         }
     }
 
@@ -112,6 +236,22 @@ impl CairoLayout {
             diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
             _n_trace_colums: 27,
             _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: false,
+        }
+    }
+
+    pub(crate) fn dynamic_instance() -> CairoLayout {
+        CairoLayout {
+            _name: String::from("dynamic"),
+            _cpu_component_step: 1,
+            rc_units: 4,
+            builtins: BuiltinsInstanceDef::dynamic(),
+            _public_memory_fraction: 8,
+            _memory_units_per_step: 8,
+            diluted_pool_instance_def: Some(DilutedPoolInstanceDef::default()),
+            _n_trace_colums: 0,
+            _cpu_instance_def: CpuInstanceDef::default(),
+            dynamic: true,
         }
     }
 }
@@ -120,6 +260,14 @@ impl CairoLayout {
 mod tests {
     use super::*;
 
+    #[test]
+    fn get_instance_from_name() {
+        let layout = CairoLayout::from_name("recursive").unwrap();
+        assert_eq!(&layout._name, "recursive");
+        assert_eq!(layout.builtins, BuiltinsInstanceDef::recursive());
+        assert!(CairoLayout::from_name("invalid layout name").is_none());
+    }
+
     #[test]
     fn get_plain_instance() {
         let layout = CairoLayout::plain_instance();
@@ -236,4 +384,92 @@ mod tests {
         assert_eq!(layout._n_trace_colums, 27);
         assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
     }
+
+    #[test]
+    fn get_starknet_instance() {
+        let layout = CairoLayout::starknet_instance();
+        let builtins = BuiltinsInstanceDef::starknet();
+        assert_eq!(&layout._name, "starknet");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(2, 4, 16))
+        );
+        assert_eq!(layout._n_trace_colums, 10);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_starknet_with_keccak_instance() {
+        let layout = CairoLayout::starknet_with_keccak_instance();
+        let builtins = BuiltinsInstanceDef::starknet_with_keccak();
+        assert_eq!(&layout._name, "starknet_with_keccak");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::new(4, 4, 16))
+        );
+        assert_eq!(layout._n_trace_colums, 15);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_recursive_large_output_instance() {
+        let layout = CairoLayout::recursive_large_output_instance();
+        let builtins = BuiltinsInstanceDef::recursive_large_output();
+        assert_eq!(&layout._name, "recursive_large_output");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::default())
+        );
+        assert_eq!(layout._n_trace_colums, 13);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_all_cairo_instance() {
+        let layout = CairoLayout::all_cairo_instance();
+        let builtins = BuiltinsInstanceDef::all_cairo();
+        assert_eq!(&layout._name, "all_cairo");
+        assert_eq!(layout._cpu_component_step, 1);
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::default())
+        );
+        assert_eq!(layout._n_trace_colums, 11);
+        assert_eq!(layout._cpu_instance_def, CpuInstanceDef::default());
+    }
+
+    #[test]
+    fn get_dynamic_instance() {
+        let layout = CairoLayout::from_name("dynamic").unwrap();
+        let builtins = BuiltinsInstanceDef::dynamic();
+        assert!(layout.is_dynamic());
+        assert_eq!(layout.rc_units, 4);
+        assert_eq!(layout.builtins, builtins);
+        assert_eq!(layout._public_memory_fraction, 8);
+        assert_eq!(layout._memory_units_per_step, 8);
+        assert_eq!(
+            layout.diluted_pool_instance_def,
+            Some(DilutedPoolInstanceDef::default())
+        );
+        assert!(!CairoLayout::all_instance().is_dynamic());
+    }
 }
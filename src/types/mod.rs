@@ -1,3 +1,5 @@
+pub mod casm_contract_class;
+pub mod contract_class;
 pub mod errors;
 pub mod exec_scope;
 pub mod instance_definitions;
@@ -1,15 +1,19 @@
+#[cfg(feature = "std")]
+use crate::serde::deserialize_program::deserialize_program;
 use crate::{
     serde::deserialize_program::{
-        deserialize_program, Attribute, HintParams, Identifier, InstructionLocation,
+        deserialize_program_from_bytes, Attribute, HintParams, Identifier, InstructionLocation,
         ReferenceManager,
     },
+    stdlib::{collections::HashMap, prelude::*},
     types::{errors::program_errors::ProgramError, relocatable::MaybeRelocatable},
 };
 use felt::{Felt, PRIME_STR};
+#[cfg(feature = "std")]
 use std::{
     fs::File,
     io::{BufReader, Read},
-    {collections::HashMap, path::Path},
+    path::Path,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -71,6 +75,7 @@ impl Program {
         })
     }
 
+    #[cfg(feature = "std")]
     pub fn from_file(path: &Path, entrypoint: Option<&str>) -> Result<Program, ProgramError> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
@@ -78,12 +83,17 @@ impl Program {
         deserialize_program(reader, entrypoint)
     }
 
+    #[cfg(feature = "std")]
     pub fn from_reader(
         reader: impl Read,
         entrypoint: Option<&str>,
     ) -> Result<Program, ProgramError> {
         deserialize_program(reader, entrypoint)
     }
+
+    pub fn from_bytes(bytes: &[u8], entrypoint: Option<&str>) -> Result<Program, ProgramError> {
+        deserialize_program_from_bytes(bytes, entrypoint)
+    }
 }
 
 impl Default for Program {
@@ -484,6 +494,22 @@ mod tests {
         assert_eq!(program.constants, constants);
     }
 
+    #[test]
+    fn deserialize_program_from_bytes_test() {
+        let program = Program::from_bytes(
+            include_bytes!("../../cairo_programs/manually_compiled/valid_program_a.json"),
+            Some("main"),
+        )
+        .expect("Failed to deserialize program");
+        let expected_program = Program::from_file(
+            Path::new("cairo_programs/manually_compiled/valid_program_a.json"),
+            Some("main"),
+        )
+        .expect("Failed to deserialize program");
+
+        assert_eq!(program, expected_program);
+    }
+
     #[test]
     fn default_program() {
         let program = Program {
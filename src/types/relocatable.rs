@@ -1,13 +1,16 @@
+use crate::stdlib::{
+    fmt::{self, Display},
+    ops::Add,
+    prelude::*,
+    str::FromStr,
+};
 use crate::{
     relocatable,
     vm::errors::{memory_errors::MemoryError, vm_errors::VirtualMachineError},
 };
 use felt::{Felt, NewFelt};
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
-use std::{
-    fmt::{self, Display},
-    ops::Add,
-};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Eq, Hash, PartialEq, PartialOrd, Clone, Copy, Debug)]
 pub struct Relocatable {
@@ -87,6 +90,101 @@ impl Display for Relocatable {
     }
 }
 
+/// Parses the `segment_index:offset` notation used by `Display`, e.g. `1:4` or `-1:0`.
+impl FromStr for Relocatable {
+    type Err = MemoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_error = || MemoryError::ParseRelocatable(s.to_string());
+        let (segment_index, offset) = s.split_once(':').ok_or_else(parse_error)?;
+        Ok(Relocatable {
+            segment_index: segment_index.parse().map_err(|_| parse_error())?,
+            offset: offset.parse().map_err(|_| parse_error())?,
+        })
+    }
+}
+
+/// Values containing a `:` are parsed as relocatables, anything else as a felt in either
+/// hexadecimal (`0x` prefixed) or decimal notation.
+impl FromStr for MaybeRelocatable {
+    type Err = MemoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            return s.parse().map(MaybeRelocatable::RelocatableValue);
+        }
+        s.parse()
+            .map(MaybeRelocatable::Int)
+            .map_err(|_| MemoryError::ParseMaybeRelocatable(s.to_string()))
+    }
+}
+
+// Human-readable formats (e.g. JSON) use the `segment_index:offset` notation, while binary
+// formats (e.g. bincode) store the segment index and offset as a tuple.
+impl Serialize for Relocatable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            (self.segment_index, self.offset).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Relocatable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(de::Error::custom)
+        } else {
+            <(isize, usize)>::deserialize(deserializer).map(Relocatable::from)
+        }
+    }
+}
+
+// Human-readable formats use a single string, either `segment_index:offset` or the felt's
+// hexadecimal representation, while binary formats keep the enum variant.
+impl Serialize for MaybeRelocatable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MaybeRelocatable::RelocatableValue(rel) if serializer.is_human_readable() => {
+                rel.serialize(serializer)
+            }
+            MaybeRelocatable::Int(num) if serializer.is_human_readable() => {
+                num.serialize(serializer)
+            }
+            MaybeRelocatable::RelocatableValue(rel) => {
+                serializer.serialize_newtype_variant("MaybeRelocatable", 0, "RelocatableValue", rel)
+            }
+            MaybeRelocatable::Int(num) => {
+                serializer.serialize_newtype_variant("MaybeRelocatable", 1, "Int", num)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MaybeRelocatable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "MaybeRelocatable")]
+        enum Variant {
+            RelocatableValue(Relocatable),
+            Int(Felt),
+        }
+
+        if deserializer.is_human_readable() {
+            return String::deserialize(deserializer)?
+                .parse()
+                .map_err(de::Error::custom);
+        }
+        Ok(match Variant::deserialize(deserializer)? {
+            Variant::RelocatableValue(rel) => MaybeRelocatable::RelocatableValue(rel),
+            Variant::Int(num) => MaybeRelocatable::Int(num),
+        })
+    }
+}
+
 impl Add<usize> for Relocatable {
     type Output = Relocatable;
     fn add(self, other: usize) -> Self {
@@ -784,4 +882,96 @@ mod tests {
             String::from("6")
         )
     }
+
+    #[test]
+    fn relocatable_from_str() {
+        assert_eq!("1:4".parse(), Ok(relocatable!(1, 4)));
+        assert_eq!("-1:0".parse(), Ok(relocatable!(-1, 0)));
+    }
+
+    #[test]
+    fn relocatable_from_str_invalid() {
+        for s in ["1", "1:", ":4", "1:-4", "a:4", "1:4:2"] {
+            assert_eq!(
+                s.parse::<Relocatable>(),
+                Err(MemoryError::ParseRelocatable(s.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn relocatable_display_from_str_round_trip() {
+        let rel = relocatable!(-2, 17);
+        assert_eq!(rel.to_string().parse(), Ok(rel));
+    }
+
+    #[test]
+    fn maybe_relocatable_from_str() {
+        assert_eq!("2:3".parse(), Ok(mayberelocatable!(2, 3)));
+        assert_eq!("0x10".parse(), Ok(mayberelocatable!(16)));
+        assert_eq!("16".parse(), Ok(mayberelocatable!(16)));
+        assert_eq!("-1".parse(), Ok(MaybeRelocatable::from(Felt::new(-1))));
+    }
+
+    #[test]
+    fn maybe_relocatable_from_str_invalid() {
+        assert_eq!(
+            "0xzz".parse::<MaybeRelocatable>(),
+            Err(MemoryError::ParseMaybeRelocatable("0xzz".to_string()))
+        );
+        assert_eq!(
+            "2:x".parse::<MaybeRelocatable>(),
+            Err(MemoryError::ParseRelocatable("2:x".to_string()))
+        );
+    }
+
+    #[test]
+    fn relocatable_serde_json() {
+        let rel = relocatable!(1, 5);
+        let serialized = serde_json::to_string(&rel).unwrap();
+        assert_eq!(serialized, "\"1:5\"");
+        assert_eq!(
+            serde_json::from_str::<Relocatable>(&serialized).unwrap(),
+            rel
+        );
+        assert!(serde_json::from_str::<Relocatable>("\"1;5\"").is_err());
+    }
+
+    #[test]
+    fn maybe_relocatable_serde_json() {
+        let values = vec![
+            mayberelocatable!(-1, 2),
+            mayberelocatable!(26),
+            MaybeRelocatable::from(Felt::new(-1)),
+        ];
+        let serialized = serde_json::to_string(&values).unwrap();
+        assert_eq!(
+            serialized,
+            "[\"-1:2\",\"0x1a\",\"0x800000000000011000000000000000000000000000000000000000000000000\"]"
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<MaybeRelocatable>>(&serialized).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn relocatable_and_maybe_relocatable_bincode() {
+        let rel = relocatable!(-3, 8);
+        let values = vec![
+            mayberelocatable!(3, 8),
+            mayberelocatable!(0),
+            MaybeRelocatable::from(Felt::new(-1)),
+        ];
+        let serialized = bincode::serialize(&rel).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Relocatable>(&serialized).unwrap(),
+            rel
+        );
+        let serialized = bincode::serialize(&values).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Vec<MaybeRelocatable>>(&serialized).unwrap(),
+            values
+        );
+    }
 }
@@ -1,6 +1,6 @@
+use crate::stdlib::ops::Shr;
 use crate::types::relocatable::Relocatable;
 use felt::Felt;
-use std::ops::Shr;
 
 #[macro_export]
 macro_rules! relocatable {
@@ -15,10 +15,14 @@ macro_rules! relocatable {
 #[macro_export]
 macro_rules! any_box {
     ($val : expr) => {
-        Box::new($val) as Box<dyn Any>
+        Box::new($val) as Box<dyn Any + Send>
     };
 }
 
+/// Fails to compile unless `T` is `Send`. Used in `const` items to check that the runner and the
+/// VM can be moved across threads.
+pub(crate) const fn assert_send<T: Send>() {}
+
 pub fn is_subsequence<T: PartialEq>(subsequence: &[T], mut sequence: &[T]) -> bool {
     for search in subsequence {
         if let Some(index) = sequence.iter().position(|element| search == element) {
@@ -412,10 +416,7 @@ pub mod test_utils {
         ( $exec_scopes: expr, $tracker_num:expr, $( ($key:expr, $val:expr )),* ) => {
             $(
                 assert_eq!(
-                    $exec_scopes
-                        .get_dict_manager()
-                        .unwrap()
-                        .borrow_mut()
+                    sync::lock(&$exec_scopes.get_dict_manager().unwrap())
                         .trackers
                         .get_mut(&$tracker_num)
                         .unwrap()
@@ -430,10 +431,7 @@ pub mod test_utils {
     macro_rules! check_dict_ptr {
         ($exec_scopes: expr, $tracker_num: expr, ($i:expr, $off:expr)) => {
             assert_eq!(
-                $exec_scopes
-                    .get_dict_manager()
-                    .unwrap()
-                    .borrow()
+                sync::lock(&$exec_scopes.get_dict_manager().unwrap())
                     .trackers
                     .get(&$tracker_num)
                     .unwrap()
@@ -452,13 +450,13 @@ pub mod test_utils {
             )*
             let mut dict_manager = DictManager::new();
             dict_manager.trackers.insert(2, tracker);
-            $exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)))
+            $exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)))
         };
         ($exec_scopes:expr, $tracker_num:expr) => {
             let  tracker = DictTracker::new_empty(&relocatable!($tracker_num, 0));
             let mut dict_manager = DictManager::new();
             dict_manager.trackers.insert(2, tracker);
-            $exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)))
+            $exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)))
         };
 
     }
@@ -472,13 +470,13 @@ pub mod test_utils {
             )*
             let mut dict_manager = DictManager::new();
             dict_manager.trackers.insert(2, tracker);
-            $exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)))
+            $exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)))
         };
         ($exec_scopes:expr, $tracker_num:expr,$default:expr) => {
             let tracker = DictTracker::new_default_dict(&relocatable!($tracker_num, 0), &MaybeRelocatable::from($default), None);
             let mut dict_manager = DictManager::new();
             dict_manager.trackers.insert(2, tracker);
-            $exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)))
+            $exec_scopes.insert_value("dict_manager", Arc::new(Mutex::new(dict_manager)))
         };
     }
     pub(crate) use dict_manager_default;
@@ -512,6 +510,7 @@ pub mod test_utils {
 
 #[cfg(test)]
 mod test {
+    use crate::stdlib::sync::{self, Arc, Mutex};
     use crate::{
         hint_processor::{
             builtin_hint_processor::{
@@ -530,7 +529,7 @@ mod test {
     };
     use felt::{Felt, NewFelt};
     use num_traits::One;
-    use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+    use std::{any::Any, collections::HashMap};
 
     use super::*;
 
@@ -693,19 +692,13 @@ mod test {
     fn check_scope_test_pass() {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable("a", any_box!(String::from("Hello")));
-        exec_scopes.assign_or_update_variable(
-            "",
-            any_box!(Rc::new(RefCell::new(HashMap::<usize, Vec<usize>>::new()))),
-        );
+        exec_scopes.assign_or_update_variable("", any_box!(HashMap::<usize, Vec<usize>>::new()));
         exec_scopes.assign_or_update_variable("c", any_box!(vec![1, 2, 3, 4]));
         check_scope!(
             &exec_scopes,
             [
                 ("a", String::from("Hello")),
-                (
-                    "",
-                    Rc::new(RefCell::new(HashMap::<usize, Vec<usize>>::new()))
-                ),
+                ("", HashMap::<usize, Vec<usize>>::new()),
                 ("c", vec![1, 2, 3, 4])
             ]
         );
@@ -716,19 +709,13 @@ mod test {
     fn check_scope_test_fail() {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable("a", any_box!(String::from("Hello")));
-        exec_scopes.assign_or_update_variable(
-            "",
-            any_box!(Rc::new(RefCell::new(HashMap::<usize, Vec<usize>>::new()))),
-        );
+        exec_scopes.assign_or_update_variable("", any_box!(HashMap::<usize, Vec<usize>>::new()));
         exec_scopes.assign_or_update_variable("c", any_box!(vec![1, 2, 3, 4]));
         check_scope!(
             &exec_scopes,
             [
                 ("a", String::from("Hello")),
-                (
-                    "",
-                    Rc::new(RefCell::new(HashMap::<usize, Vec<usize>>::new()))
-                ),
+                ("", HashMap::<usize, Vec<usize>>::new()),
                 ("c", vec![1, 2, 3, 5])
             ]
         );
@@ -759,7 +746,7 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable(
             "dict_manager",
-            any_box!(Rc::new(RefCell::new(dict_manager))),
+            any_box!(Arc::new(Mutex::new(dict_manager))),
         );
         check_dictionary!(&exec_scopes, 2, (5, 10));
     }
@@ -777,7 +764,7 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable(
             "dict_manager",
-            any_box!(Rc::new(RefCell::new(dict_manager))),
+            any_box!(Arc::new(Mutex::new(dict_manager))),
         );
         check_dictionary!(&exec_scopes, 2, (5, 11));
     }
@@ -790,7 +777,7 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable(
             "dict_manager",
-            any_box!(Rc::new(RefCell::new(dict_manager))),
+            any_box!(Arc::new(Mutex::new(dict_manager))),
         );
         check_dict_ptr!(&exec_scopes, 2, (2, 0));
     }
@@ -804,7 +791,7 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         exec_scopes.assign_or_update_variable(
             "dict_manager",
-            any_box!(Rc::new(RefCell::new(dict_manager))),
+            any_box!(Arc::new(Mutex::new(dict_manager))),
         );
         check_dict_ptr!(&exec_scopes, 2, (3, 0));
     }
@@ -817,8 +804,8 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         dict_manager!(exec_scopes, 2);
         assert_eq!(
-            exec_scopes.get_dict_manager(),
-            Ok(Rc::new(RefCell::new(dict_manager)))
+            *sync::lock(&exec_scopes.get_dict_manager().unwrap()),
+            dict_manager
         );
     }
 
@@ -834,8 +821,8 @@ mod test {
         let mut exec_scopes = ExecutionScopes::new();
         dict_manager_default!(exec_scopes, 2, 17);
         assert_eq!(
-            exec_scopes.get_dict_manager(),
-            Ok(Rc::new(RefCell::new(dict_manager)))
+            *sync::lock(&exec_scopes.get_dict_manager().unwrap()),
+            dict_manager
         );
     }
 
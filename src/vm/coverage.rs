@@ -0,0 +1,168 @@
+use crate::stdlib::{
+    collections::{BTreeMap, HashMap},
+    prelude::*,
+};
+use crate::vm::{
+    errors::{
+        runner_errors::RunnerError, trace_errors::TraceError, vm_errors::VirtualMachineError,
+    },
+    runners::cairo_runner::CairoRunner,
+    vm_core::VirtualMachine,
+};
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+/// Line coverage of the source files of a program, by file name and line number. The count
+/// of a line is the number of times its most executed instruction ran, and lines holding
+/// instructions that never ran have a count of 0.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub files: BTreeMap<String, BTreeMap<u32, usize>>,
+}
+
+impl Coverage {
+    /// Builds the coverage of a finished run from its trace, which must have been enabled.
+    /// Programs compiled without debug info have no instruction locations and get an empty
+    /// coverage.
+    pub fn new(runner: &CairoRunner, vm: &VirtualMachine) -> Result<Coverage, VirtualMachineError> {
+        let trace = vm.trace.as_ref().ok_or(TraceError::TraceNotEnabled)?;
+        let program_segment = runner
+            .program_base
+            .ok_or(RunnerError::NoProgBase)?
+            .segment_index;
+
+        let mut pc_counts = HashMap::<usize, usize>::new();
+        for entry in trace {
+            if entry.pc.segment_index == program_segment {
+                *pc_counts.entry(entry.pc.offset).or_default() += 1;
+            }
+        }
+
+        let mut coverage = Coverage::default();
+        for (pc, location) in runner.program.instruction_locations.iter().flatten() {
+            let count = pc_counts.get(pc).copied().unwrap_or(0);
+            let lines = coverage
+                .files
+                .entry(location.inst.input_file.filename.clone())
+                .or_default();
+            for line in location.inst.start_line..=location.inst.end_line {
+                let line_count = lines.entry(line).or_default();
+                *line_count = (*line_count).max(count);
+            }
+        }
+        Ok(coverage)
+    }
+
+    /// Writes the coverage as an lcov tracefile, with one record per source file.
+    #[cfg(feature = "std")]
+    pub fn write_lcov<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "TN:")?;
+        for (filename, lines) in &self.files {
+            writeln!(writer, "SF:{filename}")?;
+            for (line, count) in lines {
+                writeln!(writer, "DA:{line},{count}")?;
+            }
+            writeln!(writer, "LF:{}", lines.len())?;
+            let lines_hit = lines.values().filter(|count| **count > 0).count();
+            writeln!(writer, "LH:{lines_hit}")?;
+            writeln!(writer, "end_of_record")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        serde::deserialize_program::{InputFile, InstructionLocation, Location},
+        types::{program::Program, relocatable::Relocatable},
+        utils::test_utils::*,
+        vm::trace::trace_entry::TraceEntry,
+    };
+
+    fn location(filename: &str, start_line: u32, end_line: u32) -> InstructionLocation {
+        InstructionLocation {
+            inst: Location {
+                end_line,
+                end_col: 1,
+                input_file: InputFile {
+                    filename: filename.to_string(),
+                },
+                parent_location: None,
+                start_line,
+                start_col: 1,
+            },
+            hints: vec![],
+        }
+    }
+
+    fn trace_entry(pc: usize) -> TraceEntry {
+        TraceEntry {
+            pc: (0, pc).into(),
+            ap: (1, 0).into(),
+            fp: (1, 0).into(),
+        }
+    }
+
+    fn coverage_run() -> Coverage {
+        let program = program!(
+            instruction_locations = Some(HashMap::from([
+                (0, location("main.cairo", 3, 3)),
+                (1, location("main.cairo", 3, 3)),
+                (2, location("main.cairo", 4, 5)),
+                (4, location("lib.cairo", 1, 1)),
+            ])),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.program_base = Some(Relocatable::from((0, 0)));
+        let mut vm = vm!(true);
+        vm.trace = Some(vec![
+            trace_entry(0),
+            trace_entry(1),
+            trace_entry(0),
+            trace_entry(1),
+            trace_entry(2),
+        ]);
+        Coverage::new(&cairo_runner, &vm).unwrap()
+    }
+
+    #[test]
+    fn coverage_from_trace() {
+        assert_eq!(
+            coverage_run().files,
+            BTreeMap::from([
+                (String::from("lib.cairo"), BTreeMap::from([(1, 0)])),
+                (
+                    String::from("main.cairo"),
+                    BTreeMap::from([(3, 2), (4, 1), (5, 1)])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn write_lcov() {
+        let mut output = Vec::new();
+        coverage_run().write_lcov(&mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "TN:\n\
+             SF:lib.cairo\nDA:1,0\nLF:1\nLH:0\nend_of_record\n\
+             SF:main.cairo\nDA:3,2\nDA:4,1\nDA:5,1\nLF:3\nLH:3\nend_of_record\n"
+        );
+    }
+
+    #[test]
+    fn coverage_trace_not_enabled() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+        assert_eq!(
+            Coverage::new(&cairo_runner, &vm),
+            Err(VirtualMachineError::TracerError(
+                TraceError::TraceNotEnabled
+            ))
+        );
+    }
+}
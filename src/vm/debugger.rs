@@ -0,0 +1,545 @@
+use crate::{
+    hint_processor::hint_processor_definition::HintProcessor,
+    types::{instruction::Opcode, relocatable::Relocatable},
+    vm::{
+        errors::{
+            cairo_run_errors::CairoRunError, runner_errors::RunnerError,
+            vm_errors::VirtualMachineError, vm_exception::VmException,
+        },
+        runners::cairo_runner::CairoRunner,
+        vm_core::VirtualMachine,
+    },
+};
+use std::{
+    any::Any,
+    collections::{BTreeSet, HashMap},
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+const DEFAULT_MEMORY_WINDOW: usize = 3;
+
+const HELP: &str = "Commands:
+  s, step [n]        execute n instructions (default 1)
+  n, next            execute one instruction, stepping over calls
+  c, continue        run until a breakpoint or the end of the program
+  b, break <loc>     set a breakpoint at a pc (decimal or 0x-prefixed) or function label
+  d, delete <loc>    remove a breakpoint
+  i, info            list breakpoints
+  r, regs            print the registers
+  x, inst            print the current instruction
+  m, mem [n]         print n memory cells around fp and ap (default 3)
+  h, help            print this message
+  q, quit            abort the run";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DebugCommand {
+    Step(usize),
+    Next,
+    Continue,
+    Break(String),
+    Delete(String),
+    Breakpoints,
+    Registers,
+    Instruction,
+    Memory(usize),
+    Help,
+    Quit,
+}
+
+impl FromStr for DebugCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let command = words.next().ok_or_else(|| "Empty command".to_string())?;
+        let argument = words.next();
+        if words.next().is_some() {
+            return Err(format!("Too many arguments for {command}"));
+        }
+
+        let count = |default: usize| match argument {
+            Some(n) => n.parse().map_err(|_| format!("Invalid count: {n}")),
+            None => Ok(default),
+        };
+        let location = || {
+            argument
+                .map(String::from)
+                .ok_or_else(|| format!("{command} expects a pc or a function label"))
+        };
+
+        match command {
+            "s" | "step" => Ok(DebugCommand::Step(count(1)?)),
+            "n" | "next" => Ok(DebugCommand::Next),
+            "c" | "continue" => Ok(DebugCommand::Continue),
+            "b" | "break" => Ok(DebugCommand::Break(location()?)),
+            "d" | "delete" => Ok(DebugCommand::Delete(location()?)),
+            "i" | "info" => Ok(DebugCommand::Breakpoints),
+            "r" | "regs" => Ok(DebugCommand::Registers),
+            "x" | "inst" => Ok(DebugCommand::Instruction),
+            "m" | "mem" => Ok(DebugCommand::Memory(count(DEFAULT_MEMORY_WINDOW)?)),
+            "h" | "help" => Ok(DebugCommand::Help),
+            "q" | "quit" => Ok(DebugCommand::Quit),
+            _ => Err(format!("Unknown command: {command}")),
+        }
+    }
+}
+
+/// Runs a program one instruction at a time, stopping at user defined breakpoints.
+/// The runner and the vm are expected to be initialized, with `end` being the final pc
+/// returned by `CairoRunner::initialize`.
+pub struct Debugger<'a> {
+    runner: &'a mut CairoRunner,
+    vm: &'a mut VirtualMachine,
+    hint_processor: &'a mut dyn HintProcessor,
+    hint_data_dictionary: HashMap<usize, Vec<Box<dyn Any + Send>>>,
+    end: Relocatable,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(
+        runner: &'a mut CairoRunner,
+        vm: &'a mut VirtualMachine,
+        hint_processor: &'a mut dyn HintProcessor,
+        end: Relocatable,
+    ) -> Result<Self, VirtualMachineError> {
+        let references = runner.get_reference_list();
+        let hint_data_dictionary = runner.get_hint_data_dictionary(&references, hint_processor)?;
+        Ok(Debugger {
+            runner,
+            vm,
+            hint_processor,
+            hint_data_dictionary,
+            end,
+            breakpoints: BTreeSet::new(),
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.vm.run_context.pc == self.end
+    }
+
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    /// Resolves a location given either as a pc or as a function label. Labels can be
+    /// written with their full name (`__main__.main`), relative to `__main__`, or by any
+    /// unambiguous suffix (`fib` for `__main__.fib`).
+    pub fn resolve_location(&self, location: &str) -> Option<usize> {
+        let pc = match location.strip_prefix("0x") {
+            Some(hex) => usize::from_str_radix(hex, 16).ok(),
+            None => location.parse().ok(),
+        };
+        if pc.is_some() {
+            return pc;
+        }
+
+        let identifiers = &self.runner.program.identifiers;
+        let exact_match = [location.to_string(), format!("__main__.{location}")]
+            .iter()
+            .find_map(|name| identifiers.get(name).and_then(|identifier| identifier.pc));
+        if exact_match.is_some() {
+            return exact_match;
+        }
+
+        let suffix = format!(".{location}");
+        let mut candidates = identifiers
+            .iter()
+            .filter(|(name, _)| name.ends_with(&suffix))
+            .filter_map(|(_, identifier)| identifier.pc);
+        match (candidates.next(), candidates.next()) {
+            (Some(pc), None) => Some(pc),
+            _ => None,
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, location: &str) -> Result<usize, String> {
+        let pc = self
+            .resolve_location(location)
+            .ok_or_else(|| format!("Unknown location: {location}"))?;
+        self.breakpoints.insert(pc);
+        Ok(pc)
+    }
+
+    pub fn remove_breakpoint(&mut self, location: &str) -> Result<usize, String> {
+        let pc = self
+            .resolve_location(location)
+            .ok_or_else(|| format!("Unknown location: {location}"))?;
+        if !self.breakpoints.remove(&pc) {
+            return Err(format!("No breakpoint at pc {pc}"));
+        }
+        Ok(pc)
+    }
+
+    /// Returns the name of the function whose body contains the given pc.
+    pub fn function_at(&self, pc: usize) -> Option<&str> {
+        self.runner
+            .program
+            .identifiers
+            .iter()
+            .filter(|(_, identifier)| identifier.type_.as_deref() == Some("function"))
+            .filter_map(|(name, identifier)| Some((identifier.pc?, name)))
+            .filter(|(function_pc, _)| *function_pc <= pc)
+            .max_by_key(|(function_pc, _)| *function_pc)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Executes a single instruction, along with the hints attached to it.
+    pub fn step(&mut self) -> Result<(), CairoRunError> {
+        if self.is_finished() {
+            return Err(VirtualMachineError::EndOfProgram(1).into());
+        }
+        self.vm
+            .step(
+                self.hint_processor,
+                &mut self.runner.exec_scopes,
+                &self.hint_data_dictionary,
+                &self.runner.program.constants,
+            )
+            .map_err(|err| VmException::from_vm_error(self.runner, self.vm, err).into())
+    }
+
+    /// Executes up to `steps` instructions, stopping early at breakpoints or at the end of
+    /// the program. Returns the number of executed instructions.
+    pub fn step_n(&mut self, steps: usize) -> Result<usize, CairoRunError> {
+        for executed in 0..steps {
+            if self.is_finished() || (executed > 0 && self.at_breakpoint()) {
+                return Ok(executed);
+            }
+            self.step()?;
+        }
+        Ok(steps)
+    }
+
+    /// Executes the current instruction. If it is a call, runs until the callee returns,
+    /// unless a breakpoint is hit first.
+    pub fn step_over(&mut self) -> Result<(), CairoRunError> {
+        let instruction = self.vm.decode_current_instruction()?;
+        let return_pc = self.vm.run_context.pc + instruction.size();
+        let fp = self.vm.get_fp();
+        self.step()?;
+        if instruction.opcode == Opcode::Call {
+            while !self.is_finished()
+                && !self.at_breakpoint()
+                && !(self.vm.run_context.pc == return_pc && self.vm.get_fp() == fp)
+            {
+                self.step()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs until a breakpoint or the end of the program is reached.
+    pub fn resume(&mut self) -> Result<(), CairoRunError> {
+        if self.is_finished() {
+            return Ok(());
+        }
+        self.step()?;
+        while !self.is_finished() && !self.at_breakpoint() {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    fn at_breakpoint(&self) -> bool {
+        self.breakpoints.contains(&self.vm.run_context.pc.offset)
+    }
+
+    pub fn write_registers(&self, out: &mut dyn Write) -> Result<(), CairoRunError> {
+        let pc = self.vm.get_pc();
+        let location = match self.function_at(pc.offset) {
+            Some(name) => format!(" ({name})"),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "pc = {pc}{location}  ap = {}  fp = {}  step = {}",
+            self.vm.get_ap(),
+            self.vm.get_fp(),
+            self.vm.current_step
+        )
+        .map_err(|_| RunnerError::WriteFail.into())
+    }
+
+    pub fn write_instruction(&self, out: &mut dyn Write) -> Result<(), CairoRunError> {
+        if self.is_finished() {
+            return writeln!(out, "The program has finished")
+                .map_err(|_| RunnerError::WriteFail.into());
+        }
+        let instruction = self.vm.decode_current_instruction()?;
+        writeln!(out, "{instruction}").map_err(|_| RunnerError::WriteFail.into())
+    }
+
+    /// Prints the memory cells in `[reg - window, reg + window]` for both fp and ap.
+    pub fn write_memory(&self, window: usize, out: &mut dyn Write) -> Result<(), CairoRunError> {
+        for (register, base) in [("fp", self.vm.get_fp()), ("ap", self.vm.get_ap())] {
+            writeln!(out, "{register} = {base}").map_err(|_| RunnerError::WriteFail)?;
+            let first = base.offset.saturating_sub(window);
+            for offset in first..=base.offset + window {
+                let address = Relocatable::from((base.segment_index, offset));
+                let value = match self.vm.get_maybe(&address)? {
+                    Some(value) => value.to_string(),
+                    None => "<unset>".to_string(),
+                };
+                let relative = offset as isize - base.offset as isize;
+                writeln!(out, "  [{register}{relative:+}] {address} = {value}")
+                    .map_err(|_| RunnerError::WriteFail)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_stop(&self, out: &mut dyn Write) -> Result<(), CairoRunError> {
+        if self.is_finished() {
+            return writeln!(out, "The program has finished")
+                .map_err(|_| RunnerError::WriteFail.into());
+        }
+        if self.at_breakpoint() {
+            writeln!(out, "Breakpoint at pc {}", self.vm.run_context.pc.offset)
+                .map_err(|_| RunnerError::WriteFail)?;
+        }
+        self.write_registers(out)?;
+        self.write_instruction(out)
+    }
+
+    /// Executes a single command. Returns false once the session should be closed, either
+    /// because the user quit or because the program reached its end.
+    pub fn execute(
+        &mut self,
+        command: DebugCommand,
+        out: &mut dyn Write,
+    ) -> Result<bool, CairoRunError> {
+        match command {
+            DebugCommand::Step(steps) => {
+                self.step_n(steps)?;
+                self.write_stop(out)?;
+            }
+            DebugCommand::Next => {
+                if !self.is_finished() {
+                    self.step_over()?;
+                }
+                self.write_stop(out)?;
+            }
+            DebugCommand::Continue => {
+                self.resume()?;
+                self.write_stop(out)?;
+            }
+            DebugCommand::Break(location) => {
+                let message = match self.add_breakpoint(&location) {
+                    Ok(pc) => format!("Breakpoint set at pc {pc}"),
+                    Err(message) => message,
+                };
+                writeln!(out, "{message}").map_err(|_| RunnerError::WriteFail)?;
+            }
+            DebugCommand::Delete(location) => {
+                let message = match self.remove_breakpoint(&location) {
+                    Ok(pc) => format!("Breakpoint at pc {pc} removed"),
+                    Err(message) => message,
+                };
+                writeln!(out, "{message}").map_err(|_| RunnerError::WriteFail)?;
+            }
+            DebugCommand::Breakpoints => {
+                for pc in &self.breakpoints {
+                    let name = self.function_at(*pc).unwrap_or("?");
+                    writeln!(out, "pc {pc} ({name})").map_err(|_| RunnerError::WriteFail)?;
+                }
+            }
+            DebugCommand::Registers => self.write_registers(out)?,
+            DebugCommand::Instruction => self.write_instruction(out)?,
+            DebugCommand::Memory(window) => self.write_memory(window, out)?,
+            DebugCommand::Help => writeln!(out, "{HELP}").map_err(|_| RunnerError::WriteFail)?,
+            DebugCommand::Quit => return Ok(false),
+        }
+        Ok(!self.is_finished())
+    }
+
+    /// Reads commands from `input` until the program ends, the user quits or the input is
+    /// exhausted. An empty line repeats the previous command. Returns true if the program
+    /// ran to completion.
+    pub fn run(
+        &mut self,
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<bool, CairoRunError> {
+        writeln!(out, "Type `help` for a list of commands").map_err(|_| RunnerError::WriteFail)?;
+        self.write_stop(out)?;
+
+        let mut last_command = String::new();
+        let mut line = String::new();
+        loop {
+            write!(out, "(cairo-dbg) ").map_err(|_| RunnerError::WriteFail)?;
+            out.flush().map_err(|_| RunnerError::WriteFail)?;
+
+            line.clear();
+            if input
+                .read_line(&mut line)
+                .map_err(|_| RunnerError::WriteFail)?
+                == 0
+            {
+                return Ok(self.is_finished());
+            }
+            if !line.trim().is_empty() {
+                last_command = line.trim().to_string();
+            } else if last_command.is_empty() {
+                continue;
+            }
+
+            match last_command.parse::<DebugCommand>() {
+                Ok(command) => {
+                    if !self.execute(command, out)? {
+                        return Ok(self.is_finished());
+                    }
+                }
+                Err(message) => {
+                    writeln!(out, "{message}").map_err(|_| RunnerError::WriteFail)?;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+        types::program::Program,
+    };
+    use std::{io::Cursor, path::Path};
+
+    fn load_fibonacci() -> (CairoRunner, VirtualMachine, Relocatable) {
+        let program =
+            Program::from_file(Path::new("cairo_programs/fibonacci.json"), Some("main")).unwrap();
+        let mut runner = CairoRunner::new(&program, "plain", false).unwrap();
+        let mut vm = VirtualMachine::new(false);
+        let end = runner.initialize(&mut vm).unwrap();
+        (runner, vm, end)
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!("s".parse(), Ok(DebugCommand::Step(1)));
+        assert_eq!("step 10".parse(), Ok(DebugCommand::Step(10)));
+        assert_eq!("next".parse(), Ok(DebugCommand::Next));
+        assert_eq!("c".parse(), Ok(DebugCommand::Continue));
+        assert_eq!("b fib".parse(), Ok(DebugCommand::Break("fib".to_string())));
+        assert_eq!(
+            "delete 0x3".parse(),
+            Ok(DebugCommand::Delete("0x3".to_string()))
+        );
+        assert_eq!("m".parse(), Ok(DebugCommand::Memory(DEFAULT_MEMORY_WINDOW)));
+        assert_eq!("mem 5".parse(), Ok(DebugCommand::Memory(5)));
+        assert_eq!("q".parse(), Ok(DebugCommand::Quit));
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert!("".parse::<DebugCommand>().is_err());
+        assert!("jump".parse::<DebugCommand>().is_err());
+        assert!("step two".parse::<DebugCommand>().is_err());
+        assert!("break".parse::<DebugCommand>().is_err());
+        assert!("break a b".parse::<DebugCommand>().is_err());
+    }
+
+    #[test]
+    fn resolve_locations() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let fib_pc = runner.program.identifiers["__main__.fib"].pc;
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        assert_eq!(debugger.resolve_location("12"), Some(12));
+        assert_eq!(debugger.resolve_location("0x10"), Some(16));
+        assert_eq!(debugger.resolve_location("__main__.fib"), fib_pc);
+        assert_eq!(debugger.resolve_location("fib"), fib_pc);
+        assert_eq!(debugger.resolve_location("main"), Some(0));
+        assert_eq!(debugger.resolve_location("not_a_function"), None);
+        assert_eq!(debugger.function_at(fib_pc.unwrap()), Some("__main__.fib"));
+    }
+
+    #[test]
+    fn continue_stops_at_breakpoint() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let fib_pc = runner.program.identifiers["__main__.fib"].pc.unwrap();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        assert_eq!(debugger.add_breakpoint("fib"), Ok(fib_pc));
+        debugger.resume().unwrap();
+        assert_eq!(debugger.vm.run_context.pc, Relocatable::from((0, fib_pc)));
+
+        // fib is recursive, so continuing stops at the breakpoint again
+        let fp = debugger.vm.get_fp();
+        debugger.resume().unwrap();
+        assert_eq!(debugger.vm.run_context.pc, Relocatable::from((0, fib_pc)));
+        assert_ne!(debugger.vm.get_fp(), fp);
+
+        assert_eq!(debugger.remove_breakpoint("fib"), Ok(fib_pc));
+        debugger.resume().unwrap();
+        assert!(debugger.is_finished());
+    }
+
+    #[test]
+    fn next_steps_over_calls() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        // Step until main calls fib
+        while debugger.vm.decode_current_instruction().unwrap().opcode != Opcode::Call {
+            debugger.step().unwrap();
+        }
+        let call_pc = debugger.vm.run_context.pc;
+        let call_size = debugger.vm.decode_current_instruction().unwrap().size();
+        let fp = debugger.vm.get_fp();
+
+        debugger.step_over().unwrap();
+        assert_eq!(debugger.vm.run_context.pc, call_pc + call_size);
+        assert_eq!(debugger.vm.get_fp(), fp);
+    }
+
+    #[test]
+    fn step_n_stops_at_end_of_program() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        let executed = debugger.step_n(usize::MAX).unwrap();
+        assert!(debugger.is_finished());
+        assert_eq!(executed, debugger.vm.current_step);
+        assert!(debugger.step().is_err());
+    }
+
+    #[test]
+    fn run_session() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        let mut input = Cursor::new("break fib\ncontinue\nregs\nmem 1\n\nfoo\nc\nq\n");
+        let mut output = Vec::new();
+        assert!(!debugger.run(&mut input, &mut output).unwrap());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Breakpoint set at pc"));
+        assert!(output.contains("(__main__.fib)"));
+        assert!(output.contains("[fp-1]"));
+        assert!(output.contains("[ap+1]"));
+        assert!(output.contains("Unknown command: foo"));
+    }
+
+    #[test]
+    fn run_session_until_end() {
+        let (mut runner, mut vm, end) = load_fibonacci();
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut debugger = Debugger::new(&mut runner, &mut vm, &mut hint_processor, end).unwrap();
+
+        let mut input = Cursor::new("continue\n");
+        let mut output = Vec::new();
+        assert!(debugger.run(&mut input, &mut output).unwrap());
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("The program has finished"));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::stdlib::prelude::*;
+use crate::{
+    types::{instruction::Instruction, program::Program, relocatable::MaybeRelocatable},
+    vm::decoding::decoder::decode_instruction,
+};
+use num_traits::ToPrimitive;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+/// A word of program data, which either starts an instruction or doesn't decode as one, like
+/// the values written with `dw`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Bytecode {
+    Instruction(Instruction),
+    Data(MaybeRelocatable),
+}
+
+/// Decodes the program data from its first word, returning each instruction along with its pc.
+/// Immediates belong to the instruction before them and don't get an entry of their own.
+pub fn disassemble(program: &Program) -> Vec<(usize, Bytecode)> {
+    let mut bytecode = Vec::new();
+    let mut pc = 0;
+    while let Some(word) = program.data.get(pc) {
+        let imm = match program.data.get(pc + 1) {
+            Some(MaybeRelocatable::Int(imm)) => Some(imm),
+            _ => None,
+        };
+        let instruction = match word {
+            MaybeRelocatable::Int(encoded_instruction) => encoded_instruction
+                .to_i64()
+                .and_then(|encoded_instruction| decode_instruction(encoded_instruction, imm).ok()),
+            MaybeRelocatable::RelocatableValue(_) => None,
+        };
+        let (size, word) = match instruction {
+            Some(instruction) => (instruction.size(), Bytecode::Instruction(instruction)),
+            None => (1, Bytecode::Data(word.clone())),
+        };
+        bytecode.push((pc, word));
+        pc += size;
+    }
+    bytecode
+}
+
+/// Writes the program as Cairo assembly, with one line per instruction starting with its pc.
+/// The labels and the code of the hints at a pc come before its instruction, and the source
+/// location of the instruction follows it when the program was compiled with debug info.
+#[cfg(feature = "std")]
+pub fn write_disassembly<W: Write>(program: &Program, writer: &mut W) -> io::Result<()> {
+    let mut labels: HashMap<usize, Vec<&str>> = HashMap::new();
+    for (name, identifier) in &program.identifiers {
+        let is_label = matches!(identifier.type_.as_deref(), Some("function" | "label"));
+        if let (true, Some(pc)) = (is_label, identifier.pc) {
+            labels.entry(pc).or_default().push(name);
+        }
+    }
+    for names in labels.values_mut() {
+        names.sort_unstable();
+    }
+
+    for (pc, bytecode) in disassemble(program) {
+        if let Some(names) = labels.get(&pc) {
+            for name in names {
+                writeln!(writer, "{name}:")?;
+            }
+        }
+        for hint in program.hints.get(&pc).into_iter().flatten() {
+            match hint.code.lines().collect::<Vec<_>>().as_slice() {
+                [line] => writeln!(writer, "        %{{ {line} %}}")?,
+                lines => {
+                    writeln!(writer, "        %{{")?;
+                    for line in lines {
+                        writeln!(writer, "            {line}")?;
+                    }
+                    writeln!(writer, "        %}}")?;
+                }
+            }
+        }
+
+        let text = match bytecode {
+            Bytecode::Instruction(instruction) => instruction.to_string(),
+            Bytecode::Data(value) => format!("dw {value}"),
+        };
+        let location = program
+            .instruction_locations
+            .as_ref()
+            .and_then(|locations| locations.get(&pc));
+        match location {
+            Some(location) => writeln!(
+                writer,
+                "{pc:>6}  {text:<40}// {}:{}:{}",
+                location.inst.input_file.filename,
+                location.inst.start_line,
+                location.inst.start_col
+            )?,
+            None => writeln!(writer, "{pc:>6}  {text}")?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        serde::deserialize_program::{
+            ApTracking, FlowTrackingData, HintParams, Identifier, InputFile, InstructionLocation,
+            Location,
+        },
+        utils::test_utils::*,
+    };
+    use felt::{Felt, NewFelt};
+
+    fn label(pc: usize, type_: &str) -> Identifier {
+        Identifier {
+            pc: Some(pc),
+            type_: Some(type_.to_string()),
+            value: None,
+            full_name: None,
+            members: None,
+        }
+    }
+
+    fn hint(code: &str) -> HintParams {
+        HintParams {
+            code: code.to_string(),
+            accessible_scopes: vec![],
+            flow_tracking_data: FlowTrackingData {
+                ap_tracking: ApTracking::new(),
+                reference_ids: HashMap::new(),
+            },
+        }
+    }
+
+    // `[ap] = [fp + -3] + 5; ap++`, `call rel 7` and `ret`, followed by a data word.
+    fn program_data() -> Vec<MaybeRelocatable> {
+        vec![
+            mayberelocatable!(0x482680017ffd8000_i64),
+            mayberelocatable!(5),
+            mayberelocatable!(0x1104800180018000_i64),
+            mayberelocatable!(7),
+            mayberelocatable!(0x208b7fff7fff7ffe_i64),
+            mayberelocatable!(1, 0),
+        ]
+    }
+
+    #[test]
+    fn disassemble_program_data() {
+        let program = program!(data = program_data(),);
+        let pcs_and_text: Vec<_> = disassemble(&program)
+            .into_iter()
+            .map(|(pc, bytecode)| match bytecode {
+                Bytecode::Instruction(instruction) => (pc, instruction.to_string()),
+                Bytecode::Data(value) => (pc, format!("dw {value}")),
+            })
+            .collect();
+        assert_eq!(
+            pcs_and_text,
+            vec![
+                (0, String::from("[ap] = [fp + -3] + 5; ap++")),
+                (2, String::from("call rel 7")),
+                (4, String::from("ret")),
+                (5, String::from("dw 1:0")),
+            ]
+        );
+    }
+
+    #[test]
+    fn disassemble_instruction_without_immediate() {
+        let program = program!(data = vec![mayberelocatable!(0x482680017ffd8000_i64)],);
+        assert_eq!(
+            disassemble(&program),
+            vec![(
+                0,
+                Bytecode::Data(MaybeRelocatable::from(Felt::new(0x482680017ffd8000_i64)))
+            )]
+        );
+    }
+
+    #[test]
+    fn write_disassembly_with_labels_hints_and_locations() {
+        let program = program!(
+            data = program_data(),
+            identifiers = HashMap::from([
+                (String::from("__main__.main"), label(0, "function")),
+                (String::from("__main__.main.end"), label(4, "label")),
+                (String::from("__main__.N"), label(4, "const")),
+            ]),
+            hints = HashMap::from([
+                (0, vec![hint("memory[ap] = 1")]),
+                (2, vec![hint("x = 1\ny = 2")]),
+            ]),
+            instruction_locations = Some(HashMap::from([(
+                2,
+                InstructionLocation {
+                    inst: Location {
+                        end_line: 3,
+                        end_col: 14,
+                        input_file: InputFile {
+                            filename: String::from("main.cairo"),
+                        },
+                        parent_location: None,
+                        start_line: 3,
+                        start_col: 5,
+                    },
+                    hints: vec![],
+                },
+            )])),
+        );
+        let mut output = Vec::new();
+        write_disassembly(&program, &mut output).unwrap();
+        let call_line = format!("     2  {:<40}// main.cairo:3:5", "call rel 7");
+        let expected = [
+            "__main__.main:",
+            "        %{ memory[ap] = 1 %}",
+            "     0  [ap] = [fp + -3] + 5; ap++",
+            "        %{",
+            "            x = 1",
+            "            y = 2",
+            "        %}",
+            call_line.as_str(),
+            "__main__.main.end:",
+            "     4  ret",
+            "     5  dw 1:0",
+        ];
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            expected.join("\n") + "\n"
+        );
+    }
+}
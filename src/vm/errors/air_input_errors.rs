@@ -0,0 +1,24 @@
+use super::memory_errors::MemoryError;
+use crate::vm::errors::{
+    runner_errors::RunnerError, trace_errors::TraceError, vm_errors::VirtualMachineError,
+};
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum AirInputError {
+    #[error(transparent)]
+    Trace(#[from] TraceError),
+    #[error(transparent)]
+    Runner(#[from] RunnerError),
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+    #[error(transparent)]
+    VirtualMachine(#[from] VirtualMachineError),
+    #[error("Range check limits are unknown, as no instructions were executed")]
+    NoRangeCheckLimits,
+    #[error("Missing value at public memory address {0}")]
+    MissingPublicMemoryValue(usize),
+}
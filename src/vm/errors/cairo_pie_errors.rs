@@ -0,0 +1,45 @@
+use super::memory_errors::MemoryError;
+use crate::stdlib::prelude::*;
+use crate::types::relocatable::Relocatable;
+use crate::vm::errors::{runner_errors::RunnerError, trace_errors::TraceError};
+use num_bigint::BigUint;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
+#[cfg(feature = "std")]
+use zip::result::ZipError;
+
+#[derive(Debug, Error)]
+pub enum CairoPieError {
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Zip(#[from] ZipError),
+    #[error(transparent)]
+    Parse(#[from] serde_json::Error),
+    #[error(transparent)]
+    Runner(#[from] RunnerError),
+    #[error(transparent)]
+    Trace(#[from] TraceError),
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+    #[error("Expected the {0} to point to the start of an empty segment")]
+    InvalidReturnSegment(&'static str),
+    #[error("Memory file size {0} is not a multiple of the memory cell size")]
+    InvalidMemoryFileSize(usize),
+    #[error("Memory address {0:#x} is not relocatable")]
+    NonRelocatableMemoryAddress(u64),
+    #[error("Relocatable {0} can't be encoded in {1} bytes")]
+    RelocatableOutOfBounds(Relocatable, usize),
+    #[error("Value {0:#x} doesn't fit in {1} bytes")]
+    ValueOutOfBounds(BigUint, usize),
+    #[error("Invalid additional data for {0}")]
+    InvalidAdditionalData(String),
+    #[error("The Cairo PIE input is not identical to the resulting Cairo PIE: the {0} differ")]
+    Mismatch(&'static str),
+}
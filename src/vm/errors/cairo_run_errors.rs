@@ -1,10 +1,18 @@
+use super::air_input_errors::AirInputError;
+use super::cairo_pie_errors::CairoPieError;
 use super::memory_errors::MemoryError;
 use super::vm_exception::VmException;
-use crate::types::errors::program_errors::ProgramError;
+use crate::types::errors::{
+    contract_class_errors::ContractClassError, layout_errors::LayoutError,
+    program_errors::ProgramError,
+};
 use crate::vm::errors::{
     runner_errors::RunnerError, trace_errors::TraceError, vm_errors::VirtualMachineError,
 };
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 #[derive(Debug, Error)]
 pub enum CairoRunError {
@@ -20,4 +28,12 @@ pub enum CairoRunError {
     MemoryError(#[from] MemoryError),
     #[error(transparent)]
     VmException(#[from] VmException),
+    #[error(transparent)]
+    CairoPie(#[from] CairoPieError),
+    #[error(transparent)]
+    AirInput(#[from] AirInputError),
+    #[error(transparent)]
+    Layout(#[from] LayoutError),
+    #[error(transparent)]
+    ContractClass(#[from] ContractClassError),
 }
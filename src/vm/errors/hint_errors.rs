@@ -1,10 +1,16 @@
+use crate::stdlib::prelude::*;
 use felt::Felt;
 use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 
-use super::{exec_scope_errors::ExecScopeError, vm_errors::VirtualMachineError};
+use super::{
+    exec_scope_errors::ExecScopeError, syscall_errors::SyscallError, vm_errors::VirtualMachineError,
+};
 
 #[derive(Debug, PartialEq, Error)]
 pub enum HintError {
@@ -144,4 +150,6 @@ pub enum HintError {
     NonLeFelt(Felt, Felt),
     #[error("Unknown Hint: {0}")]
     UnknownHint(String),
+    #[error(transparent)]
+    Syscall(#[from] SyscallError),
 }
@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
+use crate::stdlib::prelude::*;
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 
@@ -62,4 +66,8 @@ pub enum MemoryError {
     ErrorVerifyingSignature,
     #[error("Couldn't obtain a mutable accessed offset")]
     CantGetMutAccessedOffset,
+    #[error("Couldn't parse {0:?} as a relocatable value, expected segment_index:offset")]
+    ParseRelocatable(String),
+    #[error("Couldn't parse {0:?} as a felt or a relocatable value")]
+    ParseMaybeRelocatable(String),
 }
@@ -1,9 +1,12 @@
-use std::collections::HashSet;
+use crate::stdlib::{collections::HashSet, prelude::*};
 
 use super::memory_errors::MemoryError;
 use crate::types::relocatable::MaybeRelocatable;
 use felt::Felt;
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum RunnerError {
@@ -73,6 +76,10 @@ pub enum RunnerError {
     FinalStack,
     #[error("Invalid stop pointer for {0} ")]
     InvalidStopPointer(String),
+    #[error("Segment arena: {0} segments were allocated but {1} were finalized")]
+    SegmentArenaNotFinalized(usize, usize),
+    #[error("Segment arena: segment {0} wasn't finalized")]
+    SegmentArenaSegmentNotFinalized(usize),
     #[error("Running in proof-mode but no __start__ label found, try compiling with proof-mode")]
     NoProgramStart,
     #[error("Running in proof-mode but no __end__ label found, try compiling with proof-mode")]
@@ -95,4 +102,10 @@ pub enum RunnerError {
     SafeDivFailUsize(usize, usize),
     #[error(transparent)]
     MemoryError(#[from] MemoryError),
+    #[error("Entrypoint arguments can't be passed in proof mode")]
+    ArgsInProofMode,
+    #[error("Invalid entrypoint argument: {0}")]
+    InvalidArgument(String),
+    #[error("Invalid additional data for builtin {0}")]
+    InvalidAdditionalData(&'static str),
 }
@@ -0,0 +1,15 @@
+use felt::Felt;
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum SyscallError {
+    #[error("Expected the selector of the {0} syscall, got {1}")]
+    UnexpectedSelector(&'static str, Felt),
+    #[error("No result registered for a call to contract {0} with selector {1}")]
+    UnknownContractCall(Felt, Felt),
+    #[error("No result registered for a library call to class {0} with selector {1}")]
+    UnknownLibraryCall(Felt, Felt),
+}
@@ -1,5 +1,8 @@
 use crate::vm::errors::memory_errors::MemoryError;
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum TraceError {
@@ -1,3 +1,4 @@
+use crate::stdlib::prelude::*;
 use crate::{
     types::relocatable::{MaybeRelocatable, Relocatable},
     vm::errors::{
@@ -7,7 +8,10 @@ use crate::{
 };
 use felt::Felt;
 use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 #[derive(Debug, PartialEq, Error)]
 pub enum VirtualMachineError {
@@ -119,6 +123,8 @@ pub enum VirtualMachineError {
     CantSubOffset(usize, usize),
     #[error("Execution reached the end of the program. Requested remaining steps: {0}.")]
     EndOfProgram(usize),
+    #[error("Run exceeded the limit of {1} {0}")]
+    OutOfResources(&'static str, usize),
     #[error(transparent)]
     TracerError(#[from] TraceError),
     #[error(transparent)]
@@ -1,11 +1,18 @@
-use std::{
+use crate::stdlib::{
     fmt::{self, Display},
+    prelude::*,
+};
+#[cfg(feature = "std")]
+use std::{
     fs::File,
     io::{BufReader, Read},
     path::Path,
 };
 
+#[cfg(feature = "std")]
 use thiserror::Error;
+#[cfg(not(feature = "std"))]
+use thiserror_no_std::Error;
 
 use crate::{
     hint_processor::{
@@ -233,6 +240,7 @@ impl Location {
         )
     }
 
+    #[cfg(feature = "std")]
     pub fn to_string_with_content(&self, message: &String) -> String {
         let mut string = self.to_string(message);
         let input_file_path = Path::new(&self.input_file.filename);
@@ -243,6 +251,13 @@ impl Location {
         string
     }
 
+    /// Without `std` the input file can't be read, so only the location is printed.
+    #[cfg(not(feature = "std"))]
+    pub fn to_string_with_content(&self, message: &String) -> String {
+        self.to_string(message)
+    }
+
+    #[cfg(feature = "std")]
     pub fn get_location_marks(&self, file_contents: &mut impl Read) -> String {
         let mut contents = String::new();
         // If this read fails, the string will be left empty, so we can ignore the result
@@ -1,6 +1,10 @@
 pub mod context;
+pub mod coverage;
+#[cfg(feature = "std")]
+pub mod debugger;
 pub mod decoding;
 pub mod errors;
+pub mod profiler;
 pub mod runners;
 pub mod security;
 pub mod trace;
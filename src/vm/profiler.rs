@@ -0,0 +1,336 @@
+use crate::stdlib::{
+    collections::{HashMap, HashSet},
+    prelude::*,
+};
+use crate::{
+    types::relocatable::Relocatable,
+    vm::{
+        errors::{
+            runner_errors::RunnerError, trace_errors::TraceError, vm_errors::VirtualMachineError,
+        },
+        runners::cairo_runner::CairoRunner,
+        vm_core::VirtualMachine,
+    },
+};
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+// Bounds the frames walked for a single call stack, in case the frame pointers in memory
+// form a cycle.
+const MAX_STACK_DEPTH: usize = 10000;
+
+const UNKNOWN_FUNCTION: &str = "<unknown>";
+
+/// Resources used by a run, attributed to the call stacks that used them. Call stacks list
+/// function names from the outermost call to the function being executed.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// Number of steps executed by each call stack.
+    pub steps: HashMap<Vec<String>, usize>,
+    /// Number of instances of each builtin used by each call stack, by builtin name. An
+    /// instance is attributed to the call stack whose instruction first accessed one of its
+    /// cells.
+    pub builtin_instances: HashMap<String, HashMap<Vec<String>, usize>>,
+}
+
+impl Profile {
+    /// Builds the profile of a finished run from its trace, which must have been enabled.
+    pub fn new(runner: &CairoRunner, vm: &VirtualMachine) -> Result<Profile, VirtualMachineError> {
+        let trace = vm.trace.as_ref().ok_or(TraceError::TraceNotEnabled)?;
+        let program_segment = runner
+            .program_base
+            .ok_or(RunnerError::NoProgBase)?
+            .segment_index;
+        let functions = FunctionMap::new(runner);
+        let builtin_segments: HashMap<isize, (&str, usize)> = vm
+            .builtin_runners
+            .iter()
+            .map(|(name, builtin)| {
+                (
+                    builtin.base(),
+                    (name.as_str(), builtin.cells_per_instance() as usize),
+                )
+            })
+            .collect();
+        // Each step accesses its dst, op0 and op1 addresses, in that order.
+        let accessed_addresses = vm.accessed_addresses.as_deref().unwrap_or(&[]);
+
+        let mut profile = Profile::default();
+        // The return fp and pc of a frame are written once, so every frame with the same fp
+        // has the same callers.
+        let mut callers_by_fp = HashMap::new();
+        let mut used_instances = HashSet::new();
+        for (step, entry) in trace.iter().enumerate() {
+            let mut stack: Vec<String> = callers_by_fp
+                .entry(entry.fp)
+                .or_insert_with(|| get_callers(vm, program_segment, &functions, entry.fp))
+                .clone();
+            stack.push(functions.name_at(entry.pc.offset).to_string());
+
+            for address in accessed_addresses.iter().skip(3 * step).take(3) {
+                let (name, cells_per_instance) = match builtin_segments.get(&address.segment_index)
+                {
+                    Some(builtin) => builtin,
+                    None => continue,
+                };
+                let instance = (address.segment_index, address.offset / cells_per_instance);
+                if used_instances.insert(instance) {
+                    *profile
+                        .builtin_instances
+                        .entry(name.to_string())
+                        .or_default()
+                        .entry(stack.clone())
+                        .or_default() += 1;
+                }
+            }
+            *profile.steps.entry(stack).or_default() += 1;
+        }
+
+        Ok(profile)
+    }
+
+    /// Writes the usage of a resource, either `steps` or the name of a builtin, in the collapsed
+    /// stacks format read by flamegraph tools: one line per call stack, with its frames
+    /// separated by semicolons and followed by the amount used.
+    #[cfg(feature = "std")]
+    pub fn write_collapsed_stacks<W: Write>(
+        &self,
+        writer: &mut W,
+        resource: &str,
+    ) -> io::Result<()> {
+        let samples = match resource {
+            "steps" => Some(&self.steps),
+            builtin => self.builtin_instances.get(builtin),
+        };
+        let mut lines: Vec<_> = samples
+            .into_iter()
+            .flatten()
+            .map(|(stack, count)| format!("{} {count}", stack.join(";")))
+            .collect();
+        lines.sort();
+        for line in lines {
+            writeln!(writer, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+// Function labels of the program, sorted by pc.
+struct FunctionMap(Vec<(usize, String)>);
+
+impl FunctionMap {
+    fn new(runner: &CairoRunner) -> Self {
+        let mut functions: Vec<_> = runner
+            .program
+            .identifiers
+            .iter()
+            .filter(|(_, identifier)| identifier.type_.as_deref() == Some("function"))
+            .filter_map(|(name, identifier)| Some((identifier.pc?, name.clone())))
+            .collect();
+        functions.sort();
+        FunctionMap(functions)
+    }
+
+    // Returns the function whose body contains the given pc, assuming it starts at the
+    // closest function label before it.
+    fn name_at(&self, pc: usize) -> &str {
+        match self
+            .0
+            .partition_point(|(function_pc, _)| *function_pc <= pc)
+        {
+            0 => UNKNOWN_FUNCTION,
+            i => &self.0[i - 1].1,
+        }
+    }
+}
+
+// Walks the frames above the one at `fp` through the return fp and pc stored right below each
+// frame, stopping at the first frame that wasn't entered through a call in the program.
+fn get_callers(
+    vm: &VirtualMachine,
+    program_segment: isize,
+    functions: &FunctionMap,
+    mut fp: Relocatable,
+) -> Vec<String> {
+    let mut callers = Vec::new();
+    while callers.len() < MAX_STACK_DEPTH {
+        let return_address = |offset| {
+            fp.sub_usize(offset)
+                .ok()
+                .and_then(|address| vm.memory.get_relocatable(&address).ok())
+        };
+        let (ret_fp, ret_pc) = match (return_address(2), return_address(1)) {
+            (Some(ret_fp), Some(ret_pc)) => (ret_fp, ret_pc),
+            _ => break,
+        };
+        if ret_pc.segment_index != program_segment
+            || ret_pc.offset == 0
+            || ret_fp.segment_index != fp.segment_index
+            || ret_fp.offset >= fp.offset
+        {
+            break;
+        }
+        // The return pc follows the call instruction, which belongs to the caller.
+        callers.push(functions.name_at(ret_pc.offset - 1).to_string());
+        fp = ret_fp;
+    }
+    callers.reverse();
+    callers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        serde::deserialize_program::Identifier,
+        types::{program::Program, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError, runners::builtin_runner::RangeCheckBuiltinRunner,
+            trace::trace_entry::TraceEntry, vm_memory::memory::Memory,
+        },
+    };
+
+    fn function(pc: usize) -> Identifier {
+        Identifier {
+            pc: Some(pc),
+            type_: Some(String::from("function")),
+            value: None,
+            full_name: None,
+            members: None,
+        }
+    }
+
+    fn stack(functions: &[&str]) -> Vec<String> {
+        functions.iter().map(|name| name.to_string()).collect()
+    }
+
+    // `main` spans pcs 0 to 3 of segment 3 and calls `foo` at pc 1, which starts at pc 4 and
+    // uses a range check instance in its two steps.
+    fn profile_run() -> Profile {
+        let program = program!(
+            identifiers = HashMap::from([
+                (String::from("__main__.main"), function(0)),
+                (String::from("__main__.foo"), function(4)),
+            ]),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.program_base = Some(Relocatable::from((3, 0)));
+        let mut vm = vm!(true);
+        vm.builtin_runners = vec![(
+            String::from("range_check"),
+            RangeCheckBuiltinRunner::new(8, 8, true).into(),
+        )];
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 1), (2, 0)),
+            ((1, 3), (1, 2)),
+            ((1, 4), (3, 3))
+        ];
+        vm.trace = Some(vec![
+            TraceEntry {
+                pc: (3, 0).into(),
+                ap: (1, 2).into(),
+                fp: (1, 2).into(),
+            },
+            TraceEntry {
+                pc: (3, 1).into(),
+                ap: (1, 3).into(),
+                fp: (1, 2).into(),
+            },
+            TraceEntry {
+                pc: (3, 4).into(),
+                ap: (1, 5).into(),
+                fp: (1, 5).into(),
+            },
+            TraceEntry {
+                pc: (3, 5).into(),
+                ap: (1, 5).into(),
+                fp: (1, 5).into(),
+            },
+        ]);
+        let execution_cell = Relocatable::from((1, 2));
+        let range_check_cell = Relocatable::from((0, 0));
+        vm.accessed_addresses = Some(
+            [
+                [execution_cell; 3],
+                [execution_cell; 3],
+                [range_check_cell, execution_cell, execution_cell],
+                [range_check_cell, execution_cell, execution_cell],
+            ]
+            .concat(),
+        );
+
+        Profile::new(&cairo_runner, &vm).unwrap()
+    }
+
+    #[test]
+    fn profile_steps_and_builtins() {
+        let profile = profile_run();
+        assert_eq!(
+            profile.steps,
+            HashMap::from([
+                (stack(&["__main__.main"]), 2),
+                (stack(&["__main__.main", "__main__.foo"]), 2)
+            ])
+        );
+        assert_eq!(
+            profile.builtin_instances,
+            HashMap::from([(
+                String::from("range_check"),
+                HashMap::from([(stack(&["__main__.main", "__main__.foo"]), 1)])
+            )])
+        );
+    }
+
+    #[test]
+    fn write_collapsed_stacks() {
+        let profile = profile_run();
+        let mut output = Vec::new();
+        profile
+            .write_collapsed_stacks(&mut output, "steps")
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "__main__.main 2\n__main__.main;__main__.foo 2\n"
+        );
+
+        let mut output = Vec::new();
+        profile
+            .write_collapsed_stacks(&mut output, "range_check")
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "__main__.main;__main__.foo 1\n"
+        );
+
+        let mut output = Vec::new();
+        profile
+            .write_collapsed_stacks(&mut output, "pedersen")
+            .unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn profile_trace_not_enabled() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+        assert_eq!(
+            Profile::new(&cairo_runner, &vm),
+            Err(VirtualMachineError::TracerError(
+                TraceError::TraceNotEnabled
+            ))
+        );
+    }
+
+    #[test]
+    fn function_at_pc_before_any_label() {
+        let program =
+            program!(identifiers = HashMap::from([(String::from("__main__.main"), function(2))]),);
+        let cairo_runner = cairo_runner!(program);
+        let functions = FunctionMap::new(&cairo_runner);
+        assert_eq!(functions.name_at(1), UNKNOWN_FUNCTION);
+        assert_eq!(functions.name_at(7), "__main__.main");
+    }
+}
@@ -1,4 +1,6 @@
+use crate::stdlib::prelude::*;
 use crate::{
+    air_private_input::{PrivateInput, PrivateInputPair},
     math_utils::safe_div_usize,
     types::{
         instance_definitions::bitwise_instance_def::{
@@ -8,6 +10,7 @@ use crate::{
     },
     vm::{
         errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::builtin_runner::get_instance_inputs,
         vm_core::VirtualMachine,
         vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
     },
@@ -17,14 +20,14 @@ use num_integer::div_ceil;
 
 #[derive(Debug, Clone)]
 pub struct BitwiseBuiltinRunner {
-    ratio: u32,
+    pub(crate) ratio: u32,
     pub base: isize,
     pub(crate) cells_per_instance: u32,
     pub(crate) n_input_cells: u32,
     bitwise_builtin: BitwiseInstanceDef,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) _included: bool,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl BitwiseBuiltinRunner {
@@ -125,6 +128,24 @@ impl BitwiseBuiltinRunner {
         ("bitwise", (self.base, self.stop_ptr))
     }
 
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Pair(PrivateInputPair {
+                index,
+                x: inputs[0].clone(),
+                y: inputs[1].clone(),
+            })
+        })
+        .collect()
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -202,8 +223,7 @@ impl BitwiseBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 
@@ -583,4 +603,25 @@ mod tests {
         ));
         assert_eq!(builtin.get_used_diluted_check_units(50, 25), 250);
     }
+
+    #[test]
+    fn air_private_input_skips_incomplete_instances() {
+        let builtin = BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true);
+        let memory = memory![
+            ((0, 0), 10),
+            ((0, 1), 12),
+            ((0, 2), 8),
+            ((0, 3), 14),
+            ((0, 4), 6),
+            ((0, 5), 3)
+        ];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![PrivateInput::Pair(PrivateInputPair {
+                index: 0,
+                x: Felt::new(10),
+                y: Felt::new(12),
+            })]
+        );
+    }
 }
@@ -1,10 +1,13 @@
+use crate::air_private_input::{PrivateInput, PrivateInputEcOp};
 use crate::math_utils::{ec_add, ec_double, safe_div_usize};
+use crate::stdlib::{borrow::Cow, prelude::*};
 use crate::types::instance_definitions::ec_op_instance_def::{
     EcOpInstanceDef, CELLS_PER_EC_OP, INPUT_CELLS_PER_EC_OP,
 };
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::builtin_runner::get_instance_inputs;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
@@ -12,18 +15,17 @@ use felt::{Felt, FeltOps, NewFelt};
 use num_bigint::BigInt;
 use num_integer::{div_ceil, Integer};
 use num_traits::{Num, One, Pow, Zero};
-use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct EcOpBuiltinRunner {
-    ratio: u32,
+    pub(crate) ratio: u32,
     pub base: isize,
     pub(crate) cells_per_instance: u32,
     pub(crate) n_input_cells: u32,
     ec_op_builtin: EcOpInstanceDef,
     pub(crate) stop_ptr: Option<usize>,
     _included: bool,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl EcOpBuiltinRunner {
@@ -198,6 +200,28 @@ impl EcOpBuiltinRunner {
         ("ec_op", (self.base, self.stop_ptr))
     }
 
+    // The input cells of an instance are laid out as p_x, p_y, q_x, q_y, m.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::EcOp(PrivateInputEcOp {
+                index,
+                p_x: inputs[0].clone(),
+                p_y: inputs[1].clone(),
+                q_x: inputs[2].clone(),
+                q_y: inputs[3].clone(),
+                m: inputs[4].clone(),
+            })
+        })
+        .collect()
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -263,8 +287,7 @@ impl EcOpBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 
@@ -0,0 +1,167 @@
+use crate::stdlib::prelude::*;
+use crate::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{
+        errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        vm_core::VirtualMachine,
+        vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    },
+};
+use felt::{Felt, NewFelt};
+
+/// The gas counter of Cairo 1 programs. Unlike other builtins, the program receives and
+/// returns an integer, the available gas, rather than a pointer. Its segment is only added so
+/// the builtin has a base like the others, and stays empty.
+#[derive(Debug, Clone)]
+pub struct GasBuiltinRunner {
+    base: isize,
+    pub initial_gas: u64,
+    pub(crate) stop_ptr: Option<usize>,
+    pub(crate) _included: bool,
+}
+
+impl GasBuiltinRunner {
+    pub fn new(initial_gas: u64, included: bool) -> Self {
+        GasBuiltinRunner {
+            base: 0,
+            initial_gas,
+            stop_ptr: None,
+            _included: included,
+        }
+    }
+
+    pub fn initialize_segments(
+        &mut self,
+        segments: &mut MemorySegmentManager,
+        memory: &mut Memory,
+    ) {
+        self.base = segments.add(memory).segment_index
+    }
+
+    pub fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        if self._included {
+            vec![MaybeRelocatable::from(Felt::new(self.initial_gas))]
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn base(&self) -> isize {
+        self.base
+    }
+
+    pub fn add_validation_rule(&self, _memory: &mut Memory) -> Result<(), RunnerError> {
+        Ok(())
+    }
+
+    pub fn deduce_memory_cell(
+        &self,
+        _address: &Relocatable,
+        _memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        Ok(None)
+    }
+
+    pub fn get_allocated_memory_units(&self, _vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        Ok(0)
+    }
+
+    pub fn get_memory_segment_addresses(&self) -> (&'static str, (isize, Option<usize>)) {
+        ("gas_builtin", (self.base, self.stop_ptr))
+    }
+
+    pub fn get_used_cells(&self, _vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        Ok(0)
+    }
+
+    pub fn get_used_cells_and_allocated_size(
+        &self,
+        _vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        Ok((0, 0))
+    }
+
+    pub fn get_used_instances(&self, _vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        Ok(0)
+    }
+
+    // The returned value is the remaining gas, which can't exceed the initial one.
+    pub fn final_stack(
+        &self,
+        vm: &VirtualMachine,
+        pointer: Relocatable,
+    ) -> Result<(Relocatable, usize), RunnerError> {
+        if self._included {
+            let remaining_gas_addr = pointer.sub_usize(1).map_err(|_| RunnerError::FinalStack)?;
+            let remaining_gas = vm
+                .get_integer(&remaining_gas_addr)
+                .map_err(|_| RunnerError::FinalStack)?;
+            if remaining_gas.as_ref() > &Felt::new(self.initial_gas) {
+                return Err(RunnerError::InvalidStopPointer("gas_builtin".to_string()));
+            }
+            Ok((remaining_gas_addr, 0))
+        } else {
+            Ok((pointer, 0))
+        }
+    }
+}
+
+impl Default for GasBuiltinRunner {
+    fn default() -> Self {
+        Self::new(u64::MAX, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    #[test]
+    fn initial_stack() {
+        let builtin = GasBuiltinRunner::new(1000, true);
+        assert_eq!(
+            builtin.initial_stack(),
+            vec![MaybeRelocatable::from(Felt::new(1000))]
+        );
+    }
+
+    #[test]
+    fn initial_stack_not_included() {
+        let builtin = GasBuiltinRunner::new(1000, false);
+        assert_eq!(builtin.initial_stack(), Vec::new());
+    }
+
+    #[test]
+    fn final_stack() {
+        let builtin = GasBuiltinRunner::new(1000, true);
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 990)];
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((1, 1))),
+            Ok((Relocatable::from((1, 0)), 0))
+        );
+    }
+
+    #[test]
+    fn final_stack_more_gas_than_available() {
+        let builtin = GasBuiltinRunner::new(1000, true);
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 1001)];
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((1, 1))),
+            Err(RunnerError::InvalidStopPointer("gas_builtin".to_string()))
+        );
+    }
+
+    #[test]
+    fn final_stack_pointer_returned() {
+        let builtin = GasBuiltinRunner::new(1000, true);
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0))];
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((1, 1))),
+            Err(RunnerError::FinalStack)
+        );
+    }
+}
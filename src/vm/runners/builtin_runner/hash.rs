@@ -1,5 +1,6 @@
-use std::cell::RefCell;
+use crate::stdlib::{cell::RefCell, prelude::*};
 
+use crate::air_private_input::{PrivateInput, PrivateInputPair};
 use crate::math_utils::safe_div_usize;
 use crate::types::instance_definitions::pedersen_instance_def::{
     CELLS_PER_HASH, INPUT_CELLS_PER_HASH,
@@ -7,6 +8,8 @@ use crate::types::instance_definitions::pedersen_instance_def::{
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::builtin_runner::get_instance_inputs;
+use crate::vm::runners::cairo_pie::BuiltinAdditionalData;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
@@ -17,12 +20,12 @@ use starknet_crypto::{pedersen_hash, FieldElement};
 #[derive(Debug, Clone)]
 pub struct HashBuiltinRunner {
     pub base: isize,
-    ratio: u32,
+    pub(crate) ratio: u32,
     pub(crate) cells_per_instance: u32,
     pub(crate) n_input_cells: u32,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) _included: bool,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
     // This act as a cache to optimize calls to deduce_memory_cell
     // Therefore need interior mutability
     pub(self) verified_addresses: RefCell<Vec<Relocatable>>,
@@ -128,6 +131,43 @@ impl HashBuiltinRunner {
         ("pedersen", (self.base, self.stop_ptr))
     }
 
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Pair(PrivateInputPair {
+                index,
+                x: inputs[0].clone(),
+                y: inputs[1].clone(),
+            })
+        })
+        .collect()
+    }
+
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        let mut verified_addresses = self.verified_addresses.borrow().clone();
+        verified_addresses.sort_by_key(|address| (address.segment_index, address.offset));
+        BuiltinAdditionalData::Hash(verified_addresses)
+    }
+
+    pub fn extend_additional_data(
+        &self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Hash(addresses) => {
+                self.verified_addresses.borrow_mut().extend(addresses);
+                Ok(())
+            }
+            _ => Err(RunnerError::InvalidAdditionalData("pedersen")),
+        }
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -193,8 +233,7 @@ impl HashBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 }
@@ -528,4 +567,24 @@ mod tests {
         vm.segments.segment_used_sizes = Some(vec![4]);
         assert_eq!(builtin.get_used_cells(&vm), Ok(4));
     }
+
+    #[test]
+    fn get_and_extend_additional_data() {
+        let mut builtin = HashBuiltinRunner::new(256, true);
+        builtin.verified_addresses =
+            RefCell::new(vec![Relocatable::from((0, 5)), Relocatable::from((0, 2))]);
+        let additional_data = builtin.get_additional_data();
+        assert_eq!(
+            additional_data,
+            BuiltinAdditionalData::Hash(vec![Relocatable::from((0, 2)), Relocatable::from((0, 5))])
+        );
+
+        let other = HashBuiltinRunner::new(256, true);
+        other.extend_additional_data(&additional_data).unwrap();
+        assert_eq!(other.get_additional_data(), additional_data);
+        assert_eq!(
+            other.extend_additional_data(&BuiltinAdditionalData::None),
+            Err(RunnerError::InvalidAdditionalData("pedersen"))
+        );
+    }
 }
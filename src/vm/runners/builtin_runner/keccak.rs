@@ -1,12 +1,15 @@
+use crate::air_private_input::{PrivateInput, PrivateInputKeccakState};
 use crate::hint_processor::builtin_hint_processor::cairo_keccak::keccak_hints::{
     maybe_reloc_vec_to_u64_array, u64_array_to_mayberelocatable_vec,
 };
 use crate::hint_processor::builtin_hint_processor::keccak_utils::left_pad_u64;
 use crate::math_utils::safe_div_usize;
+use crate::stdlib::prelude::*;
 use crate::types::instance_definitions::keccak_instance_def::KeccakInstanceDef;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::builtin_runner::get_instance_inputs;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
@@ -18,7 +21,7 @@ const KECCAK_ARRAY_LEN: usize = 25;
 
 #[derive(Debug, Clone)]
 pub struct KeccakBuiltinRunner {
-    ratio: u32,
+    pub(crate) ratio: u32,
     pub base: isize,
     pub(crate) cells_per_instance: u32,
     pub(crate) n_input_cells: u32,
@@ -26,7 +29,7 @@ pub struct KeccakBuiltinRunner {
     pub(crate) stop_ptr: Option<usize>,
     _included: bool,
     state_rep: Vec<u32>,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl KeccakBuiltinRunner {
@@ -154,6 +157,30 @@ impl KeccakBuiltinRunner {
         ("keccak", (self.base, self.stop_ptr))
     }
 
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::KeccakState(PrivateInputKeccakState {
+                index,
+                input_s0: inputs[0].clone(),
+                input_s1: inputs[1].clone(),
+                input_s2: inputs[2].clone(),
+                input_s3: inputs[3].clone(),
+                input_s4: inputs[4].clone(),
+                input_s5: inputs[5].clone(),
+                input_s6: inputs[6].clone(),
+                input_s7: inputs[7].clone(),
+            })
+        })
+        .collect()
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -217,8 +244,7 @@ impl KeccakBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 
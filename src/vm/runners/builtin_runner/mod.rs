@@ -1,26 +1,36 @@
+use crate::air_private_input::PrivateInput;
+use crate::stdlib::prelude::*;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::{self, MemoryError};
 use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::cairo_pie::BuiltinAdditionalData;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use felt::Felt;
 
 mod bitwise;
 mod ec_op;
+mod gas;
 mod hash;
 mod keccak;
 mod output;
+mod poseidon;
 mod range_check;
+mod segment_arena;
 mod signature;
 
 pub use self::keccak::KeccakBuiltinRunner;
 pub use bitwise::BitwiseBuiltinRunner;
 pub use ec_op::EcOpBuiltinRunner;
+pub use gas::GasBuiltinRunner;
 pub use hash::HashBuiltinRunner;
 use num_integer::div_floor;
 pub use output::OutputBuiltinRunner;
+pub use poseidon::PoseidonBuiltinRunner;
 pub use range_check::RangeCheckBuiltinRunner;
+pub use segment_arena::SegmentArenaBuiltinRunner;
 pub use signature::SignatureBuiltinRunner;
 
 /* NB: this enum is no accident: we may need (and cairo-rs-py *does* need)
@@ -39,7 +49,10 @@ pub enum BuiltinRunner {
     Output(OutputBuiltinRunner),
     RangeCheck(RangeCheckBuiltinRunner),
     Keccak(KeccakBuiltinRunner),
+    Poseidon(PoseidonBuiltinRunner),
     Signature(SignatureBuiltinRunner),
+    SegmentArena(SegmentArenaBuiltinRunner),
+    Gas(GasBuiltinRunner),
 }
 
 impl BuiltinRunner {
@@ -60,9 +73,16 @@ impl BuiltinRunner {
                 range_check.initialize_segments(segments, memory)
             }
             BuiltinRunner::Keccak(ref mut keccak) => keccak.initialize_segments(segments, memory),
+            BuiltinRunner::Poseidon(ref mut poseidon) => {
+                poseidon.initialize_segments(segments, memory)
+            }
             BuiltinRunner::Signature(ref mut signature) => {
                 signature.initialize_segments(segments, memory)
             }
+            BuiltinRunner::SegmentArena(ref mut segment_arena) => {
+                segment_arena.initialize_segments(segments, memory)
+            }
+            BuiltinRunner::Gas(ref mut gas) => gas.initialize_segments(segments, memory),
         }
     }
 
@@ -74,7 +94,10 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref output) => output.initial_stack(),
             BuiltinRunner::RangeCheck(ref range_check) => range_check.initial_stack(),
             BuiltinRunner::Keccak(ref keccak) => keccak.initial_stack(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.initial_stack(),
             BuiltinRunner::Signature(ref signature) => signature.initial_stack(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.initial_stack(),
+            BuiltinRunner::Gas(ref gas) => gas.initial_stack(),
         }
     }
 
@@ -92,7 +115,12 @@ impl BuiltinRunner {
                 range_check.final_stack(vm, stack_pointer)
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.final_stack(vm, stack_pointer),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.final_stack(vm, stack_pointer),
             BuiltinRunner::Signature(ref signature) => signature.final_stack(vm, stack_pointer),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.final_stack(vm, stack_pointer)
+            }
+            BuiltinRunner::Gas(ref gas) => gas.final_stack(vm, stack_pointer),
         }
     }
 
@@ -110,7 +138,12 @@ impl BuiltinRunner {
                 range_check.get_allocated_memory_units(vm)
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.get_allocated_memory_units(vm),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_allocated_memory_units(vm),
             BuiltinRunner::Signature(ref signature) => signature.get_allocated_memory_units(vm),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_allocated_memory_units(vm)
+            }
+            BuiltinRunner::Gas(ref gas) => gas.get_allocated_memory_units(vm),
         }
     }
 
@@ -123,7 +156,10 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref output) => output.base(),
             BuiltinRunner::RangeCheck(ref range_check) => range_check.base(),
             BuiltinRunner::Keccak(ref keccak) => keccak.base(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.base(),
             BuiltinRunner::Signature(ref signature) => signature.base(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.base(),
+            BuiltinRunner::Gas(ref gas) => gas.base(),
         }
     }
 
@@ -135,7 +171,41 @@ impl BuiltinRunner {
             BuiltinRunner::Output(_) => None,
             BuiltinRunner::RangeCheck(range_check) => Some(range_check.ratio()),
             BuiltinRunner::Keccak(keccak) => Some(keccak.ratio()),
+            BuiltinRunner::Poseidon(poseidon) => Some(poseidon.ratio()),
             BuiltinRunner::Signature(ref signature) => Some(signature.ratio()),
+            BuiltinRunner::SegmentArena(_) => None,
+            BuiltinRunner::Gas(_) => None,
+        }
+    }
+
+    // Used by the dynamic layout, whose ratios are only known once the run has ended.
+    pub(crate) fn set_ratio(&mut self, ratio: u32) {
+        match self {
+            BuiltinRunner::Bitwise(bitwise) => bitwise.ratio = ratio,
+            BuiltinRunner::EcOp(ec) => ec.ratio = ratio,
+            BuiltinRunner::Hash(hash) => hash.ratio = ratio,
+            BuiltinRunner::Output(_) => {}
+            BuiltinRunner::RangeCheck(range_check) => range_check.ratio = ratio,
+            BuiltinRunner::Keccak(keccak) => keccak.ratio = ratio,
+            BuiltinRunner::Poseidon(poseidon) => poseidon.ratio = ratio,
+            BuiltinRunner::Signature(signature) => signature.ratio = ratio,
+            BuiltinRunner::SegmentArena(_) => {}
+            BuiltinRunner::Gas(_) => {}
+        }
+    }
+
+    pub(crate) fn instances_per_component(&self) -> Option<u32> {
+        match self {
+            BuiltinRunner::Bitwise(bitwise) => Some(bitwise.instances_per_component),
+            BuiltinRunner::EcOp(ec) => Some(ec.instances_per_component),
+            BuiltinRunner::Hash(hash) => Some(hash.instances_per_component),
+            BuiltinRunner::Output(_) => None,
+            BuiltinRunner::RangeCheck(range_check) => Some(range_check.instances_per_component),
+            BuiltinRunner::Keccak(keccak) => Some(keccak.instances_per_component),
+            BuiltinRunner::Poseidon(poseidon) => Some(poseidon.instances_per_component),
+            BuiltinRunner::Signature(signature) => Some(signature.instances_per_component),
+            BuiltinRunner::SegmentArena(_) => None,
+            BuiltinRunner::Gas(_) => None,
         }
     }
 
@@ -147,7 +217,12 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref output) => output.add_validation_rule(memory),
             BuiltinRunner::RangeCheck(ref range_check) => range_check.add_validation_rule(memory),
             BuiltinRunner::Keccak(ref keccak) => keccak.add_validation_rule(memory),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.add_validation_rule(memory),
             BuiltinRunner::Signature(ref signature) => signature.add_validation_rule(memory),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.add_validation_rule(memory)
+            }
+            BuiltinRunner::Gas(ref gas) => gas.add_validation_rule(memory),
         }
     }
 
@@ -165,9 +240,14 @@ impl BuiltinRunner {
                 range_check.deduce_memory_cell(address, memory)
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.deduce_memory_cell(address, memory),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.deduce_memory_cell(address, memory),
             BuiltinRunner::Signature(ref signature) => {
                 signature.deduce_memory_cell(address, memory)
             }
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.deduce_memory_cell(address, memory)
+            }
+            BuiltinRunner::Gas(ref gas) => gas.deduce_memory_cell(address, memory),
         }
     }
 
@@ -187,6 +267,21 @@ impl BuiltinRunner {
         Ok((0..segment_size).map(|i| (base, i).into()).collect())
     }
 
+    pub(crate) fn cells_per_instance(&self) -> u32 {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.cells_per_instance,
+            BuiltinRunner::EcOp(ref ec) => ec.cells_per_instance,
+            BuiltinRunner::Hash(ref hash) => hash.cells_per_instance,
+            BuiltinRunner::Output(_) => 1,
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.cells_per_instance,
+            BuiltinRunner::Keccak(ref keccak) => keccak.cells_per_instance,
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.cells_per_instance,
+            BuiltinRunner::Signature(ref signature) => signature.cells_per_instance,
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.cells_per_instance,
+            BuiltinRunner::Gas(_) => 1,
+        }
+    }
+
     pub fn get_memory_segment_addresses(&self) -> (&'static str, (isize, Option<usize>)) {
         match self {
             BuiltinRunner::Bitwise(ref bitwise) => bitwise.get_memory_segment_addresses(),
@@ -197,7 +292,12 @@ impl BuiltinRunner {
                 range_check.get_memory_segment_addresses()
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.get_memory_segment_addresses(),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_memory_segment_addresses(),
             BuiltinRunner::Signature(ref signature) => signature.get_memory_segment_addresses(),
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_memory_segment_addresses()
+            }
+            BuiltinRunner::Gas(ref gas) => gas.get_memory_segment_addresses(),
         }
     }
 
@@ -209,7 +309,10 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref output) => output.get_used_cells(vm),
             BuiltinRunner::RangeCheck(ref range_check) => range_check.get_used_cells(vm),
             BuiltinRunner::Keccak(ref keccak) => keccak.get_used_cells(vm),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_cells(vm),
             BuiltinRunner::Signature(ref signature) => signature.get_used_cells(vm),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.get_used_cells(vm),
+            BuiltinRunner::Gas(ref gas) => gas.get_used_cells(vm),
         }
     }
 
@@ -221,7 +324,10 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref output) => output.get_used_instances(vm),
             BuiltinRunner::RangeCheck(ref range_check) => range_check.get_used_instances(vm),
             BuiltinRunner::Keccak(ref keccak) => keccak.get_used_instances(vm),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_instances(vm),
             BuiltinRunner::Signature(ref signature) => signature.get_used_instances(vm),
+            BuiltinRunner::SegmentArena(ref segment_arena) => segment_arena.get_used_instances(vm),
+            BuiltinRunner::Gas(ref gas) => gas.get_used_instances(vm),
         }
     }
 
@@ -258,7 +364,9 @@ impl BuiltinRunner {
     }
 
     pub fn run_security_checks(&self, vm: &mut VirtualMachine) -> Result<(), VirtualMachineError> {
-        if let BuiltinRunner::Output(_) = self {
+        if let BuiltinRunner::Output(_) | BuiltinRunner::SegmentArena(_) | BuiltinRunner::Gas(_) =
+            self
+        {
             return Ok(());
         }
 
@@ -267,8 +375,11 @@ impl BuiltinRunner {
             BuiltinRunner::EcOp(x) => (x.cells_per_instance, x.n_input_cells),
             BuiltinRunner::Hash(x) => (x.cells_per_instance, x.n_input_cells),
             BuiltinRunner::RangeCheck(x) => (x.cells_per_instance, x.n_input_cells),
-            BuiltinRunner::Output(_) => unreachable!(),
+            BuiltinRunner::Output(_) | BuiltinRunner::SegmentArena(_) | BuiltinRunner::Gas(_) => {
+                unreachable!()
+            }
             BuiltinRunner::Keccak(x) => (x.cells_per_instance, x.n_input_cells),
+            BuiltinRunner::Poseidon(x) => (x.cells_per_instance, x.n_input_cells),
             BuiltinRunner::Signature(ref x) => (x.cells_per_instance, x.n_input_cells),
         };
 
@@ -298,7 +409,10 @@ impl BuiltinRunner {
                 BuiltinRunner::Output(_) => "output",
                 BuiltinRunner::RangeCheck(_) => "range_check",
                 BuiltinRunner::Keccak(_) => "keccak",
+                BuiltinRunner::Poseidon(_) => "poseidon",
                 BuiltinRunner::Signature(_) => "ecdsa",
+                BuiltinRunner::SegmentArena(_) => "segment_arena",
+                BuiltinRunner::Gas(_) => "gas_builtin",
             })
             .into());
         }
@@ -328,7 +442,10 @@ impl BuiltinRunner {
                     BuiltinRunner::Output(_) => "output",
                     BuiltinRunner::RangeCheck(_) => "range_check",
                     BuiltinRunner::Keccak(_) => "keccak",
+                    BuiltinRunner::Poseidon(_) => "poseidon",
                     BuiltinRunner::Signature(_) => "ecdsa",
+                    BuiltinRunner::SegmentArena(_) => "segment_arena",
+                    BuiltinRunner::Gas(_) => "gas_builtin",
                 },
                 missing_offsets,
             )
@@ -364,9 +481,14 @@ impl BuiltinRunner {
                 range_check.get_used_cells_and_allocated_size(vm)
             }
             BuiltinRunner::Keccak(ref keccak) => keccak.get_used_cells_and_allocated_size(vm),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.get_used_cells_and_allocated_size(vm),
             BuiltinRunner::Signature(ref signature) => {
                 signature.get_used_cells_and_allocated_size(vm)
             }
+            BuiltinRunner::SegmentArena(ref segment_arena) => {
+                segment_arena.get_used_cells_and_allocated_size(vm)
+            }
+            BuiltinRunner::Gas(ref gas) => gas.get_used_cells_and_allocated_size(vm),
         }
     }
 
@@ -378,9 +500,88 @@ impl BuiltinRunner {
             BuiltinRunner::Output(ref mut output) => output.stop_ptr = Some(stop_ptr),
             BuiltinRunner::RangeCheck(ref mut range_check) => range_check.stop_ptr = Some(stop_ptr),
             BuiltinRunner::Keccak(ref mut keccak) => keccak.stop_ptr = Some(stop_ptr),
+            BuiltinRunner::Poseidon(ref mut poseidon) => poseidon.stop_ptr = Some(stop_ptr),
             BuiltinRunner::Signature(ref mut signature) => signature.stop_ptr = Some(stop_ptr),
+            BuiltinRunner::SegmentArena(ref mut segment_arena) => {
+                segment_arena.stop_ptr = Some(stop_ptr)
+            }
+            BuiltinRunner::Gas(ref mut gas) => gas.stop_ptr = Some(stop_ptr),
+        }
+    }
+
+    /// Returns the state that a Cairo PIE needs to run the builtin again, besides its memory.
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        match self {
+            BuiltinRunner::Hash(ref hash) => hash.get_additional_data(),
+            BuiltinRunner::Output(ref output) => output.get_additional_data(),
+            BuiltinRunner::Signature(ref signature) => signature.get_additional_data(),
+            _ => BuiltinAdditionalData::None,
+        }
+    }
+
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match self {
+            BuiltinRunner::Hash(ref hash) => hash.extend_additional_data(additional_data),
+            BuiltinRunner::Output(ref output) => output.extend_additional_data(additional_data),
+            BuiltinRunner::Signature(ref mut signature) => {
+                signature.extend_additional_data(additional_data)
+            }
+            _ => match additional_data {
+                BuiltinAdditionalData::None => Ok(()),
+                _ => Err(RunnerError::InvalidAdditionalData(
+                    self.get_memory_segment_addresses().0,
+                )),
+            },
         }
     }
+
+    /// Returns the inputs of the builtin instances that the prover needs, besides the memory.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        match self {
+            BuiltinRunner::Bitwise(ref bitwise) => bitwise.air_private_input(memory),
+            BuiltinRunner::EcOp(ref ec) => ec.air_private_input(memory),
+            BuiltinRunner::Hash(ref hash) => hash.air_private_input(memory),
+            BuiltinRunner::RangeCheck(ref range_check) => range_check.air_private_input(memory),
+            BuiltinRunner::Keccak(ref keccak) => keccak.air_private_input(memory),
+            BuiltinRunner::Poseidon(ref poseidon) => poseidon.air_private_input(memory),
+            BuiltinRunner::Signature(ref signature) => signature.air_private_input(memory),
+            BuiltinRunner::Output(_) | BuiltinRunner::SegmentArena(_) | BuiltinRunner::Gas(_) => {
+                vec![]
+            }
+        }
+    }
+}
+
+/// Returns the index and input values of each instance of a builtin whose input cells all hold
+/// integers. Instances that weren't fully written are skipped.
+pub(crate) fn get_instance_inputs(
+    memory: &Memory,
+    base: isize,
+    cells_per_instance: u32,
+    n_input_cells: u32,
+) -> Vec<(usize, Vec<Felt>)> {
+    let segment = match usize::try_from(base).ok().and_then(|i| memory.data.get(i)) {
+        Some(segment) => segment,
+        None => return vec![],
+    };
+    segment
+        .chunks(cells_per_instance as usize)
+        .enumerate()
+        .filter_map(|(index, cells)| {
+            let inputs = cells
+                .get(..n_input_cells as usize)?
+                .iter()
+                .map(|cell| match cell {
+                    Some(MaybeRelocatable::Int(value)) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some((index, inputs))
+        })
+        .collect()
 }
 
 impl From<KeccakBuiltinRunner> for BuiltinRunner {
@@ -389,6 +590,24 @@ impl From<KeccakBuiltinRunner> for BuiltinRunner {
     }
 }
 
+impl From<PoseidonBuiltinRunner> for BuiltinRunner {
+    fn from(runner: PoseidonBuiltinRunner) -> Self {
+        BuiltinRunner::Poseidon(runner)
+    }
+}
+
+impl From<SegmentArenaBuiltinRunner> for BuiltinRunner {
+    fn from(runner: SegmentArenaBuiltinRunner) -> Self {
+        BuiltinRunner::SegmentArena(runner)
+    }
+}
+
+impl From<GasBuiltinRunner> for BuiltinRunner {
+    fn from(runner: GasBuiltinRunner) -> Self {
+        BuiltinRunner::Gas(runner)
+    }
+}
+
 impl From<BitwiseBuiltinRunner> for BuiltinRunner {
     fn from(runner: BitwiseBuiltinRunner) -> Self {
         BuiltinRunner::Bitwise(runner)
@@ -1222,6 +1441,20 @@ mod tests {
         assert_eq!(keccak_builtin.ratio(), (Some(2048)),);
     }
 
+    #[test]
+    fn set_ratio() {
+        let mut keccak_builtin: BuiltinRunner =
+            KeccakBuiltinRunner::new(&KeccakInstanceDef::default(), true).into();
+        keccak_builtin.set_ratio(64);
+        assert_eq!(keccak_builtin.ratio(), Some(64));
+        assert_eq!(keccak_builtin.instances_per_component(), Some(16));
+
+        let mut output_builtin: BuiltinRunner = OutputBuiltinRunner::new(true).into();
+        output_builtin.set_ratio(64);
+        assert_eq!(output_builtin.ratio(), None);
+        assert_eq!(output_builtin.instances_per_component(), None);
+    }
+
     #[test]
     fn bitwise_get_used_instances_test() {
         let mut vm = vm!();
@@ -1,6 +1,8 @@
+use crate::stdlib::prelude::*;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::cairo_pie::{BuiltinAdditionalData, OutputBuiltinAdditionalData};
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
@@ -61,6 +63,22 @@ impl OutputBuiltinRunner {
         ("output", (self.base, self.stop_ptr))
     }
 
+    // Output pages aren't supported yet, so there's no data to export and the pages of an
+    // imported PIE are dropped.
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        BuiltinAdditionalData::Output(OutputBuiltinAdditionalData::default())
+    }
+
+    pub fn extend_additional_data(
+        &self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Output(_) => Ok(()),
+            _ => Err(RunnerError::InvalidAdditionalData("output")),
+        }
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -111,8 +129,7 @@ impl OutputBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 }
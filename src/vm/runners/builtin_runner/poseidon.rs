@@ -0,0 +1,345 @@
+use crate::stdlib::prelude::*;
+use crate::{
+    air_private_input::{PrivateInput, PrivateInputPoseidonState},
+    math_utils::safe_div_usize,
+    poseidon_hash::hades_permutation,
+    types::{
+        instance_definitions::poseidon_instance_def::{
+            PoseidonInstanceDef, CELLS_PER_POSEIDON, INPUT_CELLS_PER_POSEIDON,
+        },
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::builtin_runner::get_instance_inputs,
+        vm_core::VirtualMachine,
+        vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    },
+};
+use num_integer::div_ceil;
+
+#[derive(Debug, Clone)]
+pub struct PoseidonBuiltinRunner {
+    pub(crate) ratio: u32,
+    pub base: isize,
+    pub(crate) cells_per_instance: u32,
+    pub(crate) n_input_cells: u32,
+    pub(crate) stop_ptr: Option<usize>,
+    pub(crate) _included: bool,
+    pub(crate) instances_per_component: u32,
+}
+
+impl PoseidonBuiltinRunner {
+    pub(crate) fn new(instance_def: &PoseidonInstanceDef, included: bool) -> Self {
+        PoseidonBuiltinRunner {
+            base: 0,
+            ratio: instance_def.ratio,
+            cells_per_instance: CELLS_PER_POSEIDON,
+            n_input_cells: INPUT_CELLS_PER_POSEIDON,
+            stop_ptr: None,
+            _included: included,
+            instances_per_component: 1,
+        }
+    }
+
+    pub fn initialize_segments(
+        &mut self,
+        segments: &mut MemorySegmentManager,
+        memory: &mut Memory,
+    ) {
+        self.base = segments.add(memory).segment_index
+    }
+
+    pub fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        if self._included {
+            vec![MaybeRelocatable::from((self.base, 0))]
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn base(&self) -> isize {
+        self.base
+    }
+
+    pub fn ratio(&self) -> u32 {
+        self.ratio
+    }
+
+    pub fn add_validation_rule(&self, _memory: &mut Memory) -> Result<(), RunnerError> {
+        Ok(())
+    }
+
+    /// Deduces an output cell of an instance, which holds the element of the input state at the
+    /// same position after the Hades permutation.
+    pub fn deduce_memory_cell(
+        &self,
+        address: &Relocatable,
+        memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        let index = address.offset % self.cells_per_instance as usize;
+        if index < self.n_input_cells as usize {
+            return Ok(None);
+        }
+        let first_input_addr = Relocatable::from((address.segment_index, address.offset - index));
+
+        let mut state = Vec::with_capacity(self.n_input_cells as usize);
+        for i in 0..self.n_input_cells as usize {
+            match memory.get(&(first_input_addr + i)) {
+                Ok(Some(value)) => match value.as_ref() {
+                    MaybeRelocatable::Int(value) => state.push(value.clone()),
+                    MaybeRelocatable::RelocatableValue(_) => return Err(RunnerError::FoundNonInt),
+                },
+                _ => return Ok(None),
+            }
+        }
+        let mut state: [_; 3] = state
+            .try_into()
+            .map_err(|_| RunnerError::SliceToArrayError)?;
+        hades_permutation(&mut state);
+
+        let output_index = index - self.n_input_cells as usize;
+        Ok(Some(MaybeRelocatable::from(state[output_index].clone())))
+    }
+
+    pub fn get_allocated_memory_units(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let value = safe_div_usize(vm.current_step, self.ratio as usize)
+            .map_err(|_| MemoryError::ErrorCalculatingMemoryUnits)?;
+        Ok(self.cells_per_instance as usize * value)
+    }
+
+    pub fn get_memory_segment_addresses(&self) -> (&'static str, (isize, Option<usize>)) {
+        ("poseidon", (self.base, self.stop_ptr))
+    }
+
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::PoseidonState(PrivateInputPoseidonState {
+                index,
+                input_s0: inputs[0].clone(),
+                input_s1: inputs[1].clone(),
+                input_s2: inputs[2].clone(),
+            })
+        })
+        .collect()
+    }
+
+    pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let base = self.base();
+        vm.segments
+            .get_segment_used_size(
+                base.try_into()
+                    .map_err(|_| MemoryError::AddressInTemporarySegment(base))?,
+            )
+            .ok_or(MemoryError::MissingSegmentUsedSizes)
+    }
+
+    pub fn get_used_cells_and_allocated_size(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        let ratio = self.ratio as usize;
+        let min_step = ratio * self.instances_per_component as usize;
+        if vm.current_step < min_step {
+            Err(MemoryError::InsufficientAllocatedCells)
+        } else {
+            let used = self.get_used_cells(vm)?;
+            let size = self.cells_per_instance as usize
+                * safe_div_usize(vm.current_step, ratio)
+                    .map_err(|_| MemoryError::InsufficientAllocatedCells)?;
+            if used > size {
+                return Err(MemoryError::InsufficientAllocatedCells);
+            }
+            Ok((used, size))
+        }
+    }
+
+    pub fn final_stack(
+        &self,
+        vm: &VirtualMachine,
+        pointer: Relocatable,
+    ) -> Result<(Relocatable, usize), RunnerError> {
+        if self._included {
+            if let Ok(stop_pointer) =
+                vm.get_relocatable(&(pointer.sub_usize(1)).map_err(|_| RunnerError::FinalStack)?)
+            {
+                if self.base() != stop_pointer.segment_index {
+                    return Err(RunnerError::InvalidStopPointer("poseidon".to_string()));
+                }
+                let stop_ptr = stop_pointer.offset;
+                let num_instances = self
+                    .get_used_instances(vm)
+                    .map_err(|_| RunnerError::FinalStack)?;
+                let used_cells = num_instances * self.cells_per_instance as usize;
+                if stop_ptr != used_cells {
+                    return Err(RunnerError::InvalidStopPointer("poseidon".to_string()));
+                }
+                Ok((
+                    pointer.sub_usize(1).map_err(|_| RunnerError::FinalStack)?,
+                    stop_ptr,
+                ))
+            } else {
+                Err(RunnerError::FinalStack)
+            }
+        } else {
+            Ok((pointer, 0))
+        }
+    }
+
+    pub fn get_used_instances(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let used_cells = self.get_used_cells(vm)?;
+        Ok(div_ceil(used_cells, self.cells_per_instance as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+    use felt::{Felt, FeltOps, NewFelt};
+
+    fn felt_hex(hex: &str) -> Felt {
+        Felt::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    #[test]
+    fn get_used_instances() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![7]);
+        assert_eq!(builtin.get_used_instances(&vm), Ok(2));
+    }
+
+    #[test]
+    fn final_stack() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+        let mut vm = vm!();
+        vm.memory = memory![((2, 0), (0, 6))];
+        vm.segments.segment_used_sizes = Some(vec![6]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Ok((Relocatable::from((2, 0)), 6))
+        );
+    }
+
+    #[test]
+    fn final_stack_error_stop_pointer() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), true);
+        let mut vm = vm!();
+        vm.memory = memory![((2, 0), (0, 3))];
+        vm.segments.segment_used_sizes = Some(vec![6]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Err(RunnerError::InvalidStopPointer("poseidon".to_string()))
+        );
+    }
+
+    #[test]
+    fn final_stack_not_included() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(10), false);
+        let vm = vm!();
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Ok((Relocatable::from((2, 1)), 0))
+        );
+    }
+
+    #[test]
+    fn get_allocated_memory_units() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(8), true);
+        let mut vm = vm!();
+        vm.current_step = 32;
+        assert_eq!(builtin.get_allocated_memory_units(&vm), Ok(24));
+    }
+
+    #[test]
+    fn get_used_cells_and_allocated_size_insufficient_steps() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(8), true);
+        let mut vm = vm!();
+        vm.current_step = 4;
+        vm.segments.segment_used_sizes = Some(vec![0]);
+        assert_eq!(
+            builtin.get_used_cells_and_allocated_size(&vm),
+            Err(MemoryError::InsufficientAllocatedCells)
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell() {
+        let memory = memory![((0, 6), 1), ((0, 7), 2), ((0, 8), 2)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((0, 9)), &memory),
+            Ok(Some(MaybeRelocatable::from(felt_hex(
+                "5d44a3decb2b2e0cc71071f7b802f45dd792d064f0fc7316c46514f70f9891a"
+            ))))
+        );
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((0, 11)), &memory),
+            Ok(Some(MaybeRelocatable::from(felt_hex(
+                "68163dd4c74a3fd7cdca0cdcb80ea7b4a55b3b9f18b0b57698fbd8e5c0623c8"
+            ))))
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_input_cell() {
+        let memory = memory![((0, 0), 1), ((0, 1), 2), ((0, 2), 2)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((0, 2)), &memory),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_missing_input() {
+        let memory = memory![((0, 0), 1), ((0, 2), 2)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((0, 3)), &memory),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn deduce_memory_cell_relocatable_input() {
+        let memory = memory![((0, 0), 1), ((0, 1), (1, 0)), ((0, 2), 2)];
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        assert_eq!(
+            builtin.deduce_memory_cell(&Relocatable::from((0, 3)), &memory),
+            Err(RunnerError::FoundNonInt)
+        );
+    }
+
+    #[test]
+    fn get_memory_segment_addresses() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        assert_eq!(
+            builtin.get_memory_segment_addresses(),
+            ("poseidon", (0, None))
+        );
+    }
+
+    #[test]
+    fn air_private_input() {
+        let builtin = PoseidonBuiltinRunner::new(&PoseidonInstanceDef::default(), true);
+        let memory = memory![((0, 0), 1), ((0, 1), 2), ((0, 2), 3), ((0, 6), 4)];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![PrivateInput::PoseidonState(PrivateInputPoseidonState {
+                index: 0,
+                input_s0: Felt::new(1),
+                input_s1: Felt::new(2),
+                input_s2: Felt::new(3),
+            })]
+        );
+    }
+}
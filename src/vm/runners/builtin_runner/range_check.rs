@@ -1,4 +1,10 @@
+use crate::stdlib::{
+    cmp::{max, min},
+    ops::Shl,
+    prelude::*,
+};
 use crate::{
+    air_private_input::{PrivateInput, PrivateInputValue},
     math_utils::safe_div_usize,
     types::{
         instance_definitions::range_check_instance_def::CELLS_PER_RANGE_CHECK,
@@ -6,6 +12,7 @@ use crate::{
     },
     vm::{
         errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::builtin_runner::get_instance_inputs,
         vm_core::VirtualMachine,
         vm_memory::{
             memory::{Memory, ValidationRule},
@@ -16,14 +23,10 @@ use crate::{
 use felt::{Felt, NewFelt};
 use num_integer::Integer;
 use num_traits::{One, ToPrimitive, Zero};
-use std::{
-    cmp::{max, min},
-    ops::Shl,
-};
 
 #[derive(Debug, Clone)]
 pub struct RangeCheckBuiltinRunner {
-    ratio: u32,
+    pub(crate) ratio: u32,
     base: isize,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) cells_per_instance: u32,
@@ -32,7 +35,7 @@ pub struct RangeCheckBuiltinRunner {
     pub _bound: Option<Felt>,
     pub(crate) _included: bool,
     n_parts: u32,
-    instances_per_component: u32,
+    pub(crate) instances_per_component: u32,
 }
 
 impl RangeCheckBuiltinRunner {
@@ -131,6 +134,23 @@ impl RangeCheckBuiltinRunner {
         ("range_check", (self.base, self.stop_ptr))
     }
 
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .map(|(index, inputs)| {
+            PrivateInput::Value(PrivateInputValue {
+                index,
+                value: inputs[0].clone(),
+            })
+        })
+        .collect()
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -222,8 +242,7 @@ impl RangeCheckBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 
@@ -578,6 +597,25 @@ mod tests {
         assert_eq!(builtin.get_range_check_usage(&memory), Some((10480, 42341)));
     }
 
+    #[test]
+    fn air_private_input() {
+        let builtin = RangeCheckBuiltinRunner::new(8, 8, true);
+        let memory = memory![((0, 0), 1), ((0, 1), 2)];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![
+                PrivateInput::Value(PrivateInputValue {
+                    index: 0,
+                    value: Felt::new(1),
+                }),
+                PrivateInput::Value(PrivateInputValue {
+                    index: 1,
+                    value: Felt::new(2),
+                }),
+            ]
+        );
+    }
+
     #[test]
     fn get_range_check_empty_memory() {
         let builtin = RangeCheckBuiltinRunner::new(8, 8, true);
@@ -0,0 +1,300 @@
+use crate::stdlib::prelude::*;
+use crate::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{
+        errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        vm_core::VirtualMachine,
+        vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
+    },
+};
+use felt::{Felt, NewFelt};
+use num_integer::div_ceil;
+use num_traits::ToPrimitive;
+
+// Each instance holds a pointer to the info segment, the number of segments allocated so far
+// and the number of them that were finalized.
+pub(crate) const ARENA_BUILTIN_SIZE: u32 = 3;
+
+/// The segment arena of Cairo 1 programs, which tracks the segments allocated for dictionaries.
+/// Its segment starts with an instance pointing to an empty info segment, and the program
+/// appends an instance each time it allocates or finalizes a segment. The info segment holds
+/// the start, end and finalization index of each allocated segment.
+#[derive(Debug, Clone)]
+pub struct SegmentArenaBuiltinRunner {
+    base: isize,
+    pub(crate) cells_per_instance: u32,
+    pub(crate) n_input_cells: u32,
+    pub(crate) stop_ptr: Option<usize>,
+    pub(crate) _included: bool,
+}
+
+impl SegmentArenaBuiltinRunner {
+    pub fn new(included: bool) -> Self {
+        SegmentArenaBuiltinRunner {
+            base: 0,
+            cells_per_instance: ARENA_BUILTIN_SIZE,
+            n_input_cells: ARENA_BUILTIN_SIZE,
+            stop_ptr: None,
+            _included: included,
+        }
+    }
+
+    pub fn initialize_segments(
+        &mut self,
+        segments: &mut MemorySegmentManager,
+        memory: &mut Memory,
+    ) {
+        let info = segments.add(memory);
+        let arena = segments.add(memory);
+        let initial_instance = [
+            MaybeRelocatable::from(info),
+            MaybeRelocatable::from(Felt::new(0)),
+            MaybeRelocatable::from(Felt::new(0)),
+        ];
+        for (offset, value) in initial_instance.into_iter().enumerate() {
+            // The arena segment was just added, so these cells are free.
+            let _ = memory.insert_value(&(arena + offset), value);
+        }
+        self.base = arena.segment_index;
+    }
+
+    // The program receives a pointer right after the initial instance.
+    pub fn initial_stack(&self) -> Vec<MaybeRelocatable> {
+        if self._included {
+            vec![MaybeRelocatable::from((
+                self.base,
+                ARENA_BUILTIN_SIZE as usize,
+            ))]
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn base(&self) -> isize {
+        self.base
+    }
+
+    pub fn add_validation_rule(&self, _memory: &mut Memory) -> Result<(), RunnerError> {
+        Ok(())
+    }
+
+    pub fn deduce_memory_cell(
+        &self,
+        _address: &Relocatable,
+        _memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        Ok(None)
+    }
+
+    pub fn get_allocated_memory_units(&self, _vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        Ok(0)
+    }
+
+    pub fn get_memory_segment_addresses(&self) -> (&'static str, (isize, Option<usize>)) {
+        ("segment_arena", (self.base, self.stop_ptr))
+    }
+
+    pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let base = self.base();
+        vm.segments
+            .get_segment_used_size(
+                base.try_into()
+                    .map_err(|_| MemoryError::AddressInTemporarySegment(base))?,
+            )
+            .ok_or(MemoryError::MissingSegmentUsedSizes)
+    }
+
+    pub fn get_used_cells_and_allocated_size(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<(usize, usize), MemoryError> {
+        let used = self.get_used_cells(vm)?;
+        Ok((used, used))
+    }
+
+    // The initial instance isn't counted.
+    pub fn get_used_instances(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
+        let used_cells = self.get_used_cells(vm)?;
+        Ok(div_ceil(
+            used_cells.saturating_sub(ARENA_BUILTIN_SIZE as usize),
+            self.cells_per_instance as usize,
+        ))
+    }
+
+    pub fn final_stack(
+        &self,
+        vm: &VirtualMachine,
+        pointer: Relocatable,
+    ) -> Result<(Relocatable, usize), RunnerError> {
+        if self._included {
+            let stop_pointer_addr = pointer.sub_usize(1).map_err(|_| RunnerError::FinalStack)?;
+            let stop_pointer = vm
+                .get_relocatable(&stop_pointer_addr)
+                .map_err(|_| RunnerError::FinalStack)?;
+            if self.base() != stop_pointer.segment_index {
+                return Err(RunnerError::InvalidStopPointer("segment_arena".to_string()));
+            }
+            let stop_ptr = stop_pointer.offset;
+            let used = self
+                .get_used_cells(vm)
+                .map_err(|_| RunnerError::FinalStack)?;
+            if stop_ptr != used {
+                return Err(RunnerError::InvalidStopPointer("segment_arena".to_string()));
+            }
+            self.validate_segments_finalized(vm, stop_pointer)?;
+            Ok((stop_pointer_addr, stop_ptr))
+        } else {
+            Ok((pointer, 0))
+        }
+    }
+
+    // Checks that the last instance reports every allocated segment as finalized, and that
+    // the end of each of them was written to the info segment.
+    fn validate_segments_finalized(
+        &self,
+        vm: &VirtualMachine,
+        stop_pointer: Relocatable,
+    ) -> Result<(), RunnerError> {
+        let last_instance = stop_pointer
+            .sub_usize(ARENA_BUILTIN_SIZE as usize)
+            .map_err(|_| RunnerError::FinalStack)?;
+        let get_usize = |addr: Relocatable| {
+            vm.get_integer(&addr)
+                .ok()
+                .and_then(|value| value.to_usize())
+                .ok_or(RunnerError::FinalStack)
+        };
+        let info = vm
+            .get_relocatable(&last_instance)
+            .map_err(|_| RunnerError::FinalStack)?;
+        let n_segments = get_usize(last_instance + 1_usize)?;
+        let n_finalized = get_usize(last_instance + 2_usize)?;
+        if n_segments != n_finalized {
+            return Err(RunnerError::SegmentArenaNotFinalized(
+                n_segments,
+                n_finalized,
+            ));
+        }
+        for index in 0..n_segments {
+            let end_addr = info + (index * ARENA_BUILTIN_SIZE as usize + 1);
+            if !matches!(vm.get_maybe(&end_addr), Ok(Some(_))) {
+                return Err(RunnerError::SegmentArenaSegmentNotFinalized(index));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for SegmentArenaBuiltinRunner {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    #[test]
+    fn initialize_segments_writes_initial_instance() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        let mut vm = vm!();
+        builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        assert_eq!(builtin.base(), 1);
+        check_memory![vm.memory, ((1, 0), (0, 0)), ((1, 1), 0), ((1, 2), 0)];
+        assert_eq!(
+            builtin.initial_stack(),
+            vec![MaybeRelocatable::from((1, 3))]
+        );
+    }
+
+    #[test]
+    fn initial_stack_not_included() {
+        let builtin = SegmentArenaBuiltinRunner::new(false);
+        assert_eq!(builtin.initial_stack(), Vec::new());
+    }
+
+    #[test]
+    fn final_stack() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        builtin.base = 1;
+        let mut vm = vm!();
+        // One segment was allocated, at (3, 0), and finalized.
+        vm.memory = memory![
+            ((0, 0), (3, 0)),
+            ((0, 1), (3, 4)),
+            ((0, 2), 0),
+            ((1, 0), (0, 0)),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), (0, 0)),
+            ((1, 4), 1),
+            ((1, 5), 0),
+            ((1, 6), (0, 0)),
+            ((1, 7), 1),
+            ((1, 8), 1),
+            ((2, 0), (1, 9))
+        ];
+        vm.segments.segment_used_sizes = Some(vec![3, 9]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Ok((Relocatable::from((2, 0)), 9))
+        );
+        assert_eq!(builtin.get_used_instances(&vm), Ok(2));
+    }
+
+    #[test]
+    fn final_stack_segment_not_finalized() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        builtin.base = 1;
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((0, 0), (3, 0)),
+            ((1, 0), (0, 0)),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), (0, 0)),
+            ((1, 4), 1),
+            ((1, 5), 0),
+            ((2, 0), (1, 6))
+        ];
+        vm.segments.segment_used_sizes = Some(vec![1, 6]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Err(RunnerError::SegmentArenaNotFinalized(1, 0))
+        );
+    }
+
+    #[test]
+    fn final_stack_missing_segment_end() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        builtin.base = 1;
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((0, 0), (3, 0)),
+            ((1, 0), (0, 0)),
+            ((1, 1), 1),
+            ((1, 2), 1),
+            ((2, 0), (1, 3))
+        ];
+        vm.segments.segment_used_sizes = Some(vec![1, 3]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Err(RunnerError::SegmentArenaSegmentNotFinalized(0))
+        );
+    }
+
+    #[test]
+    fn final_stack_invalid_stop_pointer() {
+        let mut builtin = SegmentArenaBuiltinRunner::new(true);
+        builtin.base = 1;
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (0, 0)), ((1, 1), 0), ((1, 2), 0), ((2, 0), (1, 0))];
+        vm.segments.segment_used_sizes = Some(vec![0, 3]);
+        assert_eq!(
+            builtin.final_stack(&vm, Relocatable::from((2, 1))),
+            Err(RunnerError::InvalidStopPointer("segment_arena".to_string()))
+        );
+    }
+}
@@ -1,11 +1,19 @@
+use crate::stdlib::{
+    any::Any,
+    collections::HashMap,
+    prelude::*,
+    sync::{self, Arc, Mutex},
+};
 use crate::{
-    math_utils::safe_div_usize,
+    air_private_input::{PrivateInput, PrivateInputSignature, SignatureInput},
+    math_utils::{div_mod, safe_div_usize},
     types::{
         instance_definitions::ecdsa_instance_def::EcdsaInstanceDef,
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{
         errors::{memory_errors::MemoryError, runner_errors::RunnerError},
+        runners::{builtin_runner::get_instance_inputs, cairo_pie::BuiltinAdditionalData},
         vm_core::VirtualMachine,
         vm_memory::{
             memory::{Memory, ValidationRule},
@@ -14,22 +22,22 @@ use crate::{
     },
 };
 use felt::{Felt, FeltOps};
+use num_bigint::{BigInt, Sign};
 use num_integer::{div_ceil, Integer};
-use num_traits::ToPrimitive;
+use num_traits::{Num, One, ToPrimitive};
 use starknet_crypto::{verify, FieldElement, Signature};
-use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 #[derive(Debug, Clone)]
 pub struct SignatureBuiltinRunner {
     included: bool,
-    ratio: u32,
+    pub(crate) ratio: u32,
     base: isize,
     pub(crate) cells_per_instance: u32,
     pub(crate) n_input_cells: u32,
     _total_n_bits: u32,
     pub(crate) stop_ptr: Option<usize>,
-    instances_per_component: u32,
-    signatures: Rc<RefCell<HashMap<Relocatable, Signature>>>,
+    pub(crate) instances_per_component: u32,
+    signatures: Arc<Mutex<HashMap<Relocatable, Signature>>>,
 }
 
 impl SignatureBuiltinRunner {
@@ -43,7 +51,7 @@ impl SignatureBuiltinRunner {
             _total_n_bits: 251,
             stop_ptr: None,
             instances_per_component: 1,
-            signatures: Rc::new(RefCell::new(HashMap::new())),
+            signatures: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -66,13 +74,44 @@ impl SignatureBuiltinRunner {
             s: s_felt,
         };
 
-        self.signatures
-            .borrow_mut()
+        sync::lock(&self.signatures)
             .entry(relocatable)
             .or_insert(signature);
 
         Ok(())
     }
+
+    pub fn get_additional_data(&self) -> BuiltinAdditionalData {
+        let mut signatures: Vec<_> = sync::lock(&self.signatures)
+            .iter()
+            .map(|(address, signature)| {
+                (
+                    *address,
+                    (
+                        Felt::from_bytes_be(&signature.r.to_bytes_be()),
+                        Felt::from_bytes_be(&signature.s.to_bytes_be()),
+                    ),
+                )
+            })
+            .collect();
+        signatures.sort_by_key(|(address, _)| (address.segment_index, address.offset));
+        BuiltinAdditionalData::Signature(signatures)
+    }
+
+    pub fn extend_additional_data(
+        &mut self,
+        additional_data: &BuiltinAdditionalData,
+    ) -> Result<(), RunnerError> {
+        match additional_data {
+            BuiltinAdditionalData::Signature(signatures) => {
+                for (address, signature) in signatures {
+                    self.add_signature(*address, signature)?;
+                }
+                Ok(())
+            }
+            _ => Err(RunnerError::InvalidAdditionalData("ecdsa")),
+        }
+    }
 }
 
 impl SignatureBuiltinRunner {
@@ -97,7 +136,7 @@ impl SignatureBuiltinRunner {
     }
     pub fn add_validation_rule(&self, memory: &mut Memory) -> Result<(), RunnerError> {
         let cells_per_instance = self.cells_per_instance;
-        let signatures = Rc::clone(&self.signatures);
+        let signatures = Arc::clone(&self.signatures);
         let rule: ValidationRule = ValidationRule(Box::new(
             move |memory: &Memory,
                   address: &MaybeRelocatable|
@@ -140,7 +179,7 @@ impl SignatureBuiltinRunner {
                 let pub_key = memory
                     .get_integer(&pubkey_addr)
                     .map_err(|_| MemoryError::FoundNonInt)?;
-                let signatures_map = signatures.borrow();
+                let signatures_map = sync::lock(&signatures);
                 let signature = signatures_map
                     .get(&pubkey_addr)
                     .ok_or(MemoryError::SignatureNotFound)?;
@@ -193,6 +232,40 @@ impl SignatureBuiltinRunner {
         ("ecdsa", (self.base, self.stop_ptr))
     }
 
+    /// Returns the signed messages along with their signatures, with `w` being the inverse of `s`
+    /// modulo the order of the curve. Instances without a signature are skipped.
+    pub fn air_private_input(&self, memory: &Memory) -> Vec<PrivateInput> {
+        let ec_order = BigInt::from_str_radix(
+            "800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+            16,
+        )
+        .unwrap();
+        let signatures = sync::lock(&self.signatures);
+        get_instance_inputs(
+            memory,
+            self.base,
+            self.cells_per_instance,
+            self.n_input_cells,
+        )
+        .into_iter()
+        .filter_map(|(index, inputs)| {
+            let address = Relocatable::from((self.base, index * self.cells_per_instance as usize));
+            let signature = signatures.get(&address)?;
+            let s = BigInt::from_bytes_be(Sign::Plus, &signature.s.to_bytes_be());
+            let w = div_mod(&BigInt::one(), &s, &ec_order);
+            Some(PrivateInput::Signature(PrivateInputSignature {
+                index,
+                pubkey: inputs[0].clone(),
+                msg: inputs[1].clone(),
+                signature_input: SignatureInput {
+                    r: Felt::from_bytes_be(&signature.r.to_bytes_be()),
+                    w: Felt::from_bytes_be(&w.to_bytes_be().1),
+                },
+            }))
+        })
+        .collect()
+    }
+
     pub fn get_used_cells(&self, vm: &VirtualMachine) -> Result<usize, MemoryError> {
         let base = self.base();
         vm.segments
@@ -258,8 +331,7 @@ impl SignatureBuiltinRunner {
                 Err(RunnerError::FinalStack)
             }
         } else {
-            let stop_ptr = self.base() as usize;
-            Ok((pointer, stop_ptr))
+            Ok((pointer, 0))
         }
     }
 }
@@ -277,6 +349,7 @@ mod tests {
             vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
         },
     };
+    use felt::NewFelt;
 
     #[test]
     fn initialize_segments_for_ecdsa() {
@@ -490,4 +563,45 @@ mod tests {
         let result = builtin.deduce_memory_cell(&Relocatable::from((0, 5)), &memory);
         assert_eq!(result, Ok(None));
     }
+
+    #[test]
+    fn get_and_extend_additional_data() {
+        let mut builtin = SignatureBuiltinRunner::new(&EcdsaInstanceDef::default(), true);
+        let signatures = vec![
+            (Relocatable::from((4, 0)), (Felt::new(3), Felt::new(4))),
+            (Relocatable::from((4, 2)), (Felt::new(5), Felt::new(6))),
+        ];
+        builtin
+            .extend_additional_data(&BuiltinAdditionalData::Signature(signatures.clone()))
+            .unwrap();
+        assert_eq!(
+            builtin.get_additional_data(),
+            BuiltinAdditionalData::Signature(signatures)
+        );
+        assert_eq!(
+            builtin.extend_additional_data(&BuiltinAdditionalData::Hash(Vec::new())),
+            Err(RunnerError::InvalidAdditionalData("ecdsa"))
+        );
+    }
+
+    #[test]
+    fn air_private_input() {
+        let mut builtin = SignatureBuiltinRunner::new(&EcdsaInstanceDef::default(), true);
+        builtin
+            .add_signature(Relocatable::from((0, 0)), &(Felt::new(3), Felt::new(1)))
+            .unwrap();
+        let memory = memory![((0, 0), 7), ((0, 1), 8), ((0, 2), 9), ((0, 3), 10)];
+        assert_eq!(
+            builtin.air_private_input(&memory),
+            vec![PrivateInput::Signature(PrivateInputSignature {
+                index: 0,
+                pubkey: Felt::new(7),
+                msg: Felt::new(8),
+                signature_input: SignatureInput {
+                    r: Felt::new(3),
+                    w: Felt::new(1),
+                },
+            })]
+        );
+    }
 }
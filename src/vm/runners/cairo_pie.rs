@@ -0,0 +1,621 @@
+use crate::stdlib::{collections::HashMap, prelude::*, str::FromStr};
+use crate::{
+    serde::deserialize_program::deserialize_array_of_bigint_hex,
+    types::{
+        program::Program,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{
+        errors::{cairo_pie_errors::CairoPieError, memory_errors::MemoryError},
+        runners::cairo_runner::{ExecutionResources, SegmentInfo},
+    },
+};
+use felt::{Felt, FeltOps};
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive};
+use serde::{ser, Deserialize, Serialize, Serializer};
+use serde_json::{json, Number, Value};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, Write},
+    path::Path,
+};
+#[cfg(feature = "std")]
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+pub const CAIRO_PIE_VERSION: &str = "1.1";
+
+// Sizes of the encoded addresses and values in memory.bin
+const ADDR_BYTE_LEN: usize = 8;
+const FIELD_BYTE_LEN: usize = 32;
+// An encoded relocatable is 2^(8 * byte_len - 1) + segment_index * 2^OFFSET_BITS + offset,
+// whatever the size of the value it is encoded in, like cairo-lang does.
+const OFFSET_BITS: usize = 47;
+
+/// Memory cells of a Cairo PIE, sorted by address.
+pub type CairoPieMemory = Vec<((usize, usize), MaybeRelocatable)>;
+
+/// The part of a `Program` needed to run it: its bytecode, builtins and entrypoint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrippedProgram {
+    #[serde(
+        serialize_with = "serialize_array_of_bigint_hex",
+        deserialize_with = "deserialize_array_of_bigint_hex"
+    )]
+    pub data: Vec<MaybeRelocatable>,
+    pub builtins: Vec<String>,
+    pub main: usize,
+    pub prime: String,
+}
+
+impl From<&StrippedProgram> for Program {
+    fn from(program: &StrippedProgram) -> Self {
+        Program {
+            builtins: program.builtins.clone(),
+            prime: program.prime.clone(),
+            data: program.data.clone(),
+            main: Some(program.main),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CairoPieMetadata {
+    pub program: StrippedProgram,
+    pub program_segment: SegmentInfo,
+    pub execution_segment: SegmentInfo,
+    pub ret_fp_segment: SegmentInfo,
+    pub ret_pc_segment: SegmentInfo,
+    pub builtin_segments: HashMap<String, SegmentInfo>,
+    pub extra_segments: Vec<SegmentInfo>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputBuiltinAdditionalData {
+    /// Start offset and size of each output page, by page id.
+    pub pages: HashMap<usize, (usize, usize)>,
+    pub attributes: HashMap<String, Vec<usize>>,
+}
+
+/// Builtin state that isn't stored in memory and is needed to run the PIE again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuiltinAdditionalData {
+    Output(OutputBuiltinAdditionalData),
+    /// Addresses of the hash results that were already checked.
+    Hash(Vec<Relocatable>),
+    /// Signatures added to the ecdsa builtin, along with the address of the public key they sign.
+    Signature(Vec<(Relocatable, (Felt, Felt))>),
+    None,
+}
+
+impl BuiltinAdditionalData {
+    /// Returns the data as stored in the `additional_data.json` file of a Cairo PIE.
+    pub fn to_json(&self) -> Result<Value, serde_json::Error> {
+        Ok(match self {
+            BuiltinAdditionalData::Output(data) => serde_json::to_value(data)?,
+            BuiltinAdditionalData::Hash(addresses) => {
+                addresses.iter().map(relocatable_to_json).collect()
+            }
+            BuiltinAdditionalData::Signature(signatures) => signatures
+                .iter()
+                .map(|(address, (r, s))| {
+                    Ok(json!([
+                        relocatable_to_json(address),
+                        [felt_to_json(r)?, felt_to_json(s)?]
+                    ]))
+                })
+                .collect::<Result<Value, serde_json::Error>>()?,
+            BuiltinAdditionalData::None => Value::Null,
+        })
+    }
+
+    /// Reads the data of a builtin from the `additional_data.json` file of a Cairo PIE. The data
+    /// has no tag of its own, its layout is given by the builtin it belongs to.
+    pub fn from_json(builtin_name: &str, value: Value) -> Result<Self, CairoPieError> {
+        match builtin_name {
+            "output_builtin" => Ok(BuiltinAdditionalData::Output(serde_json::from_value(
+                value,
+            )?)),
+            "pedersen_builtin" => {
+                let addresses: Vec<(isize, usize)> = serde_json::from_value(value)?;
+                Ok(BuiltinAdditionalData::Hash(
+                    addresses.into_iter().map(Relocatable::from).collect(),
+                ))
+            }
+            "ecdsa_builtin" => {
+                let signatures: Vec<((isize, usize), (Number, Number))> =
+                    serde_json::from_value(value)?;
+                signatures
+                    .into_iter()
+                    .map(
+                        |(address, (r, s))| match (felt_from_json(&r), felt_from_json(&s)) {
+                            (Some(r), Some(s)) => Ok((Relocatable::from(address), (r, s))),
+                            _ => Err(CairoPieError::InvalidAdditionalData(
+                                builtin_name.to_string(),
+                            )),
+                        },
+                    )
+                    .collect::<Result<_, _>>()
+                    .map(BuiltinAdditionalData::Signature)
+            }
+            _ => Ok(BuiltinAdditionalData::None),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CairoPieVersion {
+    pub cairo_pie: String,
+}
+
+impl Default for CairoPieVersion {
+    fn default() -> Self {
+        CairoPieVersion {
+            cairo_pie: CAIRO_PIE_VERSION.to_string(),
+        }
+    }
+}
+
+/// A run in a position independent form, which can be stored and run again later on.
+/// It uses the same zip file layout as the Python VM's Cairo PIEs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CairoPie {
+    pub metadata: CairoPieMetadata,
+    pub memory: CairoPieMemory,
+    pub execution_resources: ExecutionResources,
+    /// Additional data of each builtin, keyed by `<builtin name>_builtin`.
+    pub additional_data: HashMap<String, BuiltinAdditionalData>,
+    pub version: CairoPieVersion,
+}
+
+impl CairoPie {
+    #[cfg(feature = "std")]
+    pub fn write_zip_file(&self, path: &Path) -> Result<(), CairoPieError> {
+        self.write_zip(BufWriter::new(File::create(path)?))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_zip<W: Write + Seek>(&self, writer: W) -> Result<(), CairoPieError> {
+        let additional_data = self
+            .additional_data
+            .iter()
+            .map(|(name, data)| Ok((name, data.to_json()?)))
+            .collect::<Result<HashMap<_, _>, serde_json::Error>>()?;
+
+        let mut zip = ZipWriter::new(writer);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        zip.start_file("metadata.json", options)?;
+        serde_json::to_writer(&mut zip, &self.metadata)?;
+        zip.start_file("memory.bin", options)?;
+        zip.write_all(&serialize_memory(&self.memory)?)?;
+        zip.start_file("additional_data.json", options)?;
+        serde_json::to_writer(&mut zip, &additional_data)?;
+        zip.start_file("execution_resources.json", options)?;
+        serde_json::to_writer(&mut zip, &self.execution_resources)?;
+        zip.start_file("version.json", options)?;
+        serde_json::to_writer(&mut zip, &self.version)?;
+        zip.finish()?;
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn read_zip_file(path: &Path) -> Result<CairoPie, CairoPieError> {
+        CairoPie::read_zip(BufReader::new(File::open(path)?))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn read_zip<R: Read + Seek>(reader: R) -> Result<CairoPie, CairoPieError> {
+        let mut zip = ZipArchive::new(reader)?;
+
+        let metadata = serde_json::from_reader(zip.by_name("metadata.json")?)?;
+        let mut memory = Vec::new();
+        zip.by_name("memory.bin")?.read_to_end(&mut memory)?;
+        let additional_data: HashMap<String, Value> =
+            serde_json::from_reader(zip.by_name("additional_data.json")?)?;
+        let execution_resources =
+            serde_json::from_reader(zip.by_name("execution_resources.json")?)?;
+        let version = serde_json::from_reader(zip.by_name("version.json")?)?;
+
+        Ok(CairoPie {
+            metadata,
+            memory: deserialize_memory(&memory)?,
+            execution_resources,
+            additional_data: additional_data
+                .into_iter()
+                .map(|(name, value)| {
+                    let data = BuiltinAdditionalData::from_json(&name, value)?;
+                    Ok((name, data))
+                })
+                .collect::<Result<_, CairoPieError>>()?,
+            version,
+        })
+    }
+
+    /// Checks that `other` describes the same execution, reporting the first part that differs.
+    pub fn check_matches(&self, other: &CairoPie) -> Result<(), CairoPieError> {
+        if self.metadata != other.metadata {
+            return Err(CairoPieError::Mismatch("metadata"));
+        }
+        if self.memory != other.memory {
+            return Err(CairoPieError::Mismatch("memory"));
+        }
+        if self.additional_data != other.additional_data {
+            return Err(CairoPieError::Mismatch("additional data"));
+        }
+        if self.execution_resources != other.execution_resources {
+            return Err(CairoPieError::Mismatch("execution resources"));
+        }
+        Ok(())
+    }
+}
+
+fn serialize_array_of_bigint_hex<S: Serializer>(
+    data: &[MaybeRelocatable],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(data.iter().map(|value| match value {
+        MaybeRelocatable::Int(value) => Ok(format!("{:#x}", value.to_biguint())),
+        MaybeRelocatable::RelocatableValue(_) => Err(ser::Error::custom(
+            "program data can't hold relocatable values",
+        )),
+    }))
+}
+
+fn relocatable_to_json(address: &Relocatable) -> Value {
+    json!([address.segment_index, address.offset])
+}
+
+fn felt_to_json(value: &Felt) -> Result<Value, serde_json::Error> {
+    Ok(Value::Number(Number::from_str(&value.to_str_radix(10))?))
+}
+
+fn felt_from_json(number: &Number) -> Option<Felt> {
+    Felt::parse_bytes(number.to_string().as_bytes(), 10)
+}
+
+fn encode_relocatable(
+    relocatable: &Relocatable,
+    byte_len: usize,
+) -> Result<BigUint, CairoPieError> {
+    let segment_index = usize::try_from(relocatable.segment_index)
+        .map_err(|_| MemoryError::AddressInTemporarySegment(relocatable.segment_index))?;
+    // The offset gets OFFSET_BITS bits, and the segment index the ones below the highest bit.
+    let segment_bits = (8 * byte_len - 1 - OFFSET_BITS) as u32;
+    if relocatable.offset >> OFFSET_BITS != 0
+        || segment_index.checked_shr(segment_bits).unwrap_or(0) != 0
+    {
+        return Err(CairoPieError::RelocatableOutOfBounds(
+            *relocatable,
+            byte_len,
+        ));
+    }
+    Ok((BigUint::one() << (8 * byte_len - 1))
+        + (BigUint::from(segment_index) << OFFSET_BITS)
+        + BigUint::from(relocatable.offset))
+}
+
+// Relocatables are told apart from integers by their highest bit, see encode_relocatable.
+fn decode_relocatable(bytes: &[u8]) -> Option<Relocatable> {
+    if bytes[bytes.len() - 1] & 0x80 == 0 {
+        return None;
+    }
+    let value = BigUint::from_bytes_le(bytes) - (BigUint::one() << (8 * bytes.len() - 1));
+    let offset_mask = (BigUint::one() << OFFSET_BITS) - 1u32;
+    let segment_index = &value >> OFFSET_BITS;
+    let offset = value & offset_mask;
+    Some(Relocatable::from((
+        segment_index.to_isize()?,
+        offset.to_usize()?,
+    )))
+}
+
+fn to_bytes_le(value: &BigUint, byte_len: usize) -> Result<Vec<u8>, CairoPieError> {
+    let mut bytes = value.to_bytes_le();
+    if bytes.len() > byte_len {
+        return Err(CairoPieError::ValueOutOfBounds(value.clone(), byte_len));
+    }
+    bytes.resize(byte_len, 0);
+    Ok(bytes)
+}
+
+/// Encodes each cell as its address followed by its value, both as little-endian integers.
+pub fn serialize_memory(
+    memory: &[((usize, usize), MaybeRelocatable)],
+) -> Result<Vec<u8>, CairoPieError> {
+    let mut bytes = Vec::with_capacity(memory.len() * (ADDR_BYTE_LEN + FIELD_BYTE_LEN));
+    for ((segment_index, offset), value) in memory {
+        let address = Relocatable::from((*segment_index as isize, *offset));
+        bytes.extend(to_bytes_le(
+            &encode_relocatable(&address, ADDR_BYTE_LEN)?,
+            ADDR_BYTE_LEN,
+        )?);
+        let value = match value {
+            MaybeRelocatable::Int(value) => value.to_biguint(),
+            MaybeRelocatable::RelocatableValue(value) => encode_relocatable(value, FIELD_BYTE_LEN)?,
+        };
+        bytes.extend(to_bytes_le(&value, FIELD_BYTE_LEN)?);
+    }
+    Ok(bytes)
+}
+
+pub fn deserialize_memory(bytes: &[u8]) -> Result<CairoPieMemory, CairoPieError> {
+    if bytes.len() % (ADDR_BYTE_LEN + FIELD_BYTE_LEN) != 0 {
+        return Err(CairoPieError::InvalidMemoryFileSize(bytes.len()));
+    }
+    let mut memory = bytes
+        .chunks(ADDR_BYTE_LEN + FIELD_BYTE_LEN)
+        .map(|cell| {
+            let (address, value) = cell.split_at(ADDR_BYTE_LEN);
+            let address = decode_relocatable(address)
+                .and_then(|address| Some((address.segment_index.to_usize()?, address.offset)))
+                .ok_or_else(|| {
+                    CairoPieError::NonRelocatableMemoryAddress(u64::from_le_bytes(
+                        address.try_into().unwrap_or_default(),
+                    ))
+                })?;
+            let value = match decode_relocatable(value) {
+                Some(value) => MaybeRelocatable::from(value),
+                None => MaybeRelocatable::from(Felt::from(BigUint::from_bytes_le(value))),
+            };
+            Ok((address, value))
+        })
+        .collect::<Result<CairoPieMemory, CairoPieError>>()?;
+    memory.sort_by_key(|(address, _)| *address);
+    Ok(memory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{relocatable, utils::test_utils::*};
+    use felt::NewFelt;
+    use std::io::Cursor;
+
+    fn cairo_pie() -> CairoPie {
+        CairoPie {
+            metadata: CairoPieMetadata {
+                program: StrippedProgram {
+                    data: vec![
+                        mayberelocatable!(0x208b7fff7fff7ffe_i64),
+                        mayberelocatable!(7),
+                    ],
+                    builtins: vec![String::from("output")],
+                    main: 0,
+                    prime: felt::PRIME_STR.to_string(),
+                },
+                program_segment: SegmentInfo { index: 0, size: 2 },
+                execution_segment: SegmentInfo { index: 1, size: 4 },
+                ret_fp_segment: SegmentInfo { index: 3, size: 0 },
+                ret_pc_segment: SegmentInfo { index: 4, size: 0 },
+                builtin_segments: HashMap::from([(
+                    String::from("output"),
+                    SegmentInfo { index: 2, size: 1 },
+                )]),
+                extra_segments: vec![SegmentInfo { index: 5, size: 1 }],
+            },
+            memory: vec![
+                ((0, 0), mayberelocatable!(0x208b7fff7fff7ffe_i64)),
+                ((0, 1), mayberelocatable!(7)),
+                ((1, 0), mayberelocatable!(2, 0)),
+                ((1, 1), mayberelocatable!(3, 0)),
+                ((1, 2), mayberelocatable!(4, 0)),
+                ((1, 3), mayberelocatable!(2, 1)),
+                ((2, 0), mayberelocatable!(7)),
+                ((5, 0), mayberelocatable!(-1)),
+            ],
+            execution_resources: ExecutionResources {
+                n_steps: 3,
+                n_memory_holes: 0,
+                builtin_instance_counter: HashMap::from([(String::from("output_builtin"), 1)]),
+            },
+            additional_data: HashMap::from([
+                (
+                    String::from("output_builtin"),
+                    BuiltinAdditionalData::Output(OutputBuiltinAdditionalData::default()),
+                ),
+                (
+                    String::from("pedersen_builtin"),
+                    BuiltinAdditionalData::Hash(vec![relocatable!(6, 2)]),
+                ),
+                (
+                    String::from("ecdsa_builtin"),
+                    BuiltinAdditionalData::Signature(vec![(
+                        relocatable!(7, 0),
+                        (Felt::new(11), Felt::new(-12)),
+                    )]),
+                ),
+                (
+                    String::from("range_check_builtin"),
+                    BuiltinAdditionalData::None,
+                ),
+            ]),
+            version: CairoPieVersion::default(),
+        }
+    }
+
+    #[test]
+    fn serialize_memory_cells() {
+        let memory = vec![
+            ((1, 2), mayberelocatable!(3)),
+            ((1, 3), mayberelocatable!(4, 5)),
+        ];
+        let bytes = serialize_memory(&memory).unwrap();
+        assert_eq!(bytes.len(), 2 * (ADDR_BYTE_LEN + FIELD_BYTE_LEN));
+
+        // 2^63 + 1 * 2^47 + 2
+        assert_eq!(bytes[..8], [2, 0, 0, 0, 0, 0x80, 0, 0x80]);
+        let mut value = vec![0; FIELD_BYTE_LEN];
+        value[0] = 3;
+        assert_eq!(bytes[8..40], value);
+        // 2^255 + 4 * 2^47 + 5
+        let mut value = vec![0; FIELD_BYTE_LEN];
+        value[0] = 5;
+        value[6] = 2;
+        value[31] = 0x80;
+        assert_eq!(bytes[48..], value);
+
+        assert_eq!(deserialize_memory(&bytes).unwrap(), memory);
+    }
+
+    #[test]
+    fn serialize_memory_temporary_segment() {
+        let memory = vec![((1, 0), mayberelocatable!(-1, 0))];
+        assert!(matches!(
+            serialize_memory(&memory),
+            Err(CairoPieError::Memory(
+                MemoryError::AddressInTemporarySegment(-1)
+            ))
+        ));
+    }
+
+    #[test]
+    fn serialize_memory_offset_out_of_bounds() {
+        let memory = vec![((1, 0), mayberelocatable!(2, 1 << 47))];
+        assert!(matches!(
+            serialize_memory(&memory),
+            Err(CairoPieError::RelocatableOutOfBounds(relocatable, FIELD_BYTE_LEN))
+                if relocatable == Relocatable::from((2, 1 << 47))
+        ));
+    }
+
+    #[test]
+    fn serialize_memory_segment_index_out_of_bounds() {
+        // Addresses have 16 bits left for the segment index.
+        let memory = vec![((1 << 16, 0), mayberelocatable!(1))];
+        assert!(matches!(
+            serialize_memory(&memory),
+            Err(CairoPieError::RelocatableOutOfBounds(relocatable, ADDR_BYTE_LEN))
+                if relocatable == Relocatable::from((1 << 16, 0))
+        ));
+    }
+
+    #[test]
+    fn to_bytes_le_value_out_of_bounds() {
+        assert!(matches!(
+            to_bytes_le(&(BigUint::one() << 64), 8),
+            Err(CairoPieError::ValueOutOfBounds(_, 8))
+        ));
+    }
+
+    #[test]
+    fn deserialize_memory_sorts_cells() {
+        let memory = vec![
+            ((2, 0), mayberelocatable!(1)),
+            ((1, 5), mayberelocatable!(2)),
+        ];
+        let bytes = serialize_memory(&memory).unwrap();
+        assert_eq!(
+            deserialize_memory(&bytes).unwrap(),
+            vec![
+                ((1, 5), mayberelocatable!(2)),
+                ((2, 0), mayberelocatable!(1))
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_memory_invalid_size() {
+        assert!(matches!(
+            deserialize_memory(&[0; 41]),
+            Err(CairoPieError::InvalidMemoryFileSize(41))
+        ));
+    }
+
+    #[test]
+    fn deserialize_memory_non_relocatable_address() {
+        let mut bytes = vec![0; ADDR_BYTE_LEN + FIELD_BYTE_LEN];
+        bytes[0] = 7;
+        assert!(matches!(
+            deserialize_memory(&bytes),
+            Err(CairoPieError::NonRelocatableMemoryAddress(7))
+        ));
+    }
+
+    #[test]
+    fn additional_data_json() {
+        let signature = BuiltinAdditionalData::Signature(vec![(
+            relocatable!(3, 0),
+            (Felt::new(1), Felt::new(2)),
+        )]);
+        let value = signature.to_json().unwrap();
+        assert_eq!(value.to_string(), "[[[3,0],[1,2]]]");
+        assert_eq!(
+            BuiltinAdditionalData::from_json("ecdsa_builtin", value).unwrap(),
+            signature
+        );
+
+        let hash = BuiltinAdditionalData::Hash(vec![relocatable!(2, 2), relocatable!(2, 5)]);
+        let value = hash.to_json().unwrap();
+        assert_eq!(value.to_string(), "[[2,2],[2,5]]");
+        assert_eq!(
+            BuiltinAdditionalData::from_json("pedersen_builtin", value).unwrap(),
+            hash
+        );
+
+        assert_eq!(
+            BuiltinAdditionalData::from_json("ecdsa_builtin", json!([])).unwrap(),
+            BuiltinAdditionalData::Signature(Vec::new())
+        );
+        assert!(BuiltinAdditionalData::from_json("pedersen_builtin", json!({})).is_err());
+    }
+
+    #[test]
+    fn serialize_stripped_program() {
+        let program = cairo_pie().metadata.program;
+        let value = serde_json::to_value(&program).unwrap();
+        assert_eq!(value["data"], json!(["0x208b7fff7fff7ffe", "0x7"]));
+        assert_eq!(
+            serde_json::from_value::<StrippedProgram>(value).unwrap(),
+            program
+        );
+    }
+
+    #[test]
+    fn zip_round_trip() {
+        let cairo_pie = cairo_pie();
+        let mut buffer = Cursor::new(Vec::new());
+        cairo_pie.write_zip(&mut buffer).unwrap();
+        buffer.set_position(0);
+
+        let mut zip = ZipArchive::new(&mut buffer).unwrap();
+        let mut file_names: Vec<_> = zip.file_names().collect();
+        file_names.sort_unstable();
+        assert_eq!(
+            file_names,
+            [
+                "additional_data.json",
+                "execution_resources.json",
+                "memory.bin",
+                "metadata.json",
+                "version.json"
+            ]
+        );
+        let version: Value = serde_json::from_reader(zip.by_name("version.json").unwrap()).unwrap();
+        assert_eq!(version, json!({ "cairo_pie": "1.1" }));
+
+        buffer.set_position(0);
+        assert_eq!(CairoPie::read_zip(buffer).unwrap(), cairo_pie);
+    }
+
+    #[test]
+    fn check_matches() {
+        let cairo_pie = cairo_pie();
+        assert!(cairo_pie.check_matches(&cairo_pie).is_ok());
+
+        let mut other = cairo_pie.clone();
+        other.memory[1].1 = mayberelocatable!(8);
+        assert!(matches!(
+            cairo_pie.check_matches(&other),
+            Err(CairoPieError::Mismatch("memory"))
+        ));
+
+        let mut other = cairo_pie.clone();
+        other.execution_resources.n_steps = 4;
+        assert!(matches!(
+            cairo_pie.check_matches(&other),
+            Err(CairoPieError::Mismatch("execution resources"))
+        ));
+    }
+}
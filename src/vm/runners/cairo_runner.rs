@@ -1,4 +1,13 @@
+use crate::stdlib::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    prelude::*,
+    str::FromStr,
+};
 use crate::{
+    air_private_input::AirPrivateInput,
+    air_public_input::{DynamicParams, MemorySegmentAddresses, PublicInput},
     hint_processor::hint_processor_definition::{HintProcessor, HintReference},
     math_utils::safe_div_usize,
     serde::deserialize_program::OffsetValue,
@@ -7,16 +16,17 @@ use crate::{
         exec_scope::ExecutionScopes,
         instance_definitions::{
             bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
-            ecdsa_instance_def::EcdsaInstanceDef,
+            ecdsa_instance_def::EcdsaInstanceDef, poseidon_instance_def::PoseidonInstanceDef,
         },
         instruction::Register,
         layout::CairoLayout,
         program::Program,
         relocatable::{relocate_address, relocate_value, MaybeRelocatable, Relocatable},
     },
-    utils::is_subsequence,
+    utils::{assert_send, is_subsequence},
     vm::{
         errors::{
+            air_input_errors::AirInputError, cairo_pie_errors::CairoPieError,
             memory_errors::MemoryError, runner_errors::RunnerError, trace_errors::TraceError,
             vm_errors::VirtualMachineError,
         },
@@ -25,22 +35,23 @@ use crate::{
         vm_memory::{memory::RelocateValue, memory_segments::gen_typed_args},
         {
             runners::builtin_runner::{
-                BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner,
-                OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
+                BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, GasBuiltinRunner,
+                HashBuiltinRunner, OutputBuiltinRunner, PoseidonBuiltinRunner,
+                RangeCheckBuiltinRunner, SegmentArenaBuiltinRunner, SignatureBuiltinRunner,
             },
+            runners::cairo_pie::{CairoPie, CairoPieMetadata, CairoPieVersion, StrippedProgram},
             trace::trace_entry::{relocate_trace_register, RelocatedTraceEntry},
             vm_core::VirtualMachine,
         },
     },
 };
 use felt::{Felt, FeltOps};
-use num_integer::div_rem;
-use num_traits::Zero;
-use std::{
-    any::Any,
-    collections::{HashMap, HashSet},
-    io,
-};
+use num_bigint::BigInt;
+use num_integer::{div_ceil, div_rem};
+use num_traits::{Signed, Zero};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::io;
 
 use super::builtin_runner::KeccakBuiltinRunner;
 
@@ -56,32 +67,40 @@ pub struct CairoRunner {
     run_ended: bool,
     segments_finalized: bool,
     execution_public_memory: Option<Vec<usize>>,
-    proof_mode: bool,
+    pub(crate) proof_mode: bool,
     pub original_steps: Option<usize>,
     pub relocated_memory: Vec<Option<Felt>>,
     pub relocated_trace: Option<Vec<RelocatedTraceEntry>>,
     pub exec_scopes: ExecutionScopes,
 }
 
+const _: () = assert_send::<CairoRunner>();
+
 impl CairoRunner {
     pub fn new(
         program: &Program,
         layout: &str,
         proof_mode: bool,
     ) -> Result<CairoRunner, RunnerError> {
-        let cairo_layout = match layout {
-            "plain" => CairoLayout::plain_instance(),
-            "small" => CairoLayout::small_instance(),
-            "dex" => CairoLayout::dex_instance(),
-            "perpetual_with_bitwise" => CairoLayout::perpetual_with_bitwise_instance(),
-            "bitwise" => CairoLayout::bitwise_instance(),
-            "recursive" => CairoLayout::recursive_instance(),
-            "all" => CairoLayout::all_instance(),
-            name => return Err(RunnerError::InvalidLayoutName(name.to_string())),
-        };
-        Ok(CairoRunner {
+        let cairo_layout = CairoLayout::from_name(layout)
+            .ok_or_else(|| RunnerError::InvalidLayoutName(layout.to_string()))?;
+        Ok(CairoRunner::new_with_layout(
+            program,
+            cairo_layout,
+            proof_mode,
+        ))
+    }
+
+    /// Creates a runner for a layout that isn't necessarily one of the predefined ones, such as
+    /// a layout read with `CairoLayout::from_file`.
+    pub fn new_with_layout(
+        program: &Program,
+        layout: CairoLayout,
+        proof_mode: bool,
+    ) -> CairoRunner {
+        CairoRunner {
             program: program.clone(),
-            layout: cairo_layout,
+            layout,
             final_pc: None,
             program_base: None,
             execution_base: None,
@@ -96,7 +115,7 @@ impl CairoRunner {
             relocated_trace: None,
             exec_scopes: ExecutionScopes::new(),
             execution_public_memory: if proof_mode { Some(Vec::new()) } else { None },
-        })
+        }
     }
 
     pub fn initialize(&mut self, vm: &mut VirtualMachine) -> Result<Relocatable, RunnerError> {
@@ -107,6 +126,72 @@ impl CairoRunner {
         Ok(end)
     }
 
+    /// Initializes the runner like `initialize`, passing `args` to the entrypoint after the
+    /// builtin pointers. Arguments can't be passed in proof mode, as the execution starts from
+    /// the `__start__` label instead of the entrypoint.
+    pub fn initialize_with_args(
+        &mut self,
+        vm: &mut VirtualMachine,
+        args: &[CairoArg],
+    ) -> Result<Relocatable, VirtualMachineError> {
+        if args.is_empty() {
+            return Ok(self.initialize(vm)?);
+        }
+        if self.proof_mode {
+            return Err(RunnerError::ArgsInProofMode.into());
+        }
+        self.initialize_builtins(vm)?;
+        self.initialize_segments(vm, None);
+
+        let builtin_stack: Vec<MaybeRelocatable> = vm
+            .builtin_runners
+            .iter()
+            .flat_map(|(_name, builtin_runner)| builtin_runner.initial_stack())
+            .collect();
+        let mut entrypoint_args: Vec<&dyn Any> = builtin_stack
+            .iter()
+            .map(|value| value as &dyn Any)
+            .collect();
+        for arg in args {
+            flatten_composed_arg(arg, &mut entrypoint_args);
+        }
+
+        let main = self.program.main.ok_or(RunnerError::MissingMain)?;
+        self.initialize_from_entrypoint(main, entrypoint_args, false, vm)
+    }
+
+    /// Initializes the runner to run the program of `cairo_pie` again. The segments are created
+    /// in the same order as in the original run, so that the PIE's memory and builtin data can be
+    /// loaded as they are before the vm starts.
+    pub fn initialize_from_cairo_pie(
+        &mut self,
+        vm: &mut VirtualMachine,
+        cairo_pie: &CairoPie,
+    ) -> Result<Relocatable, RunnerError> {
+        self.initialize_builtins(vm)?;
+        self.initialize_segments(vm, None);
+        let end = self.initialize_main_entrypoint(vm)?;
+        for _ in &cairo_pie.metadata.extra_segments {
+            vm.segments.add(&mut vm.memory);
+        }
+
+        for (name, builtin_runner) in vm.builtin_runners.iter_mut() {
+            if let Some(additional_data) = cairo_pie.additional_data.get(&format!("{name}_builtin"))
+            {
+                builtin_runner.extend_additional_data(additional_data)?;
+            }
+        }
+        for ((segment_index, offset), value) in &cairo_pie.memory {
+            vm.memory.insert(
+                &Relocatable::from((*segment_index as isize, *offset)),
+                value,
+            )?;
+        }
+
+        self.initialize_vm(vm)?;
+        Ok(end)
+    }
+
     pub fn initialize_builtins(&self, vm: &mut VirtualMachine) -> Result<(), RunnerError> {
         let builtin_ordered_list = vec![
             String::from("output"),
@@ -116,6 +201,9 @@ impl CairoRunner {
             String::from("bitwise"),
             String::from("ec_op"),
             String::from("keccak"),
+            String::from("poseidon"),
+            String::from("segment_arena"),
+            String::from("gas_builtin"),
         ];
         if !is_subsequence(&self.program.builtins, &builtin_ordered_list) {
             return Err(RunnerError::DisorderedBuiltins);
@@ -197,6 +285,31 @@ impl CairoRunner {
             }
         }
 
+        if let Some(instance_def) = self.layout.builtins.poseidon.as_ref() {
+            let included = self.program.builtins.contains(&"poseidon".to_string());
+            if included || self.proof_mode {
+                builtin_runners.push((
+                    "poseidon".to_string(),
+                    PoseidonBuiltinRunner::new(instance_def, included).into(),
+                ));
+            }
+        }
+
+        // Cairo 1 builtins don't take any cells of the trace, so every layout supports them.
+        if self.program.builtins.contains(&"segment_arena".to_string()) {
+            builtin_runners.push((
+                "segment_arena".to_string(),
+                SegmentArenaBuiltinRunner::new(true).into(),
+            ));
+        }
+
+        if self.program.builtins.contains(&"gas_builtin".to_string()) {
+            builtin_runners.push((
+                "gas_builtin".to_string(),
+                GasBuiltinRunner::new(u64::MAX, true).into(),
+            ));
+        }
+
         let inserted_builtins = builtin_runners
             .iter()
             .map(|x| &x.0)
@@ -229,6 +342,7 @@ impl CairoRunner {
             String::from("bitwise"),
             String::from("ec_op"),
             String::from("keccak"),
+            String::from("poseidon"),
         ];
 
         fn initialize_builtin(name: &str, vm: &mut VirtualMachine) {
@@ -259,6 +373,18 @@ impl CairoRunner {
                     name.to_string(),
                     EcOpBuiltinRunner::new(&EcOpInstanceDef::new(1), true).into(),
                 )),
+                "poseidon" => vm.builtin_runners.push((
+                    name.to_string(),
+                    PoseidonBuiltinRunner::new(&PoseidonInstanceDef::new(1), true).into(),
+                )),
+                "segment_arena" => vm.builtin_runners.push((
+                    name.to_string(),
+                    SegmentArenaBuiltinRunner::new(true).into(),
+                )),
+                "gas_builtin" => vm.builtin_runners.push((
+                    name.to_string(),
+                    GasBuiltinRunner::new(u64::MAX, true).into(),
+                )),
                 _ => {}
             }
         }
@@ -470,8 +596,8 @@ impl CairoRunner {
         &self,
         references: &HashMap<usize, HintReference>,
         hint_executor: &mut dyn HintProcessor,
-    ) -> Result<HashMap<usize, Vec<Box<dyn Any>>>, VirtualMachineError> {
-        let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any>>>::new();
+    ) -> Result<HashMap<usize, Vec<Box<dyn Any + Send>>>, VirtualMachineError> {
+        let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any + Send>>>::new();
         for (hint_index, hints) in self.program.hints.iter() {
             for hint in hints {
                 let hint_data = hint_executor.compile_hint(
@@ -676,6 +802,118 @@ impl CairoRunner {
         Ok(())
     }
 
+    /// Sets the builtin ratios and the cells per step of a dynamic layout to the smallest powers
+    /// of two that fit the run at its current step count, so that `check_used_cells` passes.
+    /// Fails with `InsufficientAllocatedCells` when a builtin used more instances than there are
+    /// steps, in which case the run has to be padded further.
+    pub(crate) fn fit_dynamic_layout(
+        &mut self,
+        vm: &mut VirtualMachine,
+    ) -> Result<(), VirtualMachineError> {
+        let n_steps = vm.current_step;
+
+        let ratios = vm
+            .builtin_runners
+            .iter()
+            .map(|(_, builtin_runner)| {
+                let instances_per_component = match builtin_runner.instances_per_component() {
+                    Some(x) => x as usize,
+                    None => return Ok(None),
+                };
+                let used_instances = builtin_runner.get_used_instances(vm)?;
+                let components = div_ceil(used_instances, instances_per_component)
+                    .max(1)
+                    .next_power_of_two();
+                let instances = components * instances_per_component;
+                if instances > n_steps {
+                    return Err(MemoryError::InsufficientAllocatedCells);
+                }
+                Ok(Some((n_steps / instances) as u32))
+            })
+            .collect::<Result<Vec<_>, MemoryError>>()?;
+        for ((_, builtin_runner), ratio) in vm.builtin_runners.iter_mut().zip(ratios) {
+            if let Some(ratio) = ratio {
+                builtin_runner.set_ratio(ratio);
+            }
+        }
+
+        if let Some((rc_min, rc_max)) = self.get_perm_range_check_limits(vm)? {
+            let mut rc_units_used_by_builtins = 0;
+            for (_, builtin_runner) in &vm.builtin_runners {
+                rc_units_used_by_builtins += builtin_runner.get_used_perm_range_check_units(vm)?;
+            }
+            let rc_units = div_ceil(
+                (rc_max - rc_min) as usize + rc_units_used_by_builtins,
+                n_steps,
+            );
+            self.layout.rc_units = (3 + rc_units as u32).next_power_of_two();
+        }
+
+        if let Some(diluted_pool_instance) = self.layout.diluted_pool_instance_def.as_mut() {
+            let mut used_units_by_builtins = 0;
+            for (_, builtin_runner) in &vm.builtin_runners {
+                let used_units = builtin_runner.get_used_diluted_check_units(
+                    diluted_pool_instance.spacing,
+                    diluted_pool_instance.n_bits,
+                );
+                let multiplier = safe_div_usize(
+                    vm.current_step,
+                    builtin_runner.ratio().unwrap_or(1) as usize,
+                )?;
+                used_units_by_builtins += used_units * multiplier;
+            }
+            let diluted_units = used_units_by_builtins + (1usize << diluted_pool_instance.n_bits);
+            diluted_pool_instance.units_per_step =
+                (div_ceil(diluted_units, n_steps) as u32).next_power_of_two();
+        }
+
+        let builtins_memory_units: usize = vm
+            .builtin_runners
+            .iter()
+            .map(|(_, builtin_runner)| builtin_runner.get_allocated_memory_units(vm))
+            .collect::<Result<Vec<usize>, MemoryError>>()?
+            .iter()
+            .sum();
+        let memory_units = 4 * n_steps + builtins_memory_units + self.get_memory_holes(vm)?;
+        // A fraction of the memory units is reserved for the public memory, so their total has
+        // to be a multiple of it.
+        let public_memory_fraction = self.layout._public_memory_fraction as usize;
+        let private_memory_steps = n_steps * public_memory_fraction.saturating_sub(1);
+        if private_memory_steps == 0 {
+            return Err(VirtualMachineError::DividedByZero);
+        }
+        let memory_units_per_step =
+            div_ceil(memory_units * public_memory_fraction, private_memory_steps);
+        self.layout._memory_units_per_step = (memory_units_per_step as u32)
+            .max(public_memory_fraction as u32)
+            .next_power_of_two();
+
+        Ok(())
+    }
+
+    /// Returns the parameters chosen for a run with the dynamic layout, and `None` for any other
+    /// layout.
+    pub fn get_dynamic_params(&self, vm: &VirtualMachine) -> Option<DynamicParams> {
+        if !self.layout.is_dynamic() {
+            return None;
+        }
+        Some(DynamicParams {
+            rc_units: self.layout.rc_units,
+            memory_units_per_step: self.layout._memory_units_per_step,
+            public_memory_fraction: self.layout._public_memory_fraction,
+            diluted_units_per_step: self
+                .layout
+                .diluted_pool_instance_def
+                .as_ref()
+                .map(|diluted_pool_instance| diluted_pool_instance.units_per_step),
+            builtin_ratios: vm
+                .builtin_runners
+                .iter()
+                .filter_map(|(name, builtin_runner)| Some((name.clone(), builtin_runner.ratio()?)))
+                .collect(),
+        })
+    }
+
     pub fn end_run(
         &mut self,
         disable_trace_padding: bool,
@@ -698,7 +936,12 @@ impl CairoRunner {
         if self.proof_mode && !disable_trace_padding {
             self.run_until_next_power_of_2(vm, hint_processor)?;
             loop {
-                match self.check_used_cells(vm) {
+                let fitted = if self.layout.is_dynamic() {
+                    self.fit_dynamic_layout(vm)
+                } else {
+                    Ok(())
+                };
+                match fitted.and_then(|_| self.check_used_cells(vm)) {
                     Ok(_) => break,
                     Err(e) => match e {
                         VirtualMachineError::MemoryError(
@@ -816,10 +1059,7 @@ impl CairoRunner {
         &self,
         vm: &VirtualMachine,
     ) -> Result<ExecutionResources, TraceError> {
-        let n_steps = match self.original_steps {
-            Some(x) => x,
-            None => vm.trace.as_ref().map(|x| x.len()).unwrap_or(0),
-        };
+        let n_steps = self.original_steps.unwrap_or(vm.current_step);
         let n_memory_holes = self.get_memory_holes(vm)?;
 
         let mut builtin_instance_counter = HashMap::new();
@@ -837,49 +1077,230 @@ impl CairoRunner {
         })
     }
 
+    /// Builds the Cairo PIE of a finished run, whose builtin stop pointers were already set by
+    /// `read_return_values`.
+    pub fn get_cairo_pie(&self, vm: &VirtualMachine) -> Result<CairoPie, CairoPieError> {
+        let program_base = self.program_base.ok_or(RunnerError::NoProgBase)?;
+        let execution_base = self.execution_base.ok_or(RunnerError::NoExecBase)?;
+        let builtin_segments = self.get_builtin_segments_info(vm)?;
+
+        // The return fp and pc are placed right after the pointers of the program's builtins.
+        let return_segment = |offset: usize, name: &'static str| {
+            let address = execution_base + self.program.builtins.len() + offset;
+            match vm.memory.get_relocatable(&address) {
+                Ok(Relocatable {
+                    segment_index,
+                    offset: 0,
+                }) if vm.segments.get_segment_size(segment_index as usize) == Some(0) => {
+                    Ok(SegmentInfo {
+                        index: segment_index,
+                        size: 0,
+                    })
+                }
+                _ => Err(CairoPieError::InvalidReturnSegment(name)),
+            }
+        };
+        let ret_fp_segment = return_segment(0, "return fp")?;
+        let ret_pc_segment = return_segment(1, "return pc")?;
+
+        let known_segments: HashSet<isize> = builtin_segments
+            .values()
+            .map(|segment| segment.index)
+            .chain([
+                program_base.segment_index,
+                execution_base.segment_index,
+                ret_fp_segment.index,
+                ret_pc_segment.index,
+            ])
+            .collect();
+        let extra_segments = (0..vm.segments.num_segments)
+            .filter(|index| !known_segments.contains(&(*index as isize)))
+            .map(|index| {
+                Ok(SegmentInfo {
+                    index: index as isize,
+                    size: vm
+                        .segments
+                        .get_segment_size(index)
+                        .ok_or(MemoryError::MissingSegmentUsedSizes)?,
+                })
+            })
+            .collect::<Result<Vec<_>, CairoPieError>>()?;
+
+        let metadata = CairoPieMetadata {
+            program: StrippedProgram {
+                data: self.program.data.clone(),
+                builtins: self.program.builtins.clone(),
+                main: self.program.main.ok_or(RunnerError::MissingMain)?,
+                prime: self.program.prime.clone(),
+            },
+            program_segment: SegmentInfo {
+                index: program_base.segment_index,
+                size: self.program.data.len(),
+            },
+            execution_segment: SegmentInfo {
+                index: execution_base.segment_index,
+                size: vm.run_context.ap - execution_base.offset,
+            },
+            ret_fp_segment,
+            ret_pc_segment,
+            builtin_segments: builtin_segments
+                .into_iter()
+                .map(|(name, segment)| (name.to_string(), segment))
+                .collect(),
+            extra_segments,
+        };
+
+        let mut memory = Vec::new();
+        for (segment_index, segment) in vm.memory.data.iter().enumerate() {
+            for (offset, value) in segment.iter().enumerate() {
+                if let Some(value) = value {
+                    memory.push(((segment_index, offset), value.clone()));
+                }
+            }
+        }
+
+        // The Python VM names builtin runners after their segment, with a `_builtin` suffix.
+        let mut execution_resources = self.get_execution_resources(vm)?;
+        execution_resources.builtin_instance_counter = execution_resources
+            .builtin_instance_counter
+            .into_iter()
+            .map(|(name, count)| (format!("{name}_builtin"), count))
+            .collect();
+        let additional_data = vm
+            .builtin_runners
+            .iter()
+            .map(|(name, builtin)| (format!("{name}_builtin"), builtin.get_additional_data()))
+            .collect();
+
+        Ok(CairoPie {
+            metadata,
+            memory,
+            execution_resources,
+            additional_data,
+            version: CairoPieVersion::default(),
+        })
+    }
+
+    /// Builds the public input of the AIR of a finished proof mode run, after its segments
+    /// were finalized and its memory relocated.
+    pub fn get_air_public_input(&self, vm: &VirtualMachine) -> Result<PublicInput, AirInputError> {
+        let relocation_table = vm.segments.relocate_segments()?;
+        let segment_addresses = |begin: Relocatable, stop: Relocatable| {
+            Ok::<_, MemoryError>(MemorySegmentAddresses {
+                begin_addr: relocate_address(begin, &relocation_table)?,
+                stop_ptr: relocate_address(stop, &relocation_table)?,
+            })
+        };
+
+        let mut memory_segments = HashMap::new();
+        memory_segments.insert(
+            String::from("program"),
+            segment_addresses(
+                self.program_base.ok_or(RunnerError::NoProgBase)?,
+                vm.run_context.pc,
+            )?,
+        );
+        memory_segments.insert(
+            String::from("execution"),
+            segment_addresses(
+                self.execution_base.ok_or(RunnerError::NoExecBase)?,
+                vm.run_context.get_ap(),
+            )?,
+        );
+        for (_, builtin) in vm.builtin_runners.iter() {
+            let (name, (base, stop_ptr)) = builtin.get_memory_segment_addresses();
+            let stop_ptr = stop_ptr.ok_or(RunnerError::BaseNotFinished)?;
+            memory_segments.insert(
+                name.to_string(),
+                segment_addresses((base, 0).into(), (base, stop_ptr).into())?,
+            );
+        }
+
+        let n_steps = vm.trace.as_ref().ok_or(TraceError::TraceNotEnabled)?.len();
+        let rc_limits = self
+            .get_perm_range_check_limits(vm)?
+            .ok_or(AirInputError::NoRangeCheckLimits)?;
+        let public_memory_addresses = vm.segments.get_public_memory_addresses(&relocation_table)?;
+
+        let mut public_input = PublicInput::new(
+            &self.relocated_memory,
+            &self.layout._name,
+            &public_memory_addresses,
+            memory_segments,
+            n_steps,
+            rc_limits,
+        )?;
+        public_input.dynamic_params = self.get_dynamic_params(vm);
+        Ok(public_input)
+    }
+
+    /// Gathers the inputs of the builtin instances used in the run, by builtin name. The output
+    /// and Cairo 1 builtins have no private input and are left out.
+    pub fn get_air_private_input(&self, vm: &VirtualMachine) -> AirPrivateInput {
+        AirPrivateInput(
+            vm.builtin_runners
+                .iter()
+                .filter(|(_, builtin)| {
+                    !matches!(
+                        builtin,
+                        BuiltinRunner::Output(_)
+                            | BuiltinRunner::SegmentArena(_)
+                            | BuiltinRunner::Gas(_)
+                    )
+                })
+                .map(|(name, builtin)| (name.to_string(), builtin.air_private_input(&vm.memory)))
+                .collect(),
+        )
+    }
+
     pub fn get_output(&mut self, vm: &mut VirtualMachine) -> Result<String, RunnerError> {
-        let mut output = Vec::<u8>::new();
-        self.write_output(vm, &mut output)?;
-        let output = String::from_utf8(output).map_err(|_| RunnerError::FailedStringConversion)?;
+        let mut output = String::new();
+        for value in self.get_output_values(vm)? {
+            writeln!(output, "{}", value.to_bigint()).map_err(|_| RunnerError::WriteFail)?;
+        }
         Ok(output)
     }
 
     /// Writes the values hosted in the output builtin's segment.
     /// Does nothing if the output builtin is not present in the program.
+    #[cfg(feature = "std")]
     pub fn write_output(
         &mut self,
         vm: &mut VirtualMachine,
         stdout: &mut dyn io::Write,
     ) -> Result<(), RunnerError> {
-        let builtin = vm
+        for value in self.get_output_values(vm)? {
+            writeln!(stdout, "{}", value.to_bigint()).map_err(|_| RunnerError::WriteFail)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the values hosted in the output builtin's segment.
+    /// The result is empty if the output builtin is not present in the program.
+    pub fn get_output_values(&self, vm: &mut VirtualMachine) -> Result<Vec<Felt>, RunnerError> {
+        let base = match vm
             .builtin_runners
-            .iter_mut()
-            .find_map(|(k, v)| match k.as_str() {
-                "output" => Some(v),
-                _ => None,
-            });
-        let builtin = match builtin {
-            Some(x) => x,
-            _ => return Ok(()),
+            .iter()
+            .find(|(name, _)| name.as_str() == "output")
+        {
+            Some((_, builtin)) => builtin.base(),
+            None => return Ok(Vec::new()),
         };
 
         let segment_used_sizes = vm.segments.compute_effective_sizes(&vm.memory);
-        let base = builtin.base();
-
         let segment_index: usize = base
             .try_into()
             .map_err(|_| RunnerError::RunnerInTemporarySegment(base))?;
 
-        for i in 0..segment_used_sizes[segment_index] {
-            let value = vm
-                .memory
-                .get_integer(&(base, i).into())
-                .map_err(|_| RunnerError::MemoryGet((base, i).into()))?
-                .to_bigint();
-            writeln!(stdout, "{}", value).map_err(|_| RunnerError::WriteFail)?;
-        }
-
-        Ok(())
+        (0..segment_used_sizes[segment_index])
+            .map(|i| {
+                vm.memory
+                    .get_integer(&(base, i).into())
+                    .map(|value| value.into_owned())
+                    .map_err(|_| RunnerError::MemoryGet((base, i).into()))
+            })
+            .collect()
     }
 
     // Finalizes the segments.
@@ -933,17 +1354,16 @@ impl CairoRunner {
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn run_from_entrypoint(
+    /// Writes `args` into memory and initializes the vm to run the function at `entrypoint`
+    /// with them, returning the pc at which the function ends. `run_from_entrypoint` runs the
+    /// function right after this.
+    pub fn initialize_from_entrypoint(
         &mut self,
         entrypoint: usize,
         args: Vec<&dyn Any>,
         typed_args: bool,
-        verify_secure: bool,
-        _apply_modulo_to_args: bool,
         vm: &mut VirtualMachine,
-        hint_processor: &mut dyn HintProcessor,
-    ) -> Result<(), VirtualMachineError> {
+    ) -> Result<Relocatable, VirtualMachineError> {
         let stack = if typed_args {
             if args.len() != 1 {
                 return Err(VirtualMachineError::InvalidArgCount(1, args.len()));
@@ -963,6 +1383,21 @@ impl CairoRunner {
         let end = self.initialize_function_entrypoint(vm, entrypoint, stack, return_fp.into())?;
 
         self.initialize_vm(vm)?;
+        Ok(end)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_from_entrypoint(
+        &mut self,
+        entrypoint: usize,
+        args: Vec<&dyn Any>,
+        typed_args: bool,
+        verify_secure: bool,
+        _apply_modulo_to_args: bool,
+        vm: &mut VirtualMachine,
+        hint_processor: &mut dyn HintProcessor,
+    ) -> Result<(), VirtualMachineError> {
+        let end = self.initialize_from_entrypoint(entrypoint, args, typed_args, vm)?;
 
         self.run_until_pc(end, vm, hint_processor)?;
         self.end_run(true, false, vm, hint_processor)?;
@@ -1022,7 +1457,8 @@ impl CairoRunner {
         let instruction_memory_units = 4 * vm_current_step_u32;
 
         let unused_memory_units = total_memory_units
-            - (public_memory_units + instruction_memory_units + builtins_memory_units);
+            .checked_sub(public_memory_units + instruction_memory_units + builtins_memory_units)
+            .ok_or(MemoryError::InsufficientAllocatedCells)?;
         let memory_address_holes = self.get_memory_holes(vm)?;
         if unused_memory_units < memory_address_holes as u32 {
             Err(MemoryError::InsufficientAllocatedCells)?
@@ -1054,24 +1490,23 @@ impl CairoRunner {
         Ok(())
     }
 
-    pub fn read_return_values(&mut self, vm: &VirtualMachine) -> Result<(), RunnerError> {
+    /// Reads the builtin pointers returned by the entrypoint, recording each builtin's stop
+    /// pointer, and adds the return values to the public memory when running in proof mode.
+    pub fn read_return_values(&mut self, vm: &mut VirtualMachine) -> Result<(), RunnerError> {
         if !self.run_ended {
             return Err(RunnerError::FinalizeNoEndRun);
         }
         let mut pointer = vm.get_ap();
         for builtin_name in self.program.builtins.iter().rev() {
-            let builtin_runner = vm
+            let index = vm
                 .builtin_runners
                 .iter()
-                .find(|(name, _builtin)| builtin_name == name);
+                .position(|(name, _builtin)| builtin_name == name)
+                .ok_or_else(|| RunnerError::MissingBuiltin(builtin_name.to_string()))?;
 
-            match builtin_runner {
-                None => return Err(RunnerError::MissingBuiltin(builtin_name.to_string())),
-                Some((_, builtin)) => {
-                    let (new_pointer, _) = builtin.final_stack(vm, pointer)?;
-                    pointer = new_pointer;
-                }
-            }
+            let (new_pointer, stop_ptr) = vm.builtin_runners[index].1.final_stack(vm, pointer)?;
+            vm.builtin_runners[index].1.set_stop_ptr(stop_ptr);
+            pointer = new_pointer;
         }
         if self.segments_finalized {
             return Err(RunnerError::FailedAddingReturnValues);
@@ -1083,10 +1518,13 @@ impl CairoRunner {
         let begin = pointer.offset - exec_base.offset;
         let ap = vm.get_ap();
         let end = ap.offset - exec_base.offset;
-        self.execution_public_memory
-            .as_mut()
-            .ok_or(RunnerError::NoExecPublicMemory)?
-            .extend(begin..end);
+        // Outside proof mode there's no public memory to add the return values to.
+        if self.proof_mode {
+            self.execution_public_memory
+                .as_mut()
+                .ok_or(RunnerError::NoExecPublicMemory)?
+                .extend(begin..end);
+        }
         Ok(())
     }
 
@@ -1111,13 +1549,89 @@ impl CairoRunner {
     }
 }
 
+// Composed arguments take one stack slot per member, so they are split into their members,
+// each of which `MemorySegmentManager::gen_arg` turns into a single value.
+fn flatten_composed_arg<'a>(arg: &'a CairoArg, args: &mut Vec<&'a dyn Any>) {
+    match arg {
+        CairoArg::Composed(members) => {
+            for member in members {
+                flatten_composed_arg(member, args);
+            }
+        }
+        _ => args.push(arg),
+    }
+}
+
+/// An argument passed to a Cairo function. Arrays are written into a new segment and passed
+/// as a pointer to it, while the members of composed arguments (structs) are passed in place.
 #[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CairoArg {
+    Single(MaybeRelocatable),
+    Array(Vec<CairoArg>),
+    Composed(Vec<CairoArg>),
+}
+
+impl From<MaybeRelocatable> for CairoArg {
+    fn from(value: MaybeRelocatable) -> Self {
+        CairoArg::Single(value)
+    }
+}
+
+/// Parses a field element, written in decimal or as a 0x-prefixed hex number. Negative values
+/// are reduced modulo the prime, like cairo-run does.
+impl FromStr for CairoArg {
+    type Err = RunnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        let value = match digits.strip_prefix("0x") {
+            Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16),
+            None => BigInt::parse_bytes(digits.as_bytes(), 10),
+        }
+        .filter(|value| !value.is_negative())
+        .ok_or_else(|| RunnerError::InvalidArgument(s.to_string()))?;
+        let value = if negative { -value } else { value };
+        Ok(CairoArg::Single(Felt::from(value).into()))
+    }
+}
+
+/// Numbers and strings are parsed as field elements, arrays become `CairoArg::Array`s and
+/// objects of the form `{"struct": [...]}` become `CairoArg::Composed`s.
+impl TryFrom<&serde_json::Value> for CairoArg {
+    type Error = RunnerError;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let parse_all = |values: &Vec<serde_json::Value>| {
+            values
+                .iter()
+                .map(CairoArg::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+        match value {
+            serde_json::Value::Number(number) => number.to_string().parse(),
+            serde_json::Value::String(string) => string.parse(),
+            serde_json::Value::Array(elements) => Ok(CairoArg::Array(parse_all(elements)?)),
+            serde_json::Value::Object(object) => match object.get("struct") {
+                Some(serde_json::Value::Array(members)) if object.len() == 1 => {
+                    Ok(CairoArg::Composed(parse_all(members)?))
+                }
+                _ => Err(RunnerError::InvalidArgument(value.to_string())),
+            },
+            _ => Err(RunnerError::InvalidArgument(value.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SegmentInfo {
     pub index: isize,
     pub size: usize,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionResources {
     pub n_steps: usize,
     pub n_memory_holes: usize,
@@ -1128,6 +1642,7 @@ pub struct ExecutionResources {
 mod tests {
     use super::*;
     use crate::{
+        air_private_input::{PrivateInput, PrivateInputValue},
         hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
         relocatable,
         serde::deserialize_program::{Identifier, ReferenceManager},
@@ -1193,6 +1708,94 @@ mod tests {
         let _cairo_runner = cairo_runner!(program);
     }
 
+    #[test]
+    fn initialize_builtins_with_custom_layout() {
+        let layout = CairoLayout::from_reader(
+            r#"{
+                "name": "output_and_bitwise",
+                "rc_units": 4,
+                "public_memory_fraction": 8,
+                "memory_units_per_step": 8,
+                "diluted_pool": { "units_per_step": 16, "spacing": 4, "n_bits": 16 },
+                "builtins": { "output": true, "bitwise": { "ratio": 8 } }
+            }"#
+            .as_bytes(),
+        )
+        .unwrap();
+        let program = program!["output", "bitwise"];
+        let cairo_runner = CairoRunner::new_with_layout(&program, layout.clone(), false);
+        let mut vm = vm!();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        assert_eq!(vm.builtin_runners[0].0, String::from("output"));
+        assert_eq!(vm.builtin_runners[1].0, String::from("bitwise"));
+
+        let program = program!["output", "range_check"];
+        let cairo_runner = CairoRunner::new_with_layout(&program, layout, false);
+        let mut vm = vm!();
+        assert!(cairo_runner.initialize_builtins(&mut vm).is_err());
+    }
+
+    #[test]
+    fn initialize_builtins_with_poseidon() {
+        let program = program!["pedersen", "range_check", "poseidon"];
+        let cairo_runner = cairo_runner!(program, "starknet");
+        let mut vm = vm!();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        assert_eq!(vm.builtin_runners.len(), 3);
+        assert_eq!(vm.builtin_runners[2].0, String::from("poseidon"));
+        assert!(matches!(
+            vm.builtin_runners[2].1,
+            BuiltinRunner::Poseidon(_)
+        ));
+
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::NoBuiltinForInstance(
+                HashSet::from([String::from("poseidon")]),
+                String::from("all")
+            ))
+        );
+    }
+
+    #[test]
+    fn initialize_builtins_with_segment_arena_and_gas() {
+        let program = program!["range_check", "segment_arena", "gas_builtin"];
+        let cairo_runner = cairo_runner!(program, "plain");
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::NoBuiltinForInstance(
+                HashSet::from([String::from("range_check")]),
+                String::from("plain")
+            ))
+        );
+
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        cairo_runner.initialize_builtins(&mut vm).unwrap();
+        assert_eq!(vm.builtin_runners.len(), 3);
+        assert_eq!(vm.builtin_runners[1].0, String::from("segment_arena"));
+        assert!(matches!(
+            vm.builtin_runners[1].1,
+            BuiltinRunner::SegmentArena(_)
+        ));
+        assert_eq!(vm.builtin_runners[2].0, String::from("gas_builtin"));
+        assert!(matches!(vm.builtin_runners[2].1, BuiltinRunner::Gas(_)));
+    }
+
+    #[test]
+    fn initialize_builtins_gas_before_segment_arena() {
+        let program = program!["gas_builtin", "segment_arena"];
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_builtins(&mut vm),
+            Err(RunnerError::DisorderedBuiltins)
+        );
+    }
+
     #[test]
     fn initialize_segments_with_base() {
         //This test works with basic Program definition, will later be updated to use Program::new() when fully defined
@@ -1428,6 +2031,142 @@ mod tests {
         assert_eq!(return_pc, Relocatable::from((1, 0)));
     }
 
+    #[test]
+    fn initialize_with_args() {
+        let program = program!(main = Some(1),);
+        let mut cairo_runner = cairo_runner!(program, "plain");
+        let mut vm = vm!();
+        let args = [
+            CairoArg::from(mayberelocatable!(5)),
+            CairoArg::Array(vec![
+                CairoArg::from(mayberelocatable!(1)),
+                CairoArg::from(mayberelocatable!(2)),
+            ]),
+        ];
+        let end = cairo_runner.initialize_with_args(&mut vm, &args).unwrap();
+        assert_eq!(end, relocatable!(4, 0));
+        check_memory!(
+            vm.memory,
+            ((1, 0), 5),
+            ((1, 1), (2, 0)),
+            ((1, 2), (3, 0)),
+            ((1, 3), (4, 0)),
+            ((2, 0), 1),
+            ((2, 1), 2)
+        );
+        assert_eq!(cairo_runner.initial_fp, Some(relocatable!(1, 4)));
+    }
+
+    #[test]
+    fn get_cairo_pie_no_program_base() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+        assert!(matches!(
+            cairo_runner.get_cairo_pie(&vm),
+            Err(CairoPieError::Runner(RunnerError::NoProgBase))
+        ));
+    }
+
+    #[test]
+    fn get_air_public_input_no_program_base() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![]);
+        assert_eq!(
+            cairo_runner.get_air_public_input(&vm),
+            Err(AirInputError::Runner(RunnerError::NoProgBase))
+        );
+    }
+
+    #[test]
+    fn get_air_private_input() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let mut vm = vm!();
+        vm.builtin_runners = vec![
+            ("output".to_string(), OutputBuiltinRunner::new(true).into()),
+            (
+                "range_check".to_string(),
+                RangeCheckBuiltinRunner::new(8, 8, true).into(),
+            ),
+        ];
+        vm.memory = memory![((0, 0), 5)];
+        assert_eq!(
+            cairo_runner.get_air_private_input(&vm),
+            AirPrivateInput(HashMap::from([(
+                "range_check".to_string(),
+                vec![PrivateInput::Value(PrivateInputValue {
+                    index: 0,
+                    value: Felt::new(5),
+                })]
+            )]))
+        );
+    }
+
+    #[test]
+    fn initialize_with_args_proof_mode() {
+        let program = program!(main = Some(1),);
+        let mut cairo_runner = cairo_runner!(program, "plain", true);
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.initialize_with_args(&mut vm, &[CairoArg::from(mayberelocatable!(5))]),
+            Err(VirtualMachineError::RunnerError(
+                RunnerError::ArgsInProofMode
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_cairo_arg() {
+        assert_eq!(
+            "10".parse::<CairoArg>(),
+            Ok(CairoArg::from(mayberelocatable!(10)))
+        );
+        assert_eq!(
+            "0x1f".parse::<CairoArg>(),
+            Ok(CairoArg::from(mayberelocatable!(31)))
+        );
+        assert_eq!(
+            "ten".parse::<CairoArg>(),
+            Err(RunnerError::InvalidArgument(String::from("ten")))
+        );
+    }
+
+    #[test]
+    fn parse_negative_cairo_arg() {
+        assert_eq!(
+            "-1".parse::<CairoArg>(),
+            Ok(CairoArg::from(MaybeRelocatable::from(-Felt::one())))
+        );
+        assert_eq!(
+            "-0x1f".parse::<CairoArg>(),
+            Ok(CairoArg::from(MaybeRelocatable::from(Felt::new(-31))))
+        );
+        assert_eq!(
+            "--1".parse::<CairoArg>(),
+            Err(RunnerError::InvalidArgument(String::from("--1")))
+        );
+    }
+
+    #[test]
+    fn cairo_arg_from_json() {
+        let value = serde_json::json!([1, "0x2", { "struct": [3, [4]] }]);
+        assert_eq!(
+            CairoArg::try_from(&value),
+            Ok(CairoArg::Array(vec![
+                CairoArg::from(mayberelocatable!(1)),
+                CairoArg::from(mayberelocatable!(2)),
+                CairoArg::Composed(vec![
+                    CairoArg::from(mayberelocatable!(3)),
+                    CairoArg::Array(vec![CairoArg::from(mayberelocatable!(4))]),
+                ]),
+            ]))
+        );
+        assert!(CairoArg::try_from(&serde_json::json!(true)).is_err());
+    }
+
     #[test]
     fn initialize_vm_program_segment_accessed_addrs() {
         // This test checks that all addresses from the program segment are marked as accessed at VM initialization.
@@ -3241,6 +3980,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn end_run_proof_mode_dynamic_layout() {
+        let program = Program::from_file(
+            Path::new("cairo_programs/proof_programs/fibonacci.json"),
+            Some("main"),
+        )
+        .expect("Call to `Program::from_file()` failed.");
+
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(program, "dynamic", true);
+        let mut vm = vm!(true);
+
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner
+            .run_until_pc(end, &mut vm, &mut hint_processor)
+            .expect("Call to `CairoRunner::run_until_pc()` failed.");
+        assert_eq!(
+            cairo_runner.end_run(false, false, &mut vm, &mut hint_processor),
+            Ok(()),
+        );
+        assert!(vm.current_step.is_power_of_two());
+        assert_eq!(cairo_runner.check_used_cells(&vm), Ok(()));
+
+        let dynamic_params = cairo_runner.get_dynamic_params(&vm).unwrap();
+        assert!(dynamic_params.rc_units.is_power_of_two());
+        assert!(dynamic_params.memory_units_per_step.is_power_of_two());
+        assert_eq!(dynamic_params.builtin_ratios.len(), 7);
+        // The program uses no builtins, so each of them gets a single component.
+        assert_eq!(
+            dynamic_params.builtin_ratios.get("pedersen"),
+            Some(&(vm.current_step as u32))
+        );
+        assert_eq!(
+            dynamic_params.builtin_ratios.get("keccak"),
+            Some(&(vm.current_step as u32 / 16))
+        );
+    }
+
+    #[test]
+    fn fit_dynamic_layout_too_many_instances() {
+        let program = program!();
+        let mut cairo_runner = cairo_runner!(program, "dynamic", true);
+        let mut vm = vm!(true);
+        vm.builtin_runners = vec![(
+            String::from("range_check"),
+            RangeCheckBuiltinRunner::new(8, 8, true).into(),
+        )];
+        vm.current_step = 4;
+        vm.segments.segment_used_sizes = Some(vec![5]);
+        assert_eq!(
+            cairo_runner.fit_dynamic_layout(&mut vm),
+            Err(MemoryError::InsufficientAllocatedCells.into())
+        );
+    }
+
+    #[test]
+    fn fit_dynamic_layout_public_memory_fraction_one() {
+        let program = program!();
+        let mut cairo_runner = cairo_runner!(program, "dynamic", true);
+        cairo_runner.layout._public_memory_fraction = 1;
+        let mut vm = vm!(true);
+        vm.current_step = 4;
+        vm.segments.segment_used_sizes = Some(vec![]);
+        assert_eq!(
+            cairo_runner.fit_dynamic_layout(&mut vm),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn get_dynamic_params_fixed_layout() {
+        let program = program!();
+        let cairo_runner = cairo_runner!(program);
+        let vm = vm!();
+        assert_eq!(cairo_runner.get_dynamic_params(&vm), None);
+    }
+
     #[test]
     fn get_builtin_segments_info_empty() {
         let program = program!();
@@ -3852,6 +4668,7 @@ mod tests {
         assert_eq!(given_output[4].0, "bitwise");
         assert_eq!(given_output[5].0, "ec_op");
         assert_eq!(given_output[6].0, "keccak");
+        assert_eq!(given_output[7].0, "poseidon");
     }
 
     #[test]
@@ -3874,6 +4691,7 @@ mod tests {
         assert_eq!(given_output[4].0, "bitwise");
         assert_eq!(given_output[5].0, "ec_op");
         assert_eq!(given_output[6].0, "keccak");
+        assert_eq!(given_output[7].0, "poseidon");
     }
 
     #[test]
@@ -3896,6 +4714,7 @@ mod tests {
         assert_eq!(builtin_runners[4].0, "bitwise");
         assert_eq!(builtin_runners[5].0, "ec_op");
         assert_eq!(builtin_runners[6].0, "keccak");
+        assert_eq!(builtin_runners[7].0, "poseidon");
 
         assert_eq!(
             cairo_runner.program_base,
@@ -3911,7 +4730,7 @@ mod tests {
                 offset: 0,
             })
         );
-        assert_eq!(vm.segments.num_segments, 9);
+        assert_eq!(vm.segments.num_segments, 10);
     }
 
     #[test]
@@ -4108,10 +4927,10 @@ mod tests {
         cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
         cairo_runner.run_ended = true;
         cairo_runner.segments_finalized = false;
-        let vm = vm!();
+        let mut vm = vm!();
         //Check values written by first call to segments.finalize()
 
-        assert_eq!(cairo_runner.read_return_values(&vm), Ok(()));
+        assert_eq!(cairo_runner.read_return_values(&mut vm), Ok(()));
         assert_eq!(
             cairo_runner
                 .execution_public_memory
@@ -4120,6 +4939,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_return_values_test_proof_mode_without_public_memory() {
+        let program = program!();
+        let mut cairo_runner = cairo_runner!(program, "plain", true);
+        cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
+        cairo_runner.run_ended = true;
+        cairo_runner.execution_public_memory = None;
+        let mut vm = vm!();
+        assert_eq!(
+            cairo_runner.read_return_values(&mut vm),
+            Err(RunnerError::NoExecPublicMemory)
+        );
+    }
+
     #[test]
     fn read_return_values_test_with_run_not_ended() {
         let mut program = program!();
@@ -4129,9 +4962,9 @@ mod tests {
         cairo_runner.program_base = Some(Relocatable::from((0, 0)));
         cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
         cairo_runner.run_ended = false;
-        let vm = vm!();
+        let mut vm = vm!();
         assert_eq!(
-            cairo_runner.read_return_values(&vm),
+            cairo_runner.read_return_values(&mut vm),
             Err(RunnerError::FinalizeNoEndRun)
         );
     }
@@ -4146,9 +4979,9 @@ mod tests {
         cairo_runner.execution_base = Some(Relocatable::from((1, 0)));
         cairo_runner.run_ended = true;
         cairo_runner.segments_finalized = true;
-        let vm = vm!();
+        let mut vm = vm!();
         assert_eq!(
-            cairo_runner.read_return_values(&vm),
+            cairo_runner.read_return_values(&mut vm),
             Err(RunnerError::FailedAddingReturnValues)
         );
     }
@@ -5,8 +5,8 @@ use super::{
     runners::cairo_runner::CairoRunner,
     vm_core::VirtualMachine,
 };
+use crate::stdlib::{collections::HashMap, mem::swap, prelude::*};
 use crate::types::relocatable::Relocatable;
-use std::{collections::HashMap, mem::swap};
 
 /// Verify that the completed run in a runner is safe to be relocated and be
 /// used by other Cairo programs.
@@ -3,9 +3,9 @@ use super::{
     decoding::decoder::decode_instruction, errors::vm_errors::VirtualMachineError,
     vm_memory::memory::Memory,
 };
+use crate::stdlib::borrow::Cow;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use num_traits::ToPrimitive;
-use std::borrow::Cow;
 
 pub mod trace_entry;
 
@@ -1,3 +1,4 @@
+use crate::stdlib::prelude::*;
 use crate::vm::errors::trace_errors::TraceError;
 use crate::{types::relocatable::Relocatable, vm::errors::memory_errors::MemoryError};
 use serde::{Deserialize, Serialize};
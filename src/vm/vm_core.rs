@@ -1,3 +1,4 @@
+use crate::stdlib::{any::Any, borrow::Cow, collections::HashMap, prelude::*};
 use crate::{
     hint_processor::hint_processor_definition::HintProcessor,
     serde::deserialize_program::ApTracking,
@@ -8,6 +9,7 @@ use crate::{
         },
         relocatable::{MaybeRelocatable, Relocatable},
     },
+    utils::assert_send,
     vm::{
         context::run_context::RunContext,
         decoding::decoder::decode_instruction,
@@ -22,7 +24,6 @@ use crate::{
 };
 use felt::Felt;
 use num_traits::{ToPrimitive, Zero};
-use std::{any::Any, borrow::Cow, collections::HashMap};
 
 use super::vm_memory::memory_segments::gen_typed_args;
 
@@ -76,6 +77,16 @@ pub struct HintData {
     pub ap_tracking_data: ApTracking,
 }
 
+/// Caps on the resources used by a run. Once one is reached, the next step fails with
+/// `VirtualMachineError::OutOfResources`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RunLimits {
+    pub max_steps: Option<usize>,
+    /// Maximum number of memory cells allocated across all segments, including temporary ones.
+    pub max_memory_cells: Option<usize>,
+    pub max_segments: Option<usize>,
+}
+
 pub struct VirtualMachine {
     pub(crate) run_context: RunContext,
     pub(crate) builtin_runners: Vec<(String, BuiltinRunner)>,
@@ -87,8 +98,11 @@ pub struct VirtualMachine {
     pub(crate) current_step: usize,
     skip_instruction_execution: bool,
     run_finished: bool,
+    run_limits: RunLimits,
 }
 
+const _: () = assert_send::<VirtualMachine>();
+
 impl HintData {
     pub fn new(
         hint_code: &str,
@@ -130,9 +144,39 @@ impl VirtualMachine {
             skip_instruction_execution: false,
             segments: MemorySegmentManager::new(),
             run_finished: false,
+            run_limits: RunLimits::default(),
         }
     }
 
+    pub fn set_run_limits(&mut self, run_limits: RunLimits) {
+        self.run_limits = run_limits;
+    }
+
+    fn check_run_limits(&self) -> Result<(), VirtualMachineError> {
+        if let Some(max_steps) = self.run_limits.max_steps {
+            if self.current_step >= max_steps {
+                return Err(VirtualMachineError::OutOfResources("steps", max_steps));
+            }
+        }
+        if let Some(max_memory_cells) = self.run_limits.max_memory_cells {
+            if self.memory.cell_count() > max_memory_cells {
+                return Err(VirtualMachineError::OutOfResources(
+                    "memory cells",
+                    max_memory_cells,
+                ));
+            }
+        }
+        if let Some(max_segments) = self.run_limits.max_segments {
+            if self.memory.data.len() + self.memory.temp_data.len() > max_segments {
+                return Err(VirtualMachineError::OutOfResources(
+                    "segments",
+                    max_segments,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     ///Returns the encoded instruction (the value at pc) and the immediate value (the value at pc + 1, if it exists in the memory).
     fn get_instruction_encoding(
         &self,
@@ -473,7 +517,7 @@ impl VirtualMachine {
         Ok(())
     }
 
-    fn decode_current_instruction(&self) -> Result<Instruction, VirtualMachineError> {
+    pub fn decode_current_instruction(&self) -> Result<Instruction, VirtualMachineError> {
         let (instruction_ref, imm) = self.get_instruction_encoding()?;
         match instruction_ref.to_i64() {
             Some(instruction) => {
@@ -492,7 +536,7 @@ impl VirtualMachine {
         &mut self,
         hint_executor: &mut dyn HintProcessor,
         exec_scopes: &mut ExecutionScopes,
-        hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
+        hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any + Send>>>,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), VirtualMachineError> {
         if let Some(hint_list) = hint_data_dictionary.get(&self.run_context.pc.offset) {
@@ -516,9 +560,10 @@ impl VirtualMachine {
         &mut self,
         hint_executor: &mut dyn HintProcessor,
         exec_scopes: &mut ExecutionScopes,
-        hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
+        hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any + Send>>>,
         constants: &HashMap<String, Felt>,
     ) -> Result<(), VirtualMachineError> {
+        self.check_run_limits()?;
         self.step_hint(hint_executor, exec_scopes, hint_data_dictionary, constants)?;
         self.step_instruction()
     }
@@ -2699,6 +2744,52 @@ mod tests {
         assert!(accessed_addresses.contains(&Relocatable::from((1, 1))));
     }
 
+    #[test]
+    fn step_max_steps_reached() {
+        let mut vm = vm!();
+        vm.set_run_limits(RunLimits {
+            max_steps: Some(5),
+            ..Default::default()
+        });
+        vm.current_step = 5;
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+
+        assert_eq!(
+            vm.step(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &HashMap::new(),
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::OutOfResources("steps", 5))
+        );
+    }
+
+    #[test]
+    fn step_max_memory_cells_exceeded() {
+        let mut vm = vm!();
+        vm.set_run_limits(RunLimits {
+            max_memory_cells: Some(2),
+            ..Default::default()
+        });
+        vm.memory = memory![
+            ((0, 0), 2345108766317314046_u64),
+            ((1, 0), (2, 0)),
+            ((1, 1), (3, 0))
+        ];
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+
+        assert_eq!(
+            vm.step(
+                &mut hint_processor,
+                exec_scopes_ref!(),
+                &HashMap::new(),
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::OutOfResources("memory cells", 2))
+        );
+    }
+
     #[test]
     /*
     Test for a simple program execution
@@ -1,18 +1,21 @@
+use crate::stdlib::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    mem::swap,
+    prelude::*,
+};
 use crate::{
     types::relocatable::{MaybeRelocatable, Relocatable},
     utils::from_relocatable_to_indexes,
     vm::errors::{memory_errors::MemoryError, vm_errors::VirtualMachineError},
 };
 use felt::Felt;
-use std::{
-    borrow::Cow,
-    collections::{HashMap, HashSet},
-    mem::swap,
-};
 
 pub struct ValidationRule(
     #[allow(clippy::type_complexity)]
-    pub  Box<dyn Fn(&Memory, &MaybeRelocatable) -> Result<Vec<MaybeRelocatable>, MemoryError>>,
+    pub  Box<
+        dyn Fn(&Memory, &MaybeRelocatable) -> Result<Vec<MaybeRelocatable>, MemoryError> + Send,
+    >,
 );
 
 pub struct Memory {
@@ -23,6 +26,9 @@ pub struct Memory {
     pub(crate) relocation_rules: HashMap<usize, Relocatable>,
     pub validated_addresses: HashSet<MaybeRelocatable>,
     validation_rules: HashMap<usize, ValidationRule>,
+    // Number of cells in data and temp_data, holes included, kept up to date by insert so that
+    // run limits don't have to go over every segment on each step.
+    cell_count: usize,
 }
 
 impl Memory {
@@ -33,6 +39,7 @@ impl Memory {
             relocation_rules: HashMap::new(),
             validated_addresses: HashSet::<MaybeRelocatable>::new(),
             validation_rules: HashMap::new(),
+            cell_count: 0,
         }
     }
     ///Inserts an MaybeRelocatable value into an address given by a MaybeRelocatable::Relocatable
@@ -64,6 +71,7 @@ impl Memory {
         //Check if the element is inserted next to the last one on the segment
         //Forgoing this check would allow data to be inserted in a different index
         if segment.len() <= value_offset {
+            self.cell_count += value_offset + 1 - segment.len();
             segment.resize(value_offset + 1, None);
         }
         // At this point there's *something* in there
@@ -179,9 +187,15 @@ impl Memory {
         }
 
         self.relocation_rules.clear();
+        self.cell_count = self.data.iter().map(Vec::len).sum();
         Ok(())
     }
 
+    /// Returns the number of cells in memory, including temporary segments and holes.
+    pub(crate) fn cell_count(&self) -> usize {
+        self.cell_count
+    }
+
     /// Add a new relocation rule.
     ///
     /// Will return an error if any of the following conditions are not met:
@@ -396,6 +410,26 @@ mod memory_tests {
         Ok(memory)
     }
 
+    #[test]
+    fn insert_counts_cells_and_holes() {
+        let mut memory = memory![((0, 0), 1), ((0, 3), 2), ((1, 1), 3)];
+        memory.temp_data.push(Vec::new());
+        memory
+            .insert(
+                &MaybeRelocatable::from((-1, 0)),
+                &MaybeRelocatable::from(Felt::new(4)),
+            )
+            .unwrap();
+        // Inserting into an existing cell doesn't add one.
+        memory
+            .insert(
+                &MaybeRelocatable::from((0, 1)),
+                &MaybeRelocatable::from(Felt::new(5)),
+            )
+            .unwrap();
+        assert_eq!(memory.cell_count(), 7);
+    }
+
     #[test]
     fn insert_and_get_succesful() {
         let key = MaybeRelocatable::from((0, 0));
@@ -3,14 +3,15 @@ use crate::{
     utils::from_relocatable_to_indexes,
     vm::{
         errors::memory_errors::MemoryError, errors::vm_errors::VirtualMachineError,
-        vm_memory::memory::Memory,
+        runners::cairo_runner::CairoArg, vm_memory::memory::Memory,
     },
 };
 
-use std::{
+use crate::stdlib::{
     any::Any,
     cmp,
     collections::{HashMap, HashSet},
+    prelude::*,
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -125,11 +126,46 @@ impl MemorySegmentManager {
             let base = self.add(memory);
             self.write_arg(memory, &base, value)?;
             Ok(base.into())
+        } else if let Some(value) = arg.downcast_ref::<CairoArg>() {
+            let mut values = self.gen_cairo_arg(value, memory)?;
+            match values.len() {
+                1 => Ok(values.remove(0)),
+                n => Err(VirtualMachineError::InvalidArgCount(1, n)),
+            }
         } else {
             Err(VirtualMachineError::NotImplemented)
         }
     }
 
+    /// Generates the values taken by a `CairoArg`. Arrays are written into a new segment,
+    /// and the members of composed arguments are flattened, so a composed argument can
+    /// produce more than one value.
+    pub fn gen_cairo_arg(
+        &mut self,
+        arg: &CairoArg,
+        memory: &mut Memory,
+    ) -> Result<Vec<MaybeRelocatable>, MemoryError> {
+        match arg {
+            CairoArg::Single(value) => Ok(vec![value.clone()]),
+            CairoArg::Array(elements) => {
+                let mut data = Vec::new();
+                for element in elements {
+                    data.extend(self.gen_cairo_arg(element, memory)?);
+                }
+                let base = self.add(memory);
+                self.load_data(memory, &base.into(), &data)?;
+                Ok(vec![base.into()])
+            }
+            CairoArg::Composed(members) => {
+                let mut data = Vec::new();
+                for member in members {
+                    data.extend(self.gen_cairo_arg(member, memory)?);
+                }
+                Ok(data)
+            }
+        }
+    }
+
     pub fn write_arg(
         &mut self,
         memory: &mut Memory,
@@ -228,6 +264,30 @@ impl MemorySegmentManager {
                 .insert(segment_index, public_memory.clone());
         }
     }
+
+    /// Returns the relocated address and the page of each public memory cell, given the first
+    /// relocated address of each segment.
+    pub fn get_public_memory_addresses(
+        &self,
+        segment_offsets: &[usize],
+    ) -> Result<Vec<(usize, usize)>, MemoryError> {
+        let mut addresses = Vec::new();
+        for segment_index in 0..self.num_segments {
+            let offsets = match self.public_memory_offsets.get(&segment_index) {
+                Some(offsets) => offsets,
+                None => continue,
+            };
+            let segment_start = segment_offsets
+                .get(segment_index)
+                .ok_or(MemoryError::SegmentNotFinalized(segment_index))?;
+            addresses.extend(
+                offsets
+                    .iter()
+                    .map(|(offset, page)| (segment_start + offset, *page)),
+            );
+        }
+        Ok(addresses)
+    }
 }
 
 pub fn gen_typed_args(args: Vec<&dyn Any>) -> Result<Vec<MaybeRelocatable>, VirtualMachineError> {
@@ -808,6 +868,70 @@ mod tests {
         );
     }
 
+    /// Test that the call to .gen_arg() with a single CairoArg passes the value
+    /// through.
+    #[test]
+    fn gen_arg_cairo_arg_single() {
+        let mut memory_segment_manager = MemorySegmentManager::new();
+        let mut vm = vm!();
+
+        assert_eq!(
+            memory_segment_manager
+                .gen_arg(&CairoArg::Single(mayberelocatable!(1234)), &mut vm.memory),
+            Ok(mayberelocatable!(1234)),
+        );
+    }
+
+    /// Test that the call to .gen_arg() with a composed CairoArg fails, as it
+    /// produces more than one value.
+    #[test]
+    fn gen_arg_cairo_arg_composed() {
+        let mut memory_segment_manager = MemorySegmentManager::new();
+        let mut vm = vm!();
+
+        assert_eq!(
+            memory_segment_manager.gen_arg(
+                &CairoArg::Composed(vec![
+                    CairoArg::Single(mayberelocatable!(1)),
+                    CairoArg::Single(mayberelocatable!(2)),
+                ]),
+                &mut vm.memory
+            ),
+            Err(VirtualMachineError::InvalidArgCount(1, 2)),
+        );
+    }
+
+    /// Test that nested arrays are written into their own segments, and that
+    /// composed members are flattened in place.
+    #[test]
+    fn gen_cairo_arg_nested() {
+        let mut memory_segment_manager = MemorySegmentManager::new();
+        let mut vm = vm!();
+
+        let arg = CairoArg::Composed(vec![
+            CairoArg::Single(mayberelocatable!(7)),
+            CairoArg::Array(vec![
+                CairoArg::Composed(vec![
+                    CairoArg::Single(mayberelocatable!(1)),
+                    CairoArg::Single(mayberelocatable!(2)),
+                ]),
+                CairoArg::Array(vec![CairoArg::Single(mayberelocatable!(3))]),
+            ]),
+        ]);
+
+        assert_eq!(
+            memory_segment_manager.gen_cairo_arg(&arg, &mut vm.memory),
+            Ok(vec![mayberelocatable!(7), mayberelocatable!(1, 0)]),
+        );
+        check_memory!(
+            vm.memory,
+            ((0, 0), 3),
+            ((1, 0), 1),
+            ((1, 1), 2),
+            ((1, 2), (0, 0))
+        );
+    }
+
     /// Test that the call to .gen_typed_args() with an empty vector returns an
     /// empty vector.
     #[test]
@@ -881,4 +1005,27 @@ mod tests {
         );
         assert_eq!(segments.segment_sizes, HashMap::from([(0, 42)]));
     }
+
+    #[test]
+    fn get_public_memory_addresses() {
+        let mut segments = MemorySegmentManager::new();
+        segments.num_segments = 3;
+        segments.finalize(None, 0, Some(&vec![(0, 0), (2, 0)]));
+        segments.finalize(None, 2, Some(&vec![(1, 1)]));
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1, 5, 9]),
+            Ok(vec![(1, 0), (3, 0), (10, 1)])
+        );
+    }
+
+    #[test]
+    fn get_public_memory_addresses_missing_segment() {
+        let mut segments = MemorySegmentManager::new();
+        segments.num_segments = 2;
+        segments.finalize(None, 1, Some(&vec![(0, 0)]));
+        assert_eq!(
+            segments.get_public_memory_addresses(&[1]),
+            Err(MemoryError::SegmentNotFinalized(1))
+        );
+    }
 }
@@ -1,5 +1,11 @@
-use cairo_vm::cairo_run;
+use cairo_vm::cairo_run::{self, CairoRunConfig};
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use cairo_vm::types::contract_class::{get_selector_from_name, ContractClass};
+use cairo_vm::types::layout::CairoLayout;
+use cairo_vm::types::relocatable::MaybeRelocatable;
+use cairo_vm::vm::runners::cairo_runner::CairoArg;
+use cairo_vm::vm::vm_core::RunLimits;
+use felt::{Felt, FeltOps, NewFelt};
 use std::path::Path;
 
 #[test]
@@ -920,6 +926,81 @@ fn cairo_run_signature() {
     .expect("Couldn't run program");
 }
 
+#[test]
+fn cairo_run_ecdsa_small() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/ecdsa.json"),
+        "main",
+        false,
+        false,
+        "small",
+        false,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_ecdsa_dex() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/ecdsa.json"),
+        "main",
+        false,
+        false,
+        "dex",
+        false,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_ecdsa_signatures() {
+    let signatures = [(
+        0,
+        (
+            Felt::parse_bytes(
+                b"3086480810278599376317923499561306189851900463386393948998357832163236918254",
+                10,
+            )
+            .unwrap(),
+            Felt::parse_bytes(
+                b"598673427589502599949712887611119751108407514580626464031881322743364689811",
+                10,
+            )
+            .unwrap(),
+        ),
+    )];
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/ecdsa_signatures_file.json"),
+        &CairoRunConfig {
+            layout: "small",
+            signatures: &signatures,
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_ecdsa_missing_signature() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    assert!(cairo_run::cairo_run(
+        Path::new("cairo_programs/ecdsa_signatures_file.json"),
+        "main",
+        false,
+        false,
+        "small",
+        false,
+        &mut hint_executor,
+    )
+    .is_err());
+}
+
 #[test]
 fn cairo_run_secp_ec() {
     let mut hint_executor = BuiltinHintProcessor::new_empty();
@@ -1301,3 +1382,272 @@ fn cairo_run_dict_store_cast_pointer() {
     )
     .expect("Couldn't run program");
 }
+
+#[test]
+fn cairo_run_entrypoint_with_args() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let args = [
+        CairoArg::from(MaybeRelocatable::from(Felt::new(1))),
+        CairoArg::from(MaybeRelocatable::from(Felt::new(1))),
+        CairoArg::from(MaybeRelocatable::from(Felt::new(10))),
+    ];
+    let (_, vm) = cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/fibonacci.json"),
+        &CairoRunConfig {
+            entrypoint: "fib",
+            args: &args,
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+    let result = vm
+        .get_integer(&(vm.get_ap().sub_usize(1).unwrap()))
+        .unwrap();
+    assert_eq!(result.as_ref(), &Felt::new(144));
+}
+
+#[test]
+fn cairo_run_entrypoint_with_array_and_struct_args() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let value = |v: &str| v.parse::<CairoArg>().unwrap();
+    // check_array(array, value, array_length, iterator), with the last three
+    // arguments passed as a single struct
+    let args = [
+        CairoArg::Array(vec![value("0x7"), value("7"), value("7")]),
+        CairoArg::Composed(vec![value("7"), value("3"), value("0")]),
+    ];
+    let (_, vm) = cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/memset.json"),
+        &CairoRunConfig {
+            entrypoint: "check_array",
+            args: &args,
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+    let result = vm
+        .get_integer(&(vm.get_ap().sub_usize(1).unwrap()))
+        .unwrap();
+    assert_eq!(result.as_ref(), &Felt::new(1));
+}
+
+#[test]
+fn cairo_run_entrypoint_with_args_proof_mode() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let args = [CairoArg::from(MaybeRelocatable::from(Felt::new(1)))];
+    let err = cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/proof_programs/fibonacci.json"),
+        &CairoRunConfig {
+            layout: "all",
+            proof_mode: true,
+            args: &args,
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .err()
+    .unwrap();
+    assert!(err
+        .to_string()
+        .contains("Entrypoint arguments can't be passed in proof mode"));
+}
+
+#[test]
+fn cairo_run_air_inputs_proof_mode() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let (cairo_runner, vm) = cairo_run::cairo_run(
+        Path::new("cairo_programs/proof_programs/pedersen_test.json"),
+        "main",
+        true,
+        false,
+        "all",
+        true,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+
+    let public_input = cairo_runner.get_air_public_input(&vm).unwrap();
+    assert_eq!(public_input.layout, "all");
+    assert!(public_input.n_steps.is_power_of_two());
+    assert!(public_input.rc_min <= public_input.rc_max);
+    for segment in ["program", "execution", "output", "pedersen", "range_check"] {
+        let addresses = &public_input.memory_segments[segment];
+        assert!(addresses.begin_addr <= addresses.stop_ptr);
+    }
+    assert!(!public_input.public_memory.is_empty());
+    assert_eq!(public_input.dynamic_params, None);
+
+    let private_input = cairo_runner.get_air_private_input(&vm);
+    assert!(!private_input.0.contains_key("output"));
+    assert!(!private_input.0["pedersen"].is_empty());
+}
+
+#[test]
+fn cairo_run_dynamic_layout_proof_mode() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let (cairo_runner, vm) = cairo_run::cairo_run(
+        Path::new("cairo_programs/proof_programs/pedersen_test.json"),
+        "main",
+        true,
+        false,
+        "dynamic",
+        true,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+    assert_eq!(cairo_runner.check_used_cells(&vm), Ok(()));
+
+    let public_input = cairo_runner.get_air_public_input(&vm).unwrap();
+    assert_eq!(public_input.layout, "dynamic");
+    let dynamic_params = public_input.dynamic_params.unwrap();
+    for (builtin, ratio) in dynamic_params.builtin_ratios {
+        assert!(ratio.is_power_of_two(), "{builtin} has ratio {ratio}");
+        assert!(ratio as usize <= public_input.n_steps);
+    }
+}
+
+#[test]
+fn cairo_run_max_steps_reached() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let err = cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/fibonacci.json"),
+        &CairoRunConfig {
+            layout: "all",
+            run_limits: RunLimits {
+                max_steps: Some(10),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .err()
+    .unwrap();
+    assert!(err
+        .to_string()
+        .contains("Run exceeded the limit of 10 steps"));
+}
+
+#[test]
+fn cairo_run_starknet_layouts() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    for layout in [
+        "starknet",
+        "starknet_with_keccak",
+        "recursive_large_output",
+        "all_cairo",
+    ] {
+        cairo_run::cairo_run(
+            Path::new("cairo_programs/bitwise_output.json"),
+            "main",
+            false,
+            false,
+            layout,
+            false,
+            &mut hint_executor,
+        )
+        .expect("Couldn't run program");
+    }
+}
+
+#[test]
+fn cairo_run_poseidon_builtin() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    for layout in ["starknet", "all_cairo"] {
+        cairo_run::cairo_run(
+            Path::new("cairo_programs/poseidon_builtin.json"),
+            "main",
+            false,
+            false,
+            layout,
+            false,
+            &mut hint_executor,
+        )
+        .expect("Couldn't run program");
+    }
+}
+
+#[test]
+fn cairo_run_poseidon_hash_many() {
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/poseidon_hash_many.json"),
+        "main",
+        false,
+        false,
+        "starknet",
+        false,
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_contract_entrypoint_echo() {
+    let contract_class = ContractClass::from_file(Path::new(
+        "cairo_programs/manually_compiled/echo_contract.json",
+    ))
+    .expect("Couldn't load contract class");
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    let calldata = vec![Felt::new(1), Felt::new(2), Felt::new(3)];
+    let retdata = cairo_run::cairo_run_contract_entrypoint(
+        &contract_class,
+        &get_selector_from_name("echo"),
+        &calldata,
+        &CairoRunConfig::default(),
+        &mut hint_executor,
+    )
+    .expect("Couldn't run entrypoint");
+    assert_eq!(retdata, calldata);
+}
+
+#[test]
+fn cairo_run_custom_layout() {
+    let layout = CairoLayout::from_reader(
+        r#"{
+            "name": "output_and_bitwise",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "diluted_pool": { "units_per_step": 16, "spacing": 4, "n_bits": 16 },
+            "builtins": { "output": true, "bitwise": { "ratio": 8 } }
+        }"#
+        .as_bytes(),
+    )
+    .unwrap();
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/bitwise_output.json"),
+        &CairoRunConfig {
+            custom_layout: Some(&layout),
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .expect("Couldn't run program");
+
+    let layout = CairoLayout::from_reader(
+        r#"{
+            "name": "output_only",
+            "rc_units": 4,
+            "public_memory_fraction": 8,
+            "memory_units_per_step": 8,
+            "builtins": { "output": true }
+        }"#
+        .as_bytes(),
+    )
+    .unwrap();
+    let err = cairo_run::cairo_run_with_config(
+        Path::new("cairo_programs/bitwise_output.json"),
+        &CairoRunConfig {
+            custom_layout: Some(&layout),
+            ..Default::default()
+        },
+        &mut hint_executor,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("output_only"));
+}
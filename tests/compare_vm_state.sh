@@ -1,6 +1,7 @@
 #!/usr/bin/env sh
 
 tests_path="../cairo_programs"
+compare="../target/release/cairo-rs-compare"
 exit_code=0
 trace=false
 memory=false
@@ -23,18 +24,27 @@ done
 for file in $(ls $tests_path | grep .cairo$ | sed -E 's/\.cairo$//'); do
     path_file="$tests_path/$file"
 
-    if $trace; then
-        if ! diff -q $path_file.trace $path_file.rs.trace; then
+    # When both are compared, a single run gets the memories it needs to decode the instruction
+    # at which the traces differ.
+    if $trace && $memory; then
+        if ! $compare --trace_files $path_file.trace $path_file.rs.trace \
+            --memory_files $path_file.memory $path_file.rs.memory; then
+            echo "Traces or memory differ for $file"
+            exit_code=1
+            failed_tests=$((failed_tests + 1))
+        else
+            passed_tests=$((passed_tests + 1))
+        fi
+    elif $trace; then
+        if ! $compare --trace_files $path_file.trace $path_file.rs.trace; then
             echo "Traces for $file differ"
             exit_code=1
             failed_tests=$((failed_tests + 1))
         else
             passed_tests=$((passed_tests + 1))
         fi
-    fi
-
-    if $memory; then
-        if ! ./memory_comparator.py $path_file.memory $path_file.rs.memory; then
+    elif $memory; then
+        if ! $compare --memory_files $path_file.memory $path_file.rs.memory; then
             echo "Memory differs for $file"
             exit_code=1
             failed_tests=$((failed_tests + 1))